@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use package_json_parser::{DiagnosticOutput, OUTPUT_VERSION, PackageJsonParser, sarif_log};
+use serde_json::{Value as JsonValue, json};
+
+#[derive(Debug, Parser)]
+#[command(
+  name = "package-json",
+  version,
+  about = "Lint and query package.json files"
+)]
+struct Cli {
+  /// Output format.
+  #[arg(long, value_enum, default_value_t = Format::Text, global = true)]
+  format: Format,
+
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+  Text,
+  Json,
+  /// SARIF 2.1.0, for code-scanning tools.
+  Sarif,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+  /// Validate every field and report all errors.
+  Validate {
+    #[arg(default_value = "package.json")]
+    path: PathBuf,
+  },
+  /// Print the value at a dotted field path, e.g. `scripts.build`.
+  Get {
+    field: String,
+
+    #[arg(long, default_value = "package.json")]
+    path: PathBuf,
+  },
+}
+
+fn main() -> ExitCode {
+  let cli = Cli::parse();
+
+  match cli.command {
+    Command::Validate { path } => validate(&path, cli.format),
+    Command::Get { field, path } => get(&path, &field, cli.format),
+  }
+}
+
+fn validate(path: &PathBuf, format: Format) -> ExitCode {
+  let result = PackageJsonParser::parse(path).and_then(|parser| parser.validate_all());
+
+  match (format, result) {
+    (Format::Text, Ok(())) => {
+      println!("{} is valid", path.display());
+      ExitCode::SUCCESS
+    }
+    (Format::Text, Err(report)) => {
+      eprintln!("{report:?}");
+      ExitCode::FAILURE
+    }
+    (format, result) => {
+      let diagnostics = match &result {
+        Ok(()) => Vec::new(),
+        Err(report) => diagnostics(path, report),
+      };
+      let output = match format {
+        Format::Sarif => sarif_log(&diagnostics),
+        _ => json!({
+          "version": OUTPUT_VERSION,
+          "path": path.display().to_string(),
+          "valid": result.is_ok(),
+          "diagnostics": diagnostics,
+        }),
+      };
+      println!("{output:#}");
+
+      if result.is_ok() {
+        ExitCode::SUCCESS
+      } else {
+        ExitCode::FAILURE
+      }
+    }
+  }
+}
+
+fn get(path: &PathBuf, field: &str, format: Format) -> ExitCode {
+  let keys = field.split('.').collect::<Vec<_>>();
+  let value =
+    PackageJsonParser::parse(path).and_then(|parser| parser.get(&keys).map(|value| value.cloned()));
+
+  match value {
+    Ok(Some(value)) => {
+      match (format, &value) {
+        (Format::Text, JsonValue::String(value)) => println!("{value}"),
+        _ => println!("{value:#}"),
+      }
+      ExitCode::SUCCESS
+    }
+    Ok(None) => {
+      match format {
+        Format::Text => eprintln!("`{field}` is not set in {}", path.display()),
+        Format::Json | Format::Sarif => println!("null"),
+      }
+      ExitCode::FAILURE
+    }
+    Err(report) => {
+      match format {
+        Format::Text => eprintln!("{report:?}"),
+        Format::Json => println!(
+          "{:#}",
+          json!({ "version": OUTPUT_VERSION, "diagnostics": diagnostics(path, &report) })
+        ),
+        Format::Sarif => println!("{:#}", sarif_log(&diagnostics(path, &report))),
+      }
+      ExitCode::FAILURE
+    }
+  }
+}
+
+/// Flattens a report into the machine-readable diagnostics, expanding the
+/// related diagnostics of an aggregated validation report.
+fn diagnostics(path: &Path, report: &miette::Report) -> Vec<DiagnosticOutput> {
+  let source = std::fs::read_to_string(path).ok();
+  let path = path.display().to_string();
+  DiagnosticOutput::from_report(report.as_ref(), source.as_deref(), Some(&path))
+}
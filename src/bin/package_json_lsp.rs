@@ -0,0 +1,11 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+  match package_json_parser::lsp::run_stdio() {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(error) => {
+      eprintln!("package-json-lsp: {error}");
+      ExitCode::FAILURE
+    }
+  }
+}
@@ -0,0 +1,661 @@
+//! Stable codes of the diagnostics reported for manifest fields, lint rules,
+//! JSONC syntax warnings and the errors of the crate's APIs.
+//!
+//! Codes read `<scope>::<field>::<problem>` and never change once released,
+//! so they are safe to suppress, configure in a [`RuleConfig`](crate::RuleConfig)
+//! and search for. Each one links to its explanation in
+//! `docs/error-codes.md`.
+
+use std::fmt;
+
+const DOCS_URL: &str = concat!(
+  env!("CARGO_PKG_REPOSITORY"),
+  "/blob/main/docs/error-codes.md"
+);
+
+/// An entry of the error-code registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiagnosticCode {
+  pub code: &'static str,
+  pub summary: &'static str,
+}
+
+impl DiagnosticCode {
+  const fn new(code: &'static str, summary: &'static str) -> Self {
+    Self { code, summary }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    self.code
+  }
+
+  /// The explanation of this code in `docs/error-codes.md`.
+  pub fn url(&self) -> String {
+    let anchor = self.code.chars().filter(|c| *c != ':').collect::<String>();
+    format!("{DOCS_URL}#{anchor}")
+  }
+}
+
+impl fmt::Display for DiagnosticCode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.code)
+  }
+}
+
+pub const INVALID_TYPE: DiagnosticCode =
+  DiagnosticCode::new("pkg::invalid-type", "The field has the wrong JSON type");
+
+pub const INVALID_FIELD: DiagnosticCode =
+  DiagnosticCode::new("pkg::invalid-field", "The field failed validation");
+
+pub const SYNTAX_COMMENT: DiagnosticCode = DiagnosticCode::new(
+  "pkg::syntax::comment",
+  "A comment, which plain JSON does not allow",
+);
+
+pub const SYNTAX_TRAILING_COMMA: DiagnosticCode = DiagnosticCode::new(
+  "pkg::syntax::trailing-comma",
+  "A trailing comma, which plain JSON does not allow",
+);
+
+pub const SYNTAX_INVALID_JSON: DiagnosticCode = DiagnosticCode::new(
+  "pkg::syntax::invalid-json",
+  "The manifest is not valid JSON, JSONC or JSON5",
+);
+
+pub const SYNTAX_INVALID_YAML: DiagnosticCode = DiagnosticCode::new(
+  "pkg::syntax::invalid-yaml",
+  "`package.yaml` is not a single valid YAML document",
+);
+
+pub const NAME_INVALID_CHARS: DiagnosticCode = DiagnosticCode::new(
+  "pkg::name::invalid-chars",
+  "The package name is not a valid npm package name",
+);
+
+pub const VERSION_INVALID_SEMVER: DiagnosticCode = DiagnosticCode::new(
+  "pkg::version::invalid-semver",
+  "The version is not a valid semantic version",
+);
+
+pub const TYPE_INVALID_VALUE: DiagnosticCode = DiagnosticCode::new(
+  "pkg::type::invalid-value",
+  "`type` is neither `commonjs` nor `module`",
+);
+
+pub const BUGS_INVALID_URL_OR_EMAIL: DiagnosticCode = DiagnosticCode::new(
+  "pkg::bugs::invalid-url-or-email",
+  "`bugs` is neither a URL nor an email address",
+);
+
+pub const BUGS_INVALID_URL: DiagnosticCode =
+  DiagnosticCode::new("pkg::bugs::invalid-url", "`bugs.url` is not a valid URL");
+
+pub const BUGS_INVALID_EMAIL: DiagnosticCode = DiagnosticCode::new(
+  "pkg::bugs::invalid-email",
+  "`bugs.email` is not a valid email address",
+);
+
+pub const FUNDING_INVALID_URL: DiagnosticCode = DiagnosticCode::new(
+  "pkg::funding::invalid-url",
+  "A funding URL is not a valid URL",
+);
+
+pub const PERSON_INVALID_NAME: DiagnosticCode = DiagnosticCode::new(
+  "pkg::person::invalid-name",
+  "An author, contributor or maintainer has an empty name",
+);
+
+pub const PERSON_INVALID_EMAIL: DiagnosticCode = DiagnosticCode::new(
+  "pkg::person::invalid-email",
+  "An author, contributor or maintainer has an invalid email address",
+);
+
+pub const PERSON_INVALID_URL: DiagnosticCode = DiagnosticCode::new(
+  "pkg::person::invalid-url",
+  "An author, contributor or maintainer has an invalid URL",
+);
+
+pub const LICENSE_INVALID_EXPRESSION: DiagnosticCode = DiagnosticCode::new(
+  "pkg::license::invalid-expression",
+  "The license is not a valid SPDX expression",
+);
+
+pub const LICENSE_INVALID_URL: DiagnosticCode = DiagnosticCode::new(
+  "pkg::license::invalid-url",
+  "An entry of the legacy `licenses` array has an invalid URL",
+);
+
+pub const REPOSITORY_INVALID_URL: DiagnosticCode = DiagnosticCode::new(
+  "pkg::repository::invalid-url",
+  "The repository is not a valid URL or shorthand",
+);
+
+pub const PACKAGE_MANAGER_INVALID_VALUE: DiagnosticCode = DiagnosticCode::new(
+  "pkg::package-manager::invalid-value",
+  "`packageManager` is not `<npm|pnpm|yarn|bun>@<version>`",
+);
+
+pub const PUBLISH_CONFIG_INVALID_ACCESS: DiagnosticCode = DiagnosticCode::new(
+  "pkg::publish-config::invalid-access",
+  "`publishConfig.access` is not `public`, `restricted` or `private`",
+);
+
+pub const PUBLISH_CONFIG_INVALID_REGISTRY: DiagnosticCode = DiagnosticCode::new(
+  "pkg::publish-config::invalid-registry",
+  "`publishConfig.registry` is not a valid URL",
+);
+
+pub const PUBLISH_CONFIG_INVALID_TAG: DiagnosticCode = DiagnosticCode::new(
+  "pkg::publish-config::invalid-tag",
+  "`publishConfig.tag` contains characters npm does not allow",
+);
+
+pub const PUBLISH_CONFIG_INVALID_PROVENANCE: DiagnosticCode = DiagnosticCode::new(
+  "pkg::publish-config::invalid-provenance",
+  "`publishConfig.provenance` is set to `false`",
+);
+
+pub const DEPENDENCIES_INVALID_SPEC: DiagnosticCode = DiagnosticCode::new(
+  "pkg::dependencies::invalid-spec",
+  "A dependency specifier is not a valid range, tag, URL or protocol",
+);
+
+pub const BUNDLE_DEPENDENCIES_INVALID_NAME: DiagnosticCode = DiagnosticCode::new(
+  "pkg::bundle-dependencies::invalid-name",
+  "A bundleDependencies entry is not a valid package name",
+);
+
+pub const BUNDLE_DEPENDENCIES_WITHOUT_DEPENDENCY: DiagnosticCode = DiagnosticCode::new(
+  "pkg::bundle-dependencies::without-dependency",
+  "A bundled package is not in `dependencies` or `optionalDependencies`",
+);
+
+pub const PEER_DEPENDENCIES_META_INVALID_NAME: DiagnosticCode = DiagnosticCode::new(
+  "pkg::peer-dependencies-meta::invalid-name",
+  "A peerDependenciesMeta key is not a valid package name",
+);
+
+pub const DEPENDENCIES_META_INVALID_SELECTOR: DiagnosticCode = DiagnosticCode::new(
+  "pkg::dependencies-meta::invalid-selector",
+  "A dependenciesMeta key is not `<name>` or `<name>@<version>`",
+);
+
+pub const PEER_DEPENDENCIES_META_WITHOUT_PEER: DiagnosticCode = DiagnosticCode::new(
+  "pkg::peer-dependencies-meta::without-peer",
+  "A `peerDependenciesMeta` entry has no `peerDependencies` entry",
+);
+
+pub const DEPENDENCIES_META_INJECTED_WITHOUT_DEPENDENCY: DiagnosticCode = DiagnosticCode::new(
+  "pkg::dependencies-meta::injected-without-dependency",
+  "A package marked `injected` is not a dependency",
+);
+
+pub const EXPORTS_INVALID_TARGET: DiagnosticCode = DiagnosticCode::new(
+  "pkg::exports::invalid-target",
+  "An exports target is not a relative path starting with `./`",
+);
+
+pub const EXPORTS_INVALID_TARGET_SEGMENT: DiagnosticCode = DiagnosticCode::new(
+  "pkg::exports::invalid-target-segment",
+  "An exports target contains an empty, `.`, `..` or `node_modules` segment",
+);
+
+pub const EXPORTS_NESTED_SUBPATH: DiagnosticCode = DiagnosticCode::new(
+  "pkg::exports::nested-subpath",
+  "A subpath key appears below the top level of exports",
+);
+
+pub const EXPORTS_NUMERIC_CONDITION: DiagnosticCode = DiagnosticCode::new(
+  "pkg::exports::numeric-condition",
+  "A condition key is numeric",
+);
+
+pub const EXPORTS_MIXED_KEYS: DiagnosticCode = DiagnosticCode::new(
+  "pkg::exports::mixed-keys",
+  "Exports mix subpath keys and condition keys",
+);
+
+pub const EXPORTS_INVALID_SUBPATH: DiagnosticCode = DiagnosticCode::new(
+  "pkg::exports::invalid-subpath",
+  "A subpath key is neither `.` nor starts with `./`",
+);
+
+pub const EXPORTS_MULTIPLE_WILDCARDS: DiagnosticCode = DiagnosticCode::new(
+  "pkg::exports::multiple-wildcards",
+  "A subpath pattern contains more than one `*`",
+);
+
+pub const IMPORTS_INVALID_KEY: DiagnosticCode = DiagnosticCode::new(
+  "pkg::imports::invalid-key",
+  "An imports key does not start with `#`",
+);
+
+pub const IMPORTS_RESERVED_KEY: DiagnosticCode = DiagnosticCode::new(
+  "pkg::imports::reserved-key",
+  "An imports key is `#` or starts with `#/`",
+);
+
+pub const IMPORTS_MULTIPLE_WILDCARDS: DiagnosticCode = DiagnosticCode::new(
+  "pkg::imports::multiple-wildcards",
+  "An imports pattern contains more than one `*`",
+);
+
+pub const IMPORTS_INVALID_TARGET: DiagnosticCode = DiagnosticCode::new(
+  "pkg::imports::invalid-target",
+  "An imports target escapes the package or is a URL",
+);
+
+pub const IMPORTS_NUMERIC_CONDITION: DiagnosticCode = DiagnosticCode::new(
+  "pkg::imports::numeric-condition",
+  "A condition key of imports is numeric",
+);
+
+pub const OVERRIDES_INVALID_SELECTOR: DiagnosticCode = DiagnosticCode::new(
+  "pkg::overrides::invalid-selector",
+  "An overrides key is not `<name>` or `<name>@<spec>`",
+);
+
+pub const OVERRIDES_INVALID_SPEC: DiagnosticCode = DiagnosticCode::new(
+  "pkg::overrides::invalid-spec",
+  "An overrides value is not a valid dependency specifier",
+);
+
+pub const OVERRIDES_MISPLACED_SELF_KEY: DiagnosticCode = DiagnosticCode::new(
+  "pkg::overrides::misplaced-self-key",
+  "A `.` key of overrides is not a string inside a nested override",
+);
+
+pub const OVERRIDES_UNKNOWN_REFERENCE: DiagnosticCode = DiagnosticCode::new(
+  "pkg::overrides::unknown-reference",
+  "A `$name` reference of overrides does not name a direct dependency",
+);
+
+pub const RESOLUTIONS_INVALID_SELECTOR: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolutions::invalid-selector",
+  "A resolutions key is not a valid package selector",
+);
+
+pub const RESOLUTIONS_INVALID_SPEC: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolutions::invalid-spec",
+  "A resolutions value is not a valid dependency specifier",
+);
+
+pub const WORKSPACES_EMPTY_PATTERN: DiagnosticCode = DiagnosticCode::new(
+  "pkg::workspaces::empty-pattern",
+  "A workspace pattern is empty",
+);
+
+pub const WORKSPACES_ABSOLUTE_PATTERN: DiagnosticCode = DiagnosticCode::new(
+  "pkg::workspaces::absolute-pattern",
+  "A workspace pattern is not relative to the package root",
+);
+
+pub const DESCRIPTION_MISSING: DiagnosticCode = DiagnosticCode::new(
+  "pkg::description::missing",
+  "A public package has no `description`",
+);
+
+pub const REPOSITORY_MISSING: DiagnosticCode = DiagnosticCode::new(
+  "pkg::repository::missing",
+  "A public package has no `repository`",
+);
+
+pub const LICENSE_MISSING: DiagnosticCode =
+  DiagnosticCode::new("pkg::license::missing", "A public package has no `license`");
+
+pub const TYPINGS_DUPLICATES_TYPES: DiagnosticCode = DiagnosticCode::new(
+  "pkg::typings::duplicates-types",
+  "`types` and `typings` are both set",
+);
+
+pub const TYPINGS_PREFER_TYPES: DiagnosticCode = DiagnosticCode::new(
+  "pkg::typings::prefer-types",
+  "`typings` is used instead of `types`",
+);
+
+pub const ENGINE_STRICT_DEPRECATED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::engine-strict::deprecated",
+  "`engineStrict` is set but ignored since npm 3",
+);
+
+pub const BUGS_EMAIL_STRING: DiagnosticCode = DiagnosticCode::new(
+  "pkg::bugs::email-string",
+  "`bugs` is an email address string",
+);
+
+pub const POLICY_REQUIRED_FIELD: DiagnosticCode = DiagnosticCode::new(
+  "pkg::policy::required-field",
+  "A field listed in `requiredFields` is missing",
+);
+
+pub const POLICY_LICENSE_NOT_ALLOWED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::policy::license-not-allowed",
+  "The license is not satisfiable with `allowedLicenses`",
+);
+
+pub const POLICY_FORBIDDEN_DEPENDENCY: DiagnosticCode = DiagnosticCode::new(
+  "pkg::policy::forbidden-dependency",
+  "A package in `forbiddenDependencies` is depended on",
+);
+
+pub const POLICY_PRIVATE_REQUIRED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::policy::private-required",
+  "`private: true` is missing while `requirePrivate` is set",
+);
+
+pub const VALIDATION_FAILED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::validation-failed",
+  "One or more fields failed validation",
+);
+
+pub const NAME_REQUIRED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::name::required",
+  "An operation needs `name`, but the manifest has none",
+);
+
+pub const IO_READ_FAILED: DiagnosticCode =
+  DiagnosticCode::new("pkg::io::read-failed", "A file could not be read");
+
+pub const IO_MANIFEST_NOT_FOUND: DiagnosticCode = DiagnosticCode::new(
+  "pkg::io::manifest-not-found",
+  "The directory has no package.json, package.json5 or package.yaml",
+);
+
+pub const RESOLVE_EXPORTS_NOT_DEFINED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolve::exports-not-defined",
+  "A subpath was resolved against a package without `exports`",
+);
+
+pub const RESOLVE_PATH_NOT_EXPORTED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolve::path-not-exported",
+  "`exports` does not define the requested subpath",
+);
+
+pub const RESOLVE_IMPORT_NOT_DEFINED: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolve::import-not-defined",
+  "`imports` does not define the requested specifier",
+);
+
+pub const RESOLVE_INVALID_SPECIFIER: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolve::invalid-specifier",
+  "The specifier to resolve is malformed",
+);
+
+pub const RESOLVE_INVALID_TARGET: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolve::invalid-target",
+  "The matched `exports` or `imports` target is invalid",
+);
+
+pub const RESOLVE_INVALID_CONFIGURATION: DiagnosticCode = DiagnosticCode::new(
+  "pkg::resolve::invalid-configuration",
+  "`exports` or `imports` cannot be used for resolution",
+);
+
+pub const EDIT_INVALID_DOCUMENT: DiagnosticCode = DiagnosticCode::new(
+  "pkg::edit::invalid-document",
+  "The document to edit is not valid JSONC",
+);
+
+pub const EDIT_ROOT_NOT_OBJECT: DiagnosticCode = DiagnosticCode::new(
+  "pkg::edit::root-not-object",
+  "The document to edit is not a JSON object",
+);
+
+pub const EDIT_NOT_AN_OBJECT: DiagnosticCode = DiagnosticCode::new(
+  "pkg::edit::not-an-object",
+  "A key on the edit path holds something other than an object",
+);
+
+pub const EDIT_EMPTY_PATH: DiagnosticCode =
+  DiagnosticCode::new("pkg::edit::empty-path", "The edit path has no keys");
+
+pub const EDIT_INVALID_VALUE: DiagnosticCode = DiagnosticCode::new(
+  "pkg::edit::invalid-value",
+  "The value to write cannot be serialized as JSON",
+);
+
+pub const WORKSPACE_INVALID_PATTERN: DiagnosticCode = DiagnosticCode::new(
+  "pkg::workspace::invalid-pattern",
+  "A workspace pattern is not a valid glob",
+);
+
+pub const WORKSPACE_INVALID_PNPM_WORKSPACE: DiagnosticCode = DiagnosticCode::new(
+  "pkg::workspace::invalid-pnpm-workspace",
+  "`pnpm-workspace.yaml` or a member directory could not be read",
+);
+
+pub const GRAPH_DEPENDENCY_CYCLE: DiagnosticCode = DiagnosticCode::new(
+  "pkg::graph::dependency-cycle",
+  "Workspace packages depend on each other in a cycle",
+);
+
+pub const CONFIG_UNSUPPORTED_FORMAT: DiagnosticCode = DiagnosticCode::new(
+  "pkg::config::unsupported-format",
+  "The rule config is neither `.json` nor `.toml`",
+);
+
+pub const CONFIG_INVALID: DiagnosticCode =
+  DiagnosticCode::new("pkg::config::invalid", "The rule config cannot be parsed");
+
+pub const LOCKFILE_UNSUPPORTED_VERSION: DiagnosticCode = DiagnosticCode::new(
+  "lockfile::version::unsupported",
+  "`lockfileVersion` is not 1, 2 or 3",
+);
+
+pub const LOCKFILE_INVALID_VERSION: DiagnosticCode = DiagnosticCode::new(
+  "lockfile::package::invalid-version",
+  "A locked package has a version that is not valid semver",
+);
+
+pub const LOCKFILE_MISSING_VERSION: DiagnosticCode = DiagnosticCode::new(
+  "lockfile::package::missing-version",
+  "A locked package has no version",
+);
+
+/// Every registered code.
+pub const DIAGNOSTIC_CODES: &[DiagnosticCode] = &[
+  INVALID_TYPE,
+  INVALID_FIELD,
+  SYNTAX_COMMENT,
+  SYNTAX_TRAILING_COMMA,
+  SYNTAX_INVALID_JSON,
+  SYNTAX_INVALID_YAML,
+  NAME_INVALID_CHARS,
+  VERSION_INVALID_SEMVER,
+  TYPE_INVALID_VALUE,
+  BUGS_INVALID_URL_OR_EMAIL,
+  BUGS_INVALID_URL,
+  BUGS_INVALID_EMAIL,
+  FUNDING_INVALID_URL,
+  PERSON_INVALID_NAME,
+  PERSON_INVALID_EMAIL,
+  PERSON_INVALID_URL,
+  LICENSE_INVALID_EXPRESSION,
+  LICENSE_INVALID_URL,
+  REPOSITORY_INVALID_URL,
+  PACKAGE_MANAGER_INVALID_VALUE,
+  PUBLISH_CONFIG_INVALID_ACCESS,
+  PUBLISH_CONFIG_INVALID_REGISTRY,
+  PUBLISH_CONFIG_INVALID_TAG,
+  PUBLISH_CONFIG_INVALID_PROVENANCE,
+  DEPENDENCIES_INVALID_SPEC,
+  PEER_DEPENDENCIES_META_INVALID_NAME,
+  DEPENDENCIES_META_INVALID_SELECTOR,
+  PEER_DEPENDENCIES_META_WITHOUT_PEER,
+  DEPENDENCIES_META_INJECTED_WITHOUT_DEPENDENCY,
+  BUNDLE_DEPENDENCIES_INVALID_NAME,
+  BUNDLE_DEPENDENCIES_WITHOUT_DEPENDENCY,
+  EXPORTS_INVALID_TARGET,
+  EXPORTS_INVALID_TARGET_SEGMENT,
+  EXPORTS_NESTED_SUBPATH,
+  EXPORTS_NUMERIC_CONDITION,
+  EXPORTS_MIXED_KEYS,
+  EXPORTS_INVALID_SUBPATH,
+  EXPORTS_MULTIPLE_WILDCARDS,
+  IMPORTS_INVALID_KEY,
+  IMPORTS_RESERVED_KEY,
+  IMPORTS_MULTIPLE_WILDCARDS,
+  IMPORTS_INVALID_TARGET,
+  IMPORTS_NUMERIC_CONDITION,
+  OVERRIDES_INVALID_SELECTOR,
+  OVERRIDES_INVALID_SPEC,
+  OVERRIDES_MISPLACED_SELF_KEY,
+  OVERRIDES_UNKNOWN_REFERENCE,
+  RESOLUTIONS_INVALID_SELECTOR,
+  RESOLUTIONS_INVALID_SPEC,
+  WORKSPACES_EMPTY_PATTERN,
+  WORKSPACES_ABSOLUTE_PATTERN,
+  DESCRIPTION_MISSING,
+  REPOSITORY_MISSING,
+  LICENSE_MISSING,
+  TYPINGS_DUPLICATES_TYPES,
+  TYPINGS_PREFER_TYPES,
+  ENGINE_STRICT_DEPRECATED,
+  BUGS_EMAIL_STRING,
+  POLICY_REQUIRED_FIELD,
+  POLICY_LICENSE_NOT_ALLOWED,
+  POLICY_FORBIDDEN_DEPENDENCY,
+  POLICY_PRIVATE_REQUIRED,
+  VALIDATION_FAILED,
+  NAME_REQUIRED,
+  IO_READ_FAILED,
+  IO_MANIFEST_NOT_FOUND,
+  RESOLVE_EXPORTS_NOT_DEFINED,
+  RESOLVE_PATH_NOT_EXPORTED,
+  RESOLVE_IMPORT_NOT_DEFINED,
+  RESOLVE_INVALID_SPECIFIER,
+  RESOLVE_INVALID_TARGET,
+  RESOLVE_INVALID_CONFIGURATION,
+  EDIT_INVALID_DOCUMENT,
+  EDIT_ROOT_NOT_OBJECT,
+  EDIT_NOT_AN_OBJECT,
+  EDIT_EMPTY_PATH,
+  EDIT_INVALID_VALUE,
+  WORKSPACE_INVALID_PATTERN,
+  WORKSPACE_INVALID_PNPM_WORKSPACE,
+  GRAPH_DEPENDENCY_CYCLE,
+  CONFIG_UNSUPPORTED_FORMAT,
+  CONFIG_INVALID,
+  LOCKFILE_UNSUPPORTED_VERSION,
+  LOCKFILE_INVALID_VERSION,
+  LOCKFILE_MISSING_VERSION,
+];
+
+/// Looks up a registered code, e.g. `pkg::name::invalid-chars`.
+pub fn lookup(code: &str) -> Option<&'static DiagnosticCode> {
+  DIAGNOSTIC_CODES.iter().find(|entry| entry.code == code)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{DIAGNOSTIC_CODES, NAME_INVALID_CHARS, lookup};
+  use crate::err::{
+    ConfigError, EditError, ErrorKind, GraphError, ResolveError, ValidationReport, WorkspaceError,
+    YamlParseError,
+  };
+  use crate::{LINT_RULES, PackageJsonParser};
+  use miette::Report;
+
+  #[test]
+  fn should_register_unique_codes() {
+    for (index, entry) in DIAGNOSTIC_CODES.iter().enumerate() {
+      assert!(
+        DIAGNOSTIC_CODES[..index]
+          .iter()
+          .all(|other| other.code != entry.code),
+        "duplicate code {}",
+        entry.code
+      );
+    }
+  }
+
+  #[test]
+  fn should_document_every_code() {
+    let docs = include_str!("../docs/error-codes.md");
+    for entry in DIAGNOSTIC_CODES {
+      assert!(
+        docs.contains(&format!("## `{}`", entry.code)),
+        "{} is not documented",
+        entry.code
+      );
+    }
+  }
+
+  #[test]
+  fn should_register_every_lint_rule() {
+    for rule in LINT_RULES {
+      assert!(
+        lookup(rule.code).is_some(),
+        "{} is not registered",
+        rule.code
+      );
+    }
+  }
+
+  #[test]
+  fn should_link_to_code_explanation() {
+    assert_eq!(
+      lookup("pkg::name::invalid-chars"),
+      Some(&NAME_INVALID_CHARS)
+    );
+    assert!(
+      NAME_INVALID_CHARS
+        .url()
+        .ends_with("/docs/error-codes.md#pkgnameinvalid-chars")
+    );
+    assert!(lookup("invalid_package_name").is_none());
+  }
+
+  #[test]
+  fn should_attach_codes_to_field_errors() {
+    let parsed = PackageJsonParser::parse_str(r#"{ "version": 1, "name": "Bad" }"#).unwrap();
+
+    let error = parsed.version().unwrap_err();
+    assert_eq!(error.code().unwrap().to_string(), "pkg::invalid-type");
+    assert!(
+      error
+        .url()
+        .unwrap()
+        .to_string()
+        .ends_with("#pkginvalid-type")
+    );
+
+    let error = parsed.name().unwrap_err();
+    assert_eq!(
+      error.code().unwrap().to_string(),
+      "pkg::name::invalid-chars"
+    );
+  }
+
+  #[test]
+  fn should_register_every_error_code() {
+    let errors: Vec<Report> = vec![
+      PackageJsonParser::parse_str("{").unwrap_err(),
+      Report::new(YamlParseError {
+        src: String::new(),
+        message: String::new(),
+        span: (0, 0).into(),
+      }),
+      Report::new(ErrorKind::NameRequired),
+      Report::new(ErrorKind::ManifestNotFound { dir: ".".into() }),
+      Report::new(ValidationReport { related: vec![] }),
+      Report::new(ResolveError::ExportsNotDefined),
+      Report::new(EditError::EmptyPath),
+      Report::new(WorkspaceError::InvalidPattern {
+        pattern: "[".into(),
+        reason: String::new(),
+      }),
+      Report::new(GraphError::DependencyCycle { cycle: vec![] }),
+      Report::new(ConfigError::InvalidConfig {
+        reason: String::new(),
+      }),
+    ];
+
+    for error in errors {
+      let code = error.code().unwrap().to_string();
+      let entry = lookup(&code).unwrap_or_else(|| panic!("{code} is not registered"));
+      assert_eq!(error.url().unwrap().to_string(), entry.url());
+    }
+  }
+}
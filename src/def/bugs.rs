@@ -4,6 +4,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use validator::{ValidateEmail, ValidateUrl};
 
+use crate::codes::{BUGS_INVALID_EMAIL, BUGS_INVALID_URL, BUGS_INVALID_URL_OR_EMAIL};
 use crate::ext::{Validator, validation_error, value_range};
 
 #[derive(Debug, PartialEq, Serialize, Clone)]
@@ -35,7 +36,7 @@ impl<'de> Deserialize<'de> for BugsItem {
       {
         struct FieldVisitor;
 
-        impl<'de> Visitor<'de> for FieldVisitor {
+        impl Visitor<'_> for FieldVisitor {
           type Value = Field;
 
           fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -163,7 +164,7 @@ impl Validator for Bugs {
 
         return Err(validation_error(
           "Invalid URL or email",
-          Some("invalid_url_or_email"),
+          &BUGS_INVALID_URL_OR_EMAIL,
           "Please provide a valid URL or email",
           value_range(props, &[]),
           "Invalid URL or email",
@@ -174,7 +175,7 @@ impl Validator for Bugs {
           if !url.validate_url() {
             return Err(validation_error(
               "Invalid URL",
-              Some("invalid_url"),
+              &BUGS_INVALID_URL,
               "Please provide a valid URL",
               value_range(props, &["url"]),
               "Invalid URL",
@@ -186,7 +187,7 @@ impl Validator for Bugs {
           if !email.validate_email() {
             return Err(validation_error(
               "Invalid Email",
-              Some("invalid_email"),
+              &BUGS_INVALID_EMAIL,
               "Please provide a valid Email",
               value_range(props, &["email"]),
               "Invalid Email",
@@ -0,0 +1,152 @@
+use jsonc_parser::ast::ObjectProp;
+use serde::de::{SeqAccess, Visitor, value::SeqAccessDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::BUNDLE_DEPENDENCIES_INVALID_NAME;
+use crate::def::is_valid_package_name;
+use crate::ext::{Validator, array_child, node_range, validation_error};
+
+/// The spellings npm accepts, in the order it prefers them.
+pub(crate) const BUNDLE_DEPENDENCIES_KEYS: &[&str] = &["bundleDependencies", "bundledDependencies"];
+
+/// The `bundleDependencies` field, also spelled `bundledDependencies`:
+/// packages included in the tarball when the package is published.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum BundleDependencies {
+  /// `true` bundles every dependency, `false` none.
+  All(bool),
+  Names(Vec<String>),
+}
+
+impl<'de> Deserialize<'de> for BundleDependencies {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct BundleDependenciesVisitor;
+
+    impl<'de> Visitor<'de> for BundleDependenciesVisitor {
+      type Value = BundleDependencies;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a boolean or an array of package names for bundleDependencies")
+      }
+
+      fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+      where
+        E: serde::de::Error,
+      {
+        Ok(BundleDependencies::All(value))
+      }
+
+      fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+      where
+        S: SeqAccess<'de>,
+      {
+        let names = Vec::<String>::deserialize(SeqAccessDeserializer::new(seq))?;
+        Ok(BundleDependencies::Names(names))
+      }
+    }
+
+    deserializer.deserialize_any(BundleDependenciesVisitor)
+  }
+}
+
+impl BundleDependencies {
+  /// The explicitly listed names; empty for the boolean form.
+  pub fn names(&self) -> &[String] {
+    match self {
+      BundleDependencies::All(_) => &[],
+      BundleDependencies::Names(names) => names,
+    }
+  }
+
+  /// Whether every entry of `dependencies` is bundled.
+  pub fn bundles_all(&self) -> bool {
+    matches!(self, BundleDependencies::All(true))
+  }
+}
+
+impl Validator for BundleDependencies {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    let node = prop.map(|prop| &prop.value);
+    for (index, name) in self.names().iter().enumerate() {
+      if !is_valid_package_name(name) {
+        return Err(validation_error(
+          "Invalid bundleDependencies entry",
+          &BUNDLE_DEPENDENCIES_INVALID_NAME,
+          &format!("`{name}` is not a valid package name"),
+          node_range(array_child(node, index)),
+          "here",
+        ));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{BundleDependencies, PackageJsonParser};
+
+  #[test]
+  fn should_pass_validate_bundle_dependencies() {
+    let jsones = [
+      r#"{"bundleDependencies": true}"#,
+      r#"{"bundleDependencies": false}"#,
+      r#"{"bundleDependencies": ["foo", "@scope/bar"]}"#,
+      r#"{"bundledDependencies": ["foo"]}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_bundle_dependencies() {
+    let jsones = [
+      r#"{"bundleDependencies": ["foo", "Bar"]}"#,
+      r#"{"bundledDependencies": [""]}"#,
+      r#"{"bundleDependencies": "foo"}"#,
+      r#"{"bundledDependencies": [1]}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_normalize_both_spellings() {
+    let parsed = PackageJsonParser::parse_str(r#"{"bundledDependencies": ["foo"]}"#).unwrap();
+    let bundled = parsed.bundle_dependencies().unwrap().unwrap();
+    assert_eq!(bundled.names(), ["foo"]);
+
+    let parsed = PackageJsonParser::parse_str(
+      r#"{"bundleDependencies": true, "bundledDependencies": ["foo"]}"#,
+    )
+    .unwrap();
+    let bundled = parsed.bundle_dependencies().unwrap().unwrap();
+    assert_eq!(bundled, &BundleDependencies::All(true));
+    assert!(bundled.bundles_all());
+  }
+
+  #[test]
+  fn should_label_invalid_bundled_name() {
+    let json = r#"{"bundledDependencies": ["foo", "Bar"]}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .bundle_dependencies()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""Bar""#
+    );
+  }
+}
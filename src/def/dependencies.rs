@@ -1,3 +1,6 @@
+use crate::def::{
+  DependencySpec, parse_declared_spec, parse_declared_specs, validate_dependency_map,
+};
 use crate::ext::Validator;
 use derive_more::{Deref, DerefMut};
 use jsonc_parser::ast::ObjectProp;
@@ -36,15 +39,75 @@ impl<'de> Deserialize<'de> for Dependencies {
   }
 }
 
+impl Dependencies {
+  /// Parses the specifier `dependencies` declares for `name`, or `None` when
+  /// it is not declared.
+  pub fn spec(&self, name: &str) -> Option<Result<DependencySpec, String>> {
+    parse_declared_spec(self, name)
+  }
+
+  /// Parses every specifier in `dependencies`, failing on the first invalid one.
+  pub fn specs(&self) -> Result<FxHashMap<&str, DependencySpec>, String> {
+    parse_declared_specs(self)
+  }
+}
+
 impl Validator for Dependencies {
-  fn validate(&self, _prop: Option<&ObjectProp>) -> miette::Result<()> {
-    Ok(())
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    validate_dependency_map(self, prop)
   }
 }
 
 #[cfg(test)]
 mod tests {
+  use super::Dependencies;
   use crate::PackageJsonParser;
+  use rustc_hash::FxHashMap;
+
+  #[test]
+  fn should_pass_validate_dependencies() {
+    let parsed = PackageJsonParser::parse_str(
+      r#"{"dependencies":{"foo":"^1.0.0", "bar":"npm:baz@latest", "ws":"workspace:*"}}"#,
+    )
+    .unwrap();
+    assert!(parsed.validate().is_ok());
+  }
+
+  #[test]
+  fn should_fail_validate_dependencies_when_spec_is_invalid() {
+    let parsed =
+      PackageJsonParser::parse_str(r#"{"dependencies":{"foo":"^1.0.0.", "bar": "^1.0.0"}}"#)
+        .unwrap();
+    assert!(parsed.validate().is_err());
+    assert!(parsed.dependencies().is_err());
+  }
+
+  #[test]
+  fn should_label_invalid_dependency_spec() {
+    let json = r#"{"dependencies":{"foo":"^1.0.0.", "bar": "^1.0.0"}}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .dependencies()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""^1.0.0.""#
+    );
+  }
+
+  #[test]
+  fn should_report_invalid_specs_instead_of_dropping_them() {
+    let dependencies = Dependencies(FxHashMap::from_iter([
+      ("foo".to_string(), "^1.0.0.".to_string()),
+      ("bar".to_string(), "^1.0.0".to_string()),
+    ]));
+
+    assert!(dependencies.spec("foo").unwrap().is_err());
+    assert!(dependencies.spec("bar").unwrap().is_ok());
+    assert!(dependencies.spec("baz").is_none());
+    assert!(dependencies.specs().unwrap_err().starts_with("`foo`"));
+  }
 
   #[test]
   fn should_deserialize_dependencies_successfully() {
@@ -0,0 +1,219 @@
+use derive_more::{Deref, DerefMut};
+use indexmap::IndexMap;
+use jsonc_parser::ast::ObjectProp;
+use serde::de::{self, IgnoredAny, MapAccess, Visitor, value::MapAccessDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::DEPENDENCIES_META_INVALID_SELECTOR;
+use crate::def::{DependencySpec, is_valid_package_name, split_selector};
+use crate::ext::{Validator, key_range, validation_error};
+
+/// Install settings for one package, as read by pnpm and yarn.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Default)]
+pub struct DependencyMeta {
+  /// pnpm: hard-link a local dependency instead of symlinking it, so it
+  /// resolves its peers from the dependent package.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub injected: Option<bool>,
+  /// yarn: `false` skips the package's build scripts.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub built: Option<bool>,
+  /// yarn: unpack the package to disk instead of reading it from its zip.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unplugged: Option<bool>,
+}
+
+impl<'de> Deserialize<'de> for DependencyMeta {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct DependencyMetaVisitor;
+
+    impl<'de> Visitor<'de> for DependencyMetaVisitor {
+      type Value = DependencyMeta;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object for dependenciesMeta entry")
+      }
+
+      fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let mut meta = DependencyMeta::default();
+
+        while let Some(key) = map.next_key::<String>()? {
+          let (slot, field) = match key.as_str() {
+            "injected" => (&mut meta.injected, "injected"),
+            "built" => (&mut meta.built, "built"),
+            "unplugged" => (&mut meta.unplugged, "unplugged"),
+            _ => {
+              let _: IgnoredAny = map.next_value()?;
+              continue;
+            }
+          };
+          if slot.is_some() {
+            return Err(de::Error::duplicate_field(field));
+          }
+          *slot = Some(map.next_value()?);
+        }
+
+        Ok(meta)
+      }
+    }
+
+    deserializer.deserialize_map(DependencyMetaVisitor)
+  }
+}
+
+/// The `dependenciesMeta` field: `<name>` or `<name>@<version>` selectors
+/// mapped to install settings.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Deref, DerefMut)]
+pub struct DependenciesMeta(IndexMap<String, DependencyMeta>);
+
+impl<'de> Deserialize<'de> for DependenciesMeta {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct DependenciesMetaVisitor;
+
+    impl<'de> Visitor<'de> for DependenciesMetaVisitor {
+      type Value = DependenciesMeta;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object map for dependenciesMeta")
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let value =
+          IndexMap::<String, DependencyMeta>::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(DependenciesMeta(value))
+      }
+    }
+
+    deserializer.deserialize_any(DependenciesMetaVisitor)
+  }
+}
+
+impl DependenciesMeta {
+  /// The entries that apply to `name`: the plain `<name>` entry and the
+  /// `<name>@<version>` ones, in declaration order.
+  pub fn for_package(&self, name: &str) -> Vec<(&str, &DependencyMeta)> {
+    self
+      .iter()
+      .filter(|(selector, _)| split_selector(selector).0 == name)
+      .map(|(selector, meta)| (selector.as_str(), meta))
+      .collect()
+  }
+
+  /// Names of the packages marked `injected`.
+  pub fn injected(&self) -> Vec<&str> {
+    self
+      .iter()
+      .filter(|(_, meta)| meta.injected == Some(true))
+      .map(|(selector, _)| split_selector(selector).0)
+      .collect()
+  }
+}
+
+impl Validator for DependenciesMeta {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    for selector in self.keys() {
+      let (name, version) = split_selector(selector);
+      let help = if !is_valid_package_name(name) {
+        Some(format!("`{name}` is not a valid package name"))
+      } else {
+        match version {
+          None => None,
+          Some("") => Some("missing version after `@`".to_string()),
+          Some(version) => DependencySpec::parse(version).err(),
+        }
+      };
+
+      if let Some(help) = help {
+        return Err(validation_error(
+          "Invalid dependenciesMeta selector",
+          &DEPENDENCIES_META_INVALID_SELECTOR,
+          &format!("`{selector}` must be `<name>` or `<name>@<version>`: {help}"),
+          key_range(prop.map(|prop| &prop.value), selector),
+          "here",
+        ));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::PackageJsonParser;
+
+  #[test]
+  fn should_pass_validate_dependencies_meta() {
+    let jsones = [
+      r#"{"dependenciesMeta": {"shared": {"injected": true}}}"#,
+      r#"{"dependenciesMeta": {"fsevents": {"built": false}, "esbuild@0.19.0": {"unplugged": true}}}"#,
+      r#"{"dependenciesMeta": {"@scope/pkg": {"other": "ignored"}}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_dependencies_meta() {
+    let jsones = [
+      r#"{"dependenciesMeta": {"Shared": {"injected": true}}}"#,
+      r#"{"dependenciesMeta": {"é": {"injected": true}}}"#,
+      r#"{"dependenciesMeta": {"shared@": {"injected": true}}}"#,
+      r#"{"dependenciesMeta": {"shared": {"injected": "yes"}}}"#,
+      r#"{"dependenciesMeta": {"shared": true}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_read_dependencies_meta_per_package() {
+    let json = r#"{"dependenciesMeta": {
+      "esbuild": {"built": true},
+      "esbuild@0.19.0": {"unplugged": true},
+      "shared": {"injected": true}
+    }}"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+    let meta = parser.dependencies_meta().unwrap().unwrap();
+
+    let esbuild = meta.for_package("esbuild");
+    assert_eq!(esbuild.len(), 2);
+    assert_eq!(esbuild[1].0, "esbuild@0.19.0");
+    assert_eq!(esbuild[1].1.unplugged, Some(true));
+    assert_eq!(meta.injected(), ["shared"]);
+  }
+
+  #[test]
+  fn should_label_invalid_dependencies_meta_selector() {
+    let json =
+      r#"{"dependenciesMeta": {"shared": {"injected": true}, "shared@": {"injected": true}}}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .dependencies_meta()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""shared@""#
+    );
+  }
+}
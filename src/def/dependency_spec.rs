@@ -0,0 +1,322 @@
+use jsonc_parser::ast::ObjectProp;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use crate::codes::DEPENDENCIES_INVALID_SPEC;
+use crate::def::is_valid_package_name;
+use crate::ext::{validation_error, value_range};
+use crate::semver::VersionRange;
+
+const GIT_PREFIXES: &[&str] = &[
+  "git+ssh://",
+  "git+https://",
+  "git+http://",
+  "git+file://",
+  "git://",
+];
+
+const HOSTED_GIT_PREFIXES: &[&str] = &["gitlab:", "bitbucket:", "gist:"];
+
+/// A parsed dependency specifier, following npm-package-arg's classification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum DependencySpec {
+  /// A semver range such as `^1.2.0` or `1.x || >=2.5`.
+  Range(VersionRange),
+  /// A dist-tag such as `latest` or `next`.
+  Tag(String),
+  /// `npm:<name>@<spec>` installs `name` under a different key.
+  Alias {
+    name: String,
+    spec: Box<DependencySpec>,
+  },
+  /// `workspace:*`, `workspace:^`, `workspace:~`, a range or a relative path.
+  Workspace(String),
+  /// `catalog:` or `catalog:<name>` (pnpm).
+  Catalog(String),
+  /// `file:<path>` or a bare relative/absolute path.
+  File(String),
+  /// `link:<path>`.
+  Link(String),
+  /// A git URL or a `gitlab:`/`bitbucket:`/`gist:` shortcut.
+  Git {
+    url: String,
+    committish: Option<String>,
+  },
+  /// `owner/repo` or `github:owner/repo`, optionally with `#committish`.
+  GitHub {
+    owner: String,
+    repo: String,
+    committish: Option<String>,
+  },
+  /// An `http(s)://` tarball URL.
+  Tarball(String),
+}
+
+impl DependencySpec {
+  pub fn parse(spec: &str) -> Result<Self, String> {
+    if let Some(rest) = spec.strip_prefix("npm:") {
+      return Self::parse_alias(rest);
+    }
+
+    if let Some(rest) = spec.strip_prefix("workspace:") {
+      let valid = matches!(rest, "*" | "^" | "~")
+        || rest.starts_with('.')
+        || rest.starts_with('/')
+        || VersionRange::parse(rest).is_ok();
+      if !valid {
+        return Err(format!("`{rest}` is not a valid workspace range or path"));
+      }
+      return Ok(DependencySpec::Workspace(rest.to_string()));
+    }
+
+    if let Some(rest) = spec.strip_prefix("catalog:") {
+      return Ok(DependencySpec::Catalog(rest.to_string()));
+    }
+
+    if let Some(rest) = spec.strip_prefix("file:") {
+      return non_empty(rest, "file path").map(DependencySpec::File);
+    }
+
+    if let Some(rest) = spec.strip_prefix("link:") {
+      return non_empty(rest, "link path").map(DependencySpec::Link);
+    }
+
+    if ["./", "../", "/", "~/"]
+      .iter()
+      .any(|prefix| spec.starts_with(prefix))
+    {
+      return Ok(DependencySpec::File(spec.to_string()));
+    }
+
+    if GIT_PREFIXES
+      .iter()
+      .chain(HOSTED_GIT_PREFIXES)
+      .any(|prefix| spec.starts_with(prefix))
+    {
+      let (url, committish) = split_committish(spec);
+      return Ok(DependencySpec::Git {
+        url: non_empty(url, "git url")?,
+        committish,
+      });
+    }
+
+    if spec.starts_with("http://") || spec.starts_with("https://") {
+      return Ok(DependencySpec::Tarball(spec.to_string()));
+    }
+
+    let shorthand = spec.strip_prefix("github:").unwrap_or(spec);
+    if spec.starts_with("github:")
+      || lazy_regex::regex_is_match!(r"^[A-Za-z0-9][A-Za-z0-9-]*/[A-Za-z0-9._-]+(#.*)?$", spec)
+    {
+      let (path, committish) = split_committish(shorthand);
+      let Some((owner, repo)) = path.split_once('/') else {
+        return Err(format!("`{spec}` is not a valid GitHub shorthand"));
+      };
+      return Ok(DependencySpec::GitHub {
+        owner: non_empty(owner, "GitHub owner")?,
+        repo: non_empty(repo, "GitHub repository")?,
+        committish,
+      });
+    }
+
+    Self::parse_registry(spec)
+  }
+
+  fn parse_alias(rest: &str) -> Result<Self, String> {
+    let (name, spec) = match rest.rfind('@') {
+      Some(index) if index > 0 => (&rest[..index], &rest[index + 1..]),
+      _ => (rest, ""),
+    };
+
+    if !is_valid_package_name(name) {
+      return Err(format!(
+        "`{name}` is not a valid package name for an npm alias"
+      ));
+    }
+
+    let spec = match spec {
+      "" => DependencySpec::Range(VersionRange::parse("*")?),
+      spec => Self::parse_registry(spec)?,
+    };
+
+    Ok(DependencySpec::Alias {
+      name: name.to_string(),
+      spec: Box::new(spec),
+    })
+  }
+
+  fn parse_registry(spec: &str) -> Result<Self, String> {
+    match VersionRange::parse(spec) {
+      Ok(range) => Ok(DependencySpec::Range(range)),
+      Err(_) if is_valid_tag(spec) => Ok(DependencySpec::Tag(spec.to_string())),
+      Err(reason) => Err(format!("`{spec}` is not a valid version range: {reason}")),
+    }
+  }
+}
+
+/// Tags must survive `encodeURIComponent` unchanged and must not look like a
+/// mistyped version, so `1.0.0.` is reported instead of read as a tag.
+fn is_valid_tag(spec: &str) -> bool {
+  lazy_regex::regex_is_match!(r"^[A-Za-z0-9\-_.!~*'()]+$", spec)
+    && !lazy_regex::regex_is_match!(r"^(?:[~^=<>]|[vV]?[0-9])", spec)
+}
+
+fn split_committish(spec: &str) -> (&str, Option<String>) {
+  match spec.split_once('#') {
+    Some((url, committish)) => (url, Some(committish.to_string())),
+    None => (spec, None),
+  }
+}
+
+fn non_empty(value: &str, what: &str) -> Result<String, String> {
+  if value.is_empty() {
+    return Err(format!("missing {what}"));
+  }
+  Ok(value.to_string())
+}
+
+/// Splits a selector into the package name and the optional version spec,
+/// e.g. `@scope/pkg@^1` into `@scope/pkg` and `^1`.
+pub(crate) fn split_selector(selector: &str) -> (&str, Option<&str>) {
+  let scope = usize::from(selector.starts_with('@'));
+  match selector[scope..].find('@') {
+    Some(index) => (
+      &selector[..scope + index],
+      Some(&selector[scope + index + 1..]),
+    ),
+    None => (selector, None),
+  }
+}
+
+/// Parses the specifier declared for `name`, or `None` when it is not
+/// declared.
+pub(crate) fn parse_declared_spec(
+  map: &FxHashMap<String, String>,
+  name: &str,
+) -> Option<Result<DependencySpec, String>> {
+  map.get(name).map(|spec| DependencySpec::parse(spec))
+}
+
+/// Parses every value of a dependency map, failing on the first invalid one
+/// by name.
+pub(crate) fn parse_declared_specs(
+  map: &FxHashMap<String, String>,
+) -> Result<FxHashMap<&str, DependencySpec>, String> {
+  let mut names = map.keys().collect::<Vec<_>>();
+  names.sort();
+
+  names
+    .into_iter()
+    .map(|name| {
+      DependencySpec::parse(&map[name])
+        .map(|spec| (name.as_str(), spec))
+        .map_err(|reason| format!("`{name}`: {reason}"))
+    })
+    .collect()
+}
+
+/// Parses every value of a dependency map, labeling the first invalid one.
+pub(crate) fn validate_dependency_map(
+  map: &FxHashMap<String, String>,
+  prop: Option<&ObjectProp>,
+) -> miette::Result<()> {
+  let mut names = map.keys().collect::<Vec<_>>();
+  names.sort();
+
+  for name in names {
+    if let Err(reason) = DependencySpec::parse(&map[name]) {
+      return Err(validation_error(
+        "Invalid dependency specifier",
+        &DEPENDENCIES_INVALID_SPEC,
+        &format!("`{name}`: {reason}"),
+        value_range(prop, &[name]),
+        "here",
+      ));
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{DependencySpec, split_selector};
+
+  #[test]
+  fn should_classify_dependency_specs() {
+    let cases = [
+      ("^1.0.0", "Range"),
+      ("", "Range"),
+      ("1.x || >=2.5.0", "Range"),
+      ("latest", "Tag"),
+      ("next-beta", "Tag"),
+      ("npm:@scope/pkg@^2", "Alias"),
+      ("npm:pkg", "Alias"),
+      ("workspace:*", "Workspace"),
+      ("workspace:^1.0.0", "Workspace"),
+      ("catalog:", "Catalog"),
+      ("file:../pkg", "File"),
+      ("./vendor/pkg", "File"),
+      ("link:../pkg", "Link"),
+      ("git+ssh://git@github.com:npm/cli.git#v1.0.27", "Git"),
+      ("gitlab:owner/repo", "Git"),
+      ("expressjs/express#4.x", "GitHub"),
+      ("github:owner/repo", "GitHub"),
+      ("https://example.com/pkg.tgz", "Tarball"),
+    ];
+
+    for (spec, kind) in cases {
+      let parsed = DependencySpec::parse(spec).unwrap();
+      let actual = match parsed {
+        DependencySpec::Range(_) => "Range",
+        DependencySpec::Tag(_) => "Tag",
+        DependencySpec::Alias { .. } => "Alias",
+        DependencySpec::Workspace(_) => "Workspace",
+        DependencySpec::Catalog(_) => "Catalog",
+        DependencySpec::File(_) => "File",
+        DependencySpec::Link(_) => "Link",
+        DependencySpec::Git { .. } => "Git",
+        DependencySpec::GitHub { .. } => "GitHub",
+        DependencySpec::Tarball(_) => "Tarball",
+      };
+      assert_eq!(actual, kind, "{spec}");
+    }
+  }
+
+  #[test]
+  fn should_reject_invalid_dependency_specs() {
+    let specs = [
+      "^1.0.0.",
+      "1.0.0.",
+      ">=",
+      "npm:Bad Name@1",
+      "npm:pkg@^1.0.0.",
+      "workspace:foo bar",
+      "file:",
+      "a b",
+    ];
+
+    for spec in specs {
+      assert!(DependencySpec::parse(spec).is_err(), "{spec}");
+    }
+  }
+
+  #[test]
+  fn should_split_selectors() {
+    let cases = [
+      ("foo", ("foo", None)),
+      ("foo@^1", ("foo", Some("^1"))),
+      ("@scope/foo", ("@scope/foo", None)),
+      ("@scope/foo@1.0.0", ("@scope/foo", Some("1.0.0"))),
+      ("foo@", ("foo", Some(""))),
+      ("é", ("é", None)),
+      ("éa@1", ("éa", Some("1"))),
+      ("@", ("@", None)),
+      ("", ("", None)),
+    ];
+
+    for (selector, expected) in cases {
+      assert_eq!(split_selector(selector), expected, "{selector}");
+    }
+  }
+}
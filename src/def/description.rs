@@ -16,7 +16,7 @@ impl<'de> Deserialize<'de> for Description {
   {
     struct DescriptionVisitor;
 
-    impl<'de> Visitor<'de> for DescriptionVisitor {
+    impl Visitor<'_> for DescriptionVisitor {
       type Value = Description;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
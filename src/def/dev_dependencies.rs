@@ -5,6 +5,9 @@ use serde::de::{MapAccess, Visitor, value::MapAccessDeserializer};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 
+use crate::def::{
+  DependencySpec, parse_declared_spec, parse_declared_specs, validate_dependency_map,
+};
 use crate::ext::Validator;
 
 #[derive(Debug, Serialize, Clone, Deref, DerefMut, Default)]
@@ -37,9 +40,22 @@ impl<'de> Deserialize<'de> for DevDependencies {
   }
 }
 
+impl DevDependencies {
+  /// Parses the specifier `devDependencies` declares for `name`, or `None` when
+  /// it is not declared.
+  pub fn spec(&self, name: &str) -> Option<Result<DependencySpec, String>> {
+    parse_declared_spec(self, name)
+  }
+
+  /// Parses every specifier in `devDependencies`, failing on the first invalid one.
+  pub fn specs(&self) -> Result<FxHashMap<&str, DependencySpec>, String> {
+    parse_declared_specs(self)
+  }
+}
+
 impl Validator for DevDependencies {
-  fn validate(&self, _prop: Option<&ObjectProp>) -> miette::Result<()> {
-    Ok(())
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    validate_dependency_map(self, prop)
   }
 }
 
@@ -47,6 +63,23 @@ impl Validator for DevDependencies {
 mod tests {
   use crate::PackageJsonParser;
 
+  #[test]
+  fn should_pass_validate_dev_dependencies() {
+    let parsed = PackageJsonParser::parse_str(
+      r#"{"devDependencies":{"typescript":"~5.4", "local":"file:../local"}}"#,
+    )
+    .unwrap();
+    assert!(parsed.validate().is_ok());
+  }
+
+  #[test]
+  fn should_fail_validate_dev_dependencies_when_spec_is_invalid() {
+    let parsed =
+      PackageJsonParser::parse_str(r#"{"devDependencies":{"typescript":"~5.4.x.1"}}"#).unwrap();
+    assert!(parsed.validate().is_err());
+    assert!(parsed.dev_dependencies().is_err());
+  }
+
   #[test]
   fn should_deserialize_dev_dependencies_successfully() {
     let parsed = PackageJsonParser::parse_str(r#"{"devDependencies":{"foo":"^1.0.0"}}"#);
@@ -16,7 +16,7 @@ impl<'de> Deserialize<'de> for EngineStrict {
   {
     struct EngineStrictVisitor;
 
-    impl<'de> Visitor<'de> for EngineStrictVisitor {
+    impl Visitor<'_> for EngineStrictVisitor {
       type Value = EngineStrict;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,360 @@
+use indexmap::IndexMap;
+use jsonc_parser::ast::{ObjectProp, Value};
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::{
+  EXPORTS_INVALID_SUBPATH, EXPORTS_INVALID_TARGET, EXPORTS_INVALID_TARGET_SEGMENT,
+  EXPORTS_MIXED_KEYS, EXPORTS_MULTIPLE_WILDCARDS, EXPORTS_NESTED_SUBPATH,
+  EXPORTS_NUMERIC_CONDITION,
+};
+use crate::ext::{Validator, array_child, node_range, object_child, validation_error};
+
+/// A single `exports` target: a path, `null`, a condition object or a fallback array.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum ExportsTarget {
+  Null,
+  Path(String),
+  Conditions(IndexMap<String, ExportsTarget>),
+  Fallback(Vec<ExportsTarget>),
+}
+
+impl<'de> Deserialize<'de> for ExportsTarget {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct ExportsTargetVisitor;
+
+    impl<'de> Visitor<'de> for ExportsTargetVisitor {
+      type Value = ExportsTarget;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string, null, an object or an array for exports target")
+      }
+
+      fn visit_unit<E>(self) -> Result<Self::Value, E>
+      where
+        E: serde::de::Error,
+      {
+        Ok(ExportsTarget::Null)
+      }
+
+      fn visit_none<E>(self) -> Result<Self::Value, E>
+      where
+        E: serde::de::Error,
+      {
+        Ok(ExportsTarget::Null)
+      }
+
+      fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+      where
+        E: serde::de::Error,
+      {
+        Ok(ExportsTarget::Path(value.to_string()))
+      }
+
+      fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+      where
+        E: serde::de::Error,
+      {
+        Ok(ExportsTarget::Path(value))
+      }
+
+      fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+      where
+        S: SeqAccess<'de>,
+      {
+        let value = Vec::<ExportsTarget>::deserialize(SeqAccessDeserializer::new(seq))?;
+        Ok(ExportsTarget::Fallback(value))
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let value =
+          IndexMap::<String, ExportsTarget>::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(ExportsTarget::Conditions(value))
+      }
+    }
+
+    deserializer.deserialize_any(ExportsTargetVisitor)
+  }
+}
+
+/// The `exports` field.
+///
+/// An object whose keys start with `.` is a subpath map, any other object is a
+/// condition map for the `"."` entry. Objects mixing both kinds of keys are
+/// kept as `Subpaths` and rejected by validation.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum Exports {
+  Null,
+  Path(String),
+  Fallback(Vec<ExportsTarget>),
+  Conditions(IndexMap<String, ExportsTarget>),
+  Subpaths(IndexMap<String, ExportsTarget>),
+}
+
+impl<'de> Deserialize<'de> for Exports {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let exports = match ExportsTarget::deserialize(deserializer)? {
+      ExportsTarget::Null => Exports::Null,
+      ExportsTarget::Path(path) => Exports::Path(path),
+      ExportsTarget::Fallback(items) => Exports::Fallback(items),
+      ExportsTarget::Conditions(map) if map.keys().any(|key| key.starts_with('.')) => {
+        Exports::Subpaths(map)
+      }
+      ExportsTarget::Conditions(map) => Exports::Conditions(map),
+    };
+
+    Ok(exports)
+  }
+}
+
+/// Whether a target starts with a URL scheme such as `https:` or `node:`.
+pub(crate) fn is_url(target: &str) -> bool {
+  lazy_regex::regex_is_match!(r"^[a-zA-Z][a-zA-Z0-9+.-]*:", target)
+}
+
+/// Whether a path, split on `/` and `\`, has an empty, `.`, `..` or
+/// `node_modules` segment, which Node.js rejects in targets and pattern
+/// matches.
+pub(crate) fn has_invalid_segment(path: &str) -> bool {
+  path.split(['/', '\\']).any(|segment| {
+    segment.is_empty()
+      || segment == "."
+      || segment == ".."
+      || segment.eq_ignore_ascii_case("node_modules")
+  })
+}
+
+fn validate_target_path(path: &str, node: Option<&Value>) -> miette::Result<()> {
+  let Some(rest) = path.strip_prefix("./") else {
+    return Err(validation_error(
+      "Invalid exports target",
+      &EXPORTS_INVALID_TARGET,
+      "Exports targets must be relative paths starting with `./`",
+      node_range(node),
+      "here",
+    ));
+  };
+
+  if has_invalid_segment(rest) {
+    return Err(validation_error(
+      "Invalid exports target",
+      &EXPORTS_INVALID_TARGET_SEGMENT,
+      "Exports targets must not contain empty, `.`, `..` or `node_modules` segments",
+      node_range(node),
+      "here",
+    ));
+  }
+
+  Ok(())
+}
+
+fn validate_conditions(
+  conditions: &IndexMap<String, ExportsTarget>,
+  node: Option<&Value>,
+) -> miette::Result<()> {
+  for (key, target) in conditions {
+    let child = object_child(node, key);
+
+    if key.starts_with('.') {
+      return Err(validation_error(
+        "Invalid exports condition",
+        &EXPORTS_NESTED_SUBPATH,
+        "Subpath keys starting with `.` are only allowed at the top level of exports",
+        node_range(child),
+        "here",
+      ));
+    }
+
+    if key.parse::<u32>().is_ok() {
+      return Err(validation_error(
+        "Invalid exports condition",
+        &EXPORTS_NUMERIC_CONDITION,
+        "Condition keys must not be numeric",
+        node_range(child),
+        "here",
+      ));
+    }
+
+    validate_target(target, child)?;
+  }
+
+  Ok(())
+}
+
+fn validate_target(target: &ExportsTarget, node: Option<&Value>) -> miette::Result<()> {
+  match target {
+    ExportsTarget::Null => Ok(()),
+    ExportsTarget::Path(path) => validate_target_path(path, node),
+    ExportsTarget::Conditions(conditions) => validate_conditions(conditions, node),
+    ExportsTarget::Fallback(items) => {
+      for (index, item) in items.iter().enumerate() {
+        validate_target(item, array_child(node, index))?;
+      }
+      Ok(())
+    }
+  }
+}
+
+fn validate_subpaths(
+  subpaths: &IndexMap<String, ExportsTarget>,
+  node: Option<&Value>,
+) -> miette::Result<()> {
+  for (key, target) in subpaths {
+    let child = object_child(node, key);
+
+    if !key.starts_with('.') {
+      return Err(validation_error(
+        "Invalid exports",
+        &EXPORTS_MIXED_KEYS,
+        "Exports cannot mix subpath keys starting with `.` and condition keys",
+        node_range(child),
+        "here",
+      ));
+    }
+
+    if key != "." && !key.starts_with("./") {
+      return Err(validation_error(
+        "Invalid exports subpath",
+        &EXPORTS_INVALID_SUBPATH,
+        "Subpath keys must be `.` or start with `./`",
+        node_range(child),
+        "here",
+      ));
+    }
+
+    if key.matches('*').count() > 1 {
+      return Err(validation_error(
+        "Invalid exports subpath",
+        &EXPORTS_MULTIPLE_WILDCARDS,
+        "Subpath patterns may contain at most one `*`",
+        node_range(child),
+        "here",
+      ));
+    }
+
+    validate_target(target, child)?;
+  }
+
+  Ok(())
+}
+
+impl Validator for Exports {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    let node = prop.map(|prop| &prop.value);
+
+    match self {
+      Exports::Null => Ok(()),
+      Exports::Path(path) => validate_target_path(path, node),
+      Exports::Fallback(items) => {
+        for (index, item) in items.iter().enumerate() {
+          validate_target(item, array_child(node, index))?;
+        }
+        Ok(())
+      }
+      Exports::Conditions(conditions) => validate_conditions(conditions, node),
+      Exports::Subpaths(subpaths) => validate_subpaths(subpaths, node),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{Exports, PackageJsonParser};
+
+  #[test]
+  fn should_pass_validate_exports() {
+    let jsones = [
+      r#"{"exports": "./index.js"}"#,
+      r#"{"exports": null}"#,
+      r#"{"exports": ["./index.mjs", "./index.cjs"]}"#,
+      r#"{"exports": {"import": "./index.mjs", "require": "./index.cjs"}}"#,
+      r#"{"exports": {".": "./index.js", "./feature/*": "./src/feature/*.js", "./internal/*": null}}"#,
+      r#"{"exports": {".": {"types": "./index.d.ts", "node": {"import": "./node.mjs"}, "default": "./index.js"}}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      let res = res.validate();
+      assert!(res.is_ok());
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_exports() {
+    let jsones = [
+      r#"{"exports": "index.js"}"#,
+      r#"{"exports": "./../index.js"}"#,
+      r#"{"exports": "./a//b.js"}"#,
+      r#"{"exports": {".": "./index.js", "import": "./index.mjs"}}"#,
+      r#"{"exports": {"./a": {"./b": "./b.js"}}}"#,
+      r#"{"exports": {"./a/*/*": "./a/*.js"}}"#,
+      r#"{"exports": {"import": ["./ok.js", "bad.js"]}}"#,
+      r#"{"exports": {"0": "./index.js"}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      let res = res.validate();
+      assert!(res.is_err());
+    }
+  }
+
+  #[test]
+  fn should_label_invalid_exports_target_span() {
+    let json = r#"{"exports": {".": {"import": "./ok.mjs", "require": "bad.cjs"}}}"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+
+    let report = parser.exports().unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""bad.cjs""#
+    );
+  }
+
+  #[test]
+  fn should_deserialize_exports_forms() {
+    let parser =
+      PackageJsonParser::parse_str(r#"{"exports": {"require": "./a.cjs", "import": "./a.mjs"}}"#)
+        .unwrap();
+    let Some(Exports::Conditions(conditions)) = parser.exports().unwrap() else {
+      panic!("expected condition exports");
+    };
+    assert_eq!(
+      conditions.keys().collect::<Vec<_>>(),
+      vec!["require", "import"]
+    );
+
+    let parser = PackageJsonParser::parse_str(r#"{"exports": {".": "./a.js"}}"#).unwrap();
+    assert!(matches!(
+      parser.exports().unwrap(),
+      Some(Exports::Subpaths(_))
+    ));
+  }
+
+  #[test]
+  fn should_fail_deserialize_exports_when_type_is_invalid() {
+    let parsed = PackageJsonParser::parse_str(r#"{"exports":123}"#);
+    assert!(parsed.is_ok());
+    let parsed = parsed.unwrap();
+    assert!(parsed.exports().is_err());
+  }
+
+  #[test]
+  fn should_fail_deserialize_exports_when_json_is_invalid() {
+    let parsed = PackageJsonParser::parse_str("{");
+    assert!(parsed.is_err());
+  }
+}
@@ -0,0 +1,355 @@
+use jsonc_parser::ast::{ObjectProp, Value};
+use serde::de::{
+  self, IgnoredAny, MapAccess, SeqAccess, Visitor, value::MapAccessDeserializer,
+  value::SeqAccessDeserializer,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use validator::ValidateUrl;
+
+use crate::codes::FUNDING_INVALID_URL;
+use crate::ext::{Validator, array_child, node_range, object_child, validation_error};
+
+/// A funding source in the `{ "type": ..., "url": ... }` form that npm
+/// normalizes every `funding` value to.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub struct FundingEntry {
+  /// The kind of platform, e.g. `github`, `opencollective` or `individual`.
+  #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  pub url: String,
+}
+
+impl<'de> Deserialize<'de> for FundingEntry {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    const FIELDS: &[&str] = &["type", "url"];
+
+    enum Field {
+      Type,
+      Url,
+      Ignore,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+      fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+      where
+        D: Deserializer<'de>,
+      {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+          type Value = Field;
+
+          fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("`type` or `url`")
+          }
+
+          fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+          where
+            E: de::Error,
+          {
+            Ok(match value {
+              "type" => Field::Type,
+              "url" => Field::Url,
+              _ => Field::Ignore,
+            })
+          }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+      }
+    }
+
+    struct FundingEntryVisitor;
+
+    impl<'de> Visitor<'de> for FundingEntryVisitor {
+      type Value = FundingEntry;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object with `url` and optional `type`")
+      }
+
+      fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let mut r#type = None;
+        let mut url = None;
+
+        while let Some(key) = map.next_key::<Field>()? {
+          match key {
+            Field::Type => {
+              if r#type.is_some() {
+                return Err(de::Error::duplicate_field("type"));
+              }
+              r#type = Some(map.next_value()?);
+            }
+            Field::Url => {
+              if url.is_some() {
+                return Err(de::Error::duplicate_field("url"));
+              }
+              url = Some(map.next_value()?);
+            }
+            Field::Ignore => {
+              let _: IgnoredAny = map.next_value()?;
+            }
+          }
+        }
+
+        Ok(FundingEntry {
+          r#type,
+          url: url.ok_or_else(|| de::Error::missing_field("url"))?,
+        })
+      }
+    }
+
+    deserializer.deserialize_struct("FundingEntry", FIELDS, FundingEntryVisitor)
+  }
+}
+
+/// One funding source: a bare URL or a [`FundingEntry`].
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum FundingSource {
+  Url(String),
+  Entry(FundingEntry),
+}
+
+impl<'de> Deserialize<'de> for FundingSource {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct FundingSourceVisitor;
+
+    impl<'de> Visitor<'de> for FundingSourceVisitor {
+      type Value = FundingSource;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a URL or an object for funding")
+      }
+
+      fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+      where
+        E: de::Error,
+      {
+        Ok(FundingSource::Url(value.to_string()))
+      }
+
+      fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+      where
+        E: de::Error,
+      {
+        Ok(FundingSource::Url(value))
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let entry = FundingEntry::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(FundingSource::Entry(entry))
+      }
+    }
+
+    deserializer.deserialize_any(FundingSourceVisitor)
+  }
+}
+
+impl FundingSource {
+  pub fn url(&self) -> &str {
+    match self {
+      FundingSource::Url(url) => url,
+      FundingSource::Entry(entry) => &entry.url,
+    }
+  }
+
+  /// The object form; a bare URL has no `type`.
+  pub fn to_entry(&self) -> FundingEntry {
+    match self {
+      FundingSource::Url(url) => FundingEntry {
+        r#type: None,
+        url: url.clone(),
+      },
+      FundingSource::Entry(entry) => entry.clone(),
+    }
+  }
+}
+
+/// The `funding` field: one source or an array of them.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum Funding {
+  Source(FundingSource),
+  List(Vec<FundingSource>),
+}
+
+impl<'de> Deserialize<'de> for Funding {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct FundingVisitor;
+
+    impl<'de> Visitor<'de> for FundingVisitor {
+      type Value = Funding;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a URL, an object or an array of them for funding")
+      }
+
+      fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+      where
+        E: de::Error,
+      {
+        Ok(Funding::Source(FundingSource::Url(value.to_string())))
+      }
+
+      fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+      where
+        E: de::Error,
+      {
+        Ok(Funding::Source(FundingSource::Url(value)))
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let entry = FundingEntry::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Funding::Source(FundingSource::Entry(entry)))
+      }
+
+      fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+      where
+        S: SeqAccess<'de>,
+      {
+        let sources = Vec::<FundingSource>::deserialize(SeqAccessDeserializer::new(seq))?;
+        Ok(Funding::List(sources))
+      }
+    }
+
+    deserializer.deserialize_any(FundingVisitor)
+  }
+}
+
+impl Funding {
+  pub fn sources(&self) -> &[FundingSource] {
+    match self {
+      Funding::Source(source) => std::slice::from_ref(source),
+      Funding::List(sources) => sources,
+    }
+  }
+
+  /// Every source in the object form, in declaration order, as `npm fund`
+  /// lists them.
+  pub fn entries(&self) -> Vec<FundingEntry> {
+    self.sources().iter().map(FundingSource::to_entry).collect()
+  }
+}
+
+fn validate_source(source: &FundingSource, node: Option<&Value>) -> miette::Result<()> {
+  if source.url().validate_url() {
+    return Ok(());
+  }
+
+  let node = match source {
+    FundingSource::Url(_) => node,
+    FundingSource::Entry(_) => object_child(node, "url"),
+  };
+  Err(validation_error(
+    "Invalid URL",
+    &FUNDING_INVALID_URL,
+    "Please provide a valid funding URL",
+    node_range(node),
+    "Invalid URL",
+  ))
+}
+
+impl Validator for Funding {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    let node = prop.map(|prop| &prop.value);
+    match self {
+      Funding::Source(source) => validate_source(source, node),
+      Funding::List(sources) => {
+        for (index, source) in sources.iter().enumerate() {
+          validate_source(source, array_child(node, index))?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{FundingEntry, PackageJsonParser};
+
+  #[test]
+  fn should_pass_validate_funding() {
+    let jsones = [
+      r#"{"funding": "https://github.com/sponsors/user"}"#,
+      r#"{"funding": {"type": "opencollective", "url": "https://opencollective.com/pkg"}}"#,
+      r#"{"funding": {"url": "https://example.com/donate"}}"#,
+      r#"{"funding": ["https://a.example.com", {"type": "patreon", "url": "https://patreon.com/u"}]}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_funding() {
+    let jsones = [
+      r#"{"funding": "not a url"}"#,
+      r#"{"funding": {"type": "github", "url": "invalid"}}"#,
+      r#"{"funding": {"type": "github"}}"#,
+      r#"{"funding": ["https://a.example.com", "invalid"]}"#,
+      r#"{"funding": [["https://a.example.com"]]}"#,
+      r#"{"funding": 1}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_label_invalid_funding_url() {
+    let json = r#"{"funding": ["https://a.example.com", {"type": "github", "url": "invalid"}]}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .funding()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""invalid""#
+    );
+  }
+
+  #[test]
+  fn should_normalize_funding_entries() {
+    let json = r#"{"funding": ["https://a.example.com", {"type": "github", "url": "https://github.com/sponsors/u"}]}"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+    let funding = parser.funding().unwrap().unwrap();
+
+    assert_eq!(
+      funding.entries(),
+      [
+        FundingEntry {
+          r#type: None,
+          url: "https://a.example.com".to_string(),
+        },
+        FundingEntry {
+          r#type: Some("github".to_string()),
+          url: "https://github.com/sponsors/u".to_string(),
+        },
+      ]
+    );
+  }
+}
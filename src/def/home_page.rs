@@ -15,7 +15,7 @@ impl<'de> Deserialize<'de> for HomePage {
   {
     struct HomePageVisitor;
 
-    impl<'de> Visitor<'de> for HomePageVisitor {
+    impl Visitor<'_> for HomePageVisitor {
       type Value = HomePage;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,231 @@
+use derive_more::{Deref, DerefMut};
+use indexmap::IndexMap;
+use jsonc_parser::ast::{ObjectProp, Value};
+use serde::de::{MapAccess, Visitor, value::MapAccessDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::{
+  IMPORTS_INVALID_KEY, IMPORTS_INVALID_TARGET, IMPORTS_MULTIPLE_WILDCARDS,
+  IMPORTS_NUMERIC_CONDITION, IMPORTS_RESERVED_KEY,
+};
+use crate::def::{ExportsTarget, has_invalid_segment, is_url};
+use crate::ext::{Validator, array_child, key_range, node_range, object_child, validation_error};
+
+/// The `imports` field: private `#`-prefixed specifiers mapped to paths in
+/// the package or to other packages.
+///
+/// Targets have the same shape as `exports` targets: a path, `null`, a
+/// condition object or a fallback array.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Deref, DerefMut)]
+pub struct Imports(IndexMap<String, ExportsTarget>);
+
+impl<'de> Deserialize<'de> for Imports {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct ImportsVisitor;
+
+    impl<'de> Visitor<'de> for ImportsVisitor {
+      type Value = Imports;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object map for imports")
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let value =
+          IndexMap::<String, ExportsTarget>::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Imports(value))
+      }
+    }
+
+    deserializer.deserialize_any(ImportsVisitor)
+  }
+}
+
+/// Paths must stay inside the package; anything not starting with `./` is
+/// resolved as a package, so it must not look like a path or URL.
+fn validate_target_path(path: &str, node: Option<&Value>) -> miette::Result<()> {
+  let help = match path.strip_prefix("./") {
+    Some(rest) => has_invalid_segment(rest)
+      .then_some("Import targets must not contain empty, `.`, `..` or `node_modules` segments"),
+    None if path.starts_with("../") || path.starts_with('/') || is_url(path) => {
+      Some("Import targets must start with `./` or name another package")
+    }
+    None => None,
+  };
+
+  match help {
+    Some(help) => Err(validation_error(
+      "Invalid imports target",
+      &IMPORTS_INVALID_TARGET,
+      help,
+      node_range(node),
+      "here",
+    )),
+    None => Ok(()),
+  }
+}
+
+fn validate_target(target: &ExportsTarget, node: Option<&Value>) -> miette::Result<()> {
+  match target {
+    ExportsTarget::Null => Ok(()),
+    ExportsTarget::Path(path) => validate_target_path(path, node),
+    ExportsTarget::Conditions(conditions) => {
+      for (condition, target) in conditions {
+        let child = object_child(node, condition);
+        if condition.parse::<u32>().is_ok() {
+          return Err(validation_error(
+            "Invalid imports condition",
+            &IMPORTS_NUMERIC_CONDITION,
+            "Condition keys must not be numeric",
+            node_range(child),
+            "here",
+          ));
+        }
+        validate_target(target, child)?;
+      }
+      Ok(())
+    }
+    ExportsTarget::Fallback(items) => {
+      for (index, item) in items.iter().enumerate() {
+        validate_target(item, array_child(node, index))?;
+      }
+      Ok(())
+    }
+  }
+}
+
+impl Validator for Imports {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    let node = prop.map(|prop| &prop.value);
+    for (key, target) in self.iter() {
+      let range = key_range(node, key);
+
+      if !key.starts_with('#') {
+        return Err(validation_error(
+          "Invalid imports key",
+          &IMPORTS_INVALID_KEY,
+          &format!("`{key}` must start with `#`"),
+          range,
+          "here",
+        ));
+      }
+
+      if key == "#" || key.starts_with("#/") {
+        return Err(validation_error(
+          "Invalid imports key",
+          &IMPORTS_RESERVED_KEY,
+          "`#` and keys starting with `#/` are reserved",
+          range,
+          "here",
+        ));
+      }
+
+      if key.matches('*').count() > 1 {
+        return Err(validation_error(
+          "Invalid imports key",
+          &IMPORTS_MULTIPLE_WILDCARDS,
+          "Import patterns may contain at most one `*`",
+          range,
+          "here",
+        ));
+      }
+
+      validate_target(target, object_child(node, key))?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{ExportsTarget, PackageJsonParser};
+
+  #[test]
+  fn should_pass_validate_imports() {
+    let jsones = [
+      r##"{"imports": {"#dep": "dep-polyfill"}}"##,
+      r##"{"imports": {"#internal/*.js": "./src/internal/*.js", "#gone": null}}"##,
+      r##"{"imports": {"#dep": {"node": "dep-node-native", "default": "./dep.js"}}}"##,
+      r##"{"imports": {"#dep": [{"import": "./dep.mjs"}, "./dep.cjs"]}}"##,
+      r##"{"imports": {"#scoped": "@scope/pkg/sub"}}"##,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_imports() {
+    let jsones = [
+      r##"{"imports": {"dep": "./dep.js"}}"##,
+      r##"{"imports": {"#": "./dep.js"}}"##,
+      r##"{"imports": {"#/dep": "./dep.js"}}"##,
+      r##"{"imports": {"#a/*/*": "./a/*.js"}}"##,
+      r##"{"imports": {"#dep": "../outside.js"}}"##,
+      r##"{"imports": {"#dep": "/abs.js"}}"##,
+      r##"{"imports": {"#dep": "https://example.com/dep.js"}}"##,
+      r##"{"imports": {"#dep": "./node_modules/dep.js"}}"##,
+      r##"{"imports": {"#dep": "./a//dep.js"}}"##,
+      r##"{"imports": {"#dep": {"0": "./dep.js"}}}"##,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_label_invalid_imports_spans() {
+    let json = r##"{"imports": {"#ok": "./ok.js", "bad": "./bad.js"}}"##;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .imports()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""bad""#
+    );
+
+    let json = r##"{"imports": {"#dep": ["./ok.js", {"node": "../bad.js"}]}}"##;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .imports()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""../bad.js""#
+    );
+  }
+
+  #[test]
+  fn should_deserialize_imports_targets() {
+    let json =
+      r##"{"imports": {"#a": "./a.js", "#b": {"node": "b"}, "#c": ["./c.js"], "#d": null}}"##;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+    let imports = parser.imports().unwrap().unwrap();
+
+    assert_eq!(imports["#a"], ExportsTarget::Path("./a.js".to_string()));
+    assert!(matches!(imports["#b"], ExportsTarget::Conditions(_)));
+    assert!(matches!(imports["#c"], ExportsTarget::Fallback(_)));
+    assert_eq!(imports["#d"], ExportsTarget::Null);
+  }
+
+  #[test]
+  fn should_fail_deserialize_imports_when_type_is_invalid() {
+    let parsed = PackageJsonParser::parse_str(r#"{"imports": ["./a.js"]}"#).unwrap();
+    assert!(parsed.imports().is_err());
+  }
+}
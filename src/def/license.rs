@@ -1,13 +1,119 @@
-use derive_more::{Deref, DerefMut};
-use jsonc_parser::ast::ObjectProp;
-use serde::de::Visitor;
+use jsonc_parser::ast::{ObjectProp, Value};
+use serde::de::{self, IgnoredAny, MapAccess, Visitor, value::MapAccessDeserializer};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
+use std::ops::{Deref, DerefMut};
+use validator::ValidateUrl;
 
-use crate::ext::{Validator, validation_error, value_range};
+use crate::codes::{LICENSE_INVALID_EXPRESSION, LICENSE_INVALID_URL};
+use crate::ext::{Validator, node_range, object_child, validation_error};
+use crate::spdx::{SpdxError, SpdxExpression};
 
-#[derive(Debug, PartialEq, Serialize, Clone, Deref, DerefMut)]
-pub struct License(String);
+/// The deprecated `{ "type": ..., "url": ... }` license form.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub struct LicenseObject {
+  pub r#type: String,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for LicenseObject {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    const FIELDS: &[&str] = &["type", "url"];
+
+    enum Field {
+      Type,
+      Url,
+      Ignore,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+      fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+      where
+        D: Deserializer<'de>,
+      {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+          type Value = Field;
+
+          fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("`type` or `url`")
+          }
+
+          fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+          where
+            E: de::Error,
+          {
+            Ok(match value {
+              "type" => Field::Type,
+              "url" => Field::Url,
+              _ => Field::Ignore,
+            })
+          }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+      }
+    }
+
+    struct LicenseObjectVisitor;
+
+    impl<'de> Visitor<'de> for LicenseObjectVisitor {
+      type Value = LicenseObject;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object with `type` and optional `url`")
+      }
+
+      fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let mut r#type: Option<String> = None;
+        let mut url: Option<String> = None;
+        let mut seen_url = false;
+
+        while let Some(key) = map.next_key::<Field>()? {
+          match key {
+            Field::Type => {
+              if r#type.is_some() {
+                return Err(de::Error::duplicate_field("type"));
+              }
+              r#type = Some(map.next_value()?);
+            }
+            Field::Url => {
+              if seen_url {
+                return Err(de::Error::duplicate_field("url"));
+              }
+              url = map.next_value()?;
+              seen_url = true;
+            }
+            Field::Ignore => {
+              let _: IgnoredAny = map.next_value()?;
+            }
+          }
+        }
+
+        let r#type = r#type.ok_or_else(|| de::Error::missing_field("type"))?;
+
+        Ok(LicenseObject { r#type, url })
+      }
+    }
+
+    deserializer.deserialize_struct("LicenseObject", FIELDS, LicenseObjectVisitor)
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum License {
+  String(String),
+  Object(LicenseObject),
+}
 
 impl<'de> Deserialize<'de> for License {
   fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -20,21 +126,29 @@ impl<'de> Deserialize<'de> for License {
       type Value = License;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str("a string for license")
+        formatter.write_str("a string or an object for license")
       }
 
       fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
       where
         E: serde::de::Error,
       {
-        Ok(License(value.to_string()))
+        Ok(License::String(value.to_string()))
       }
 
       fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
       where
         E: serde::de::Error,
       {
-        Ok(License(value))
+        Ok(License::String(value))
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let license = LicenseObject::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(License::Object(license))
       }
     }
 
@@ -42,30 +156,132 @@ impl<'de> Deserialize<'de> for License {
   }
 }
 
+/// Derefs to the license string, or the `type` of the deprecated object
+/// form, as the string newtype `License` used to.
+impl Deref for License {
+  type Target = String;
+
+  fn deref(&self) -> &Self::Target {
+    match self {
+      License::String(value) => value,
+      License::Object(object) => &object.r#type,
+    }
+  }
+}
+
+impl DerefMut for License {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    match self {
+      License::String(value) => value,
+      License::Object(object) => &mut object.r#type,
+    }
+  }
+}
+
+impl License {
+  /// The license string, or the `type` of the deprecated object form.
+  pub fn value(&self) -> &str {
+    self
+  }
+
+  /// The parsed license, or `None` when it is not valid.
+  pub fn spec(&self) -> Option<LicenseSpec> {
+    LicenseSpec::parse(self.value()).ok()
+  }
+}
+
+/// What a `license` value means to npm.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum LicenseSpec {
+  Spdx(SpdxExpression),
+  /// `SEE LICENSE IN <filename>`.
+  SeeLicenseIn(String),
+  /// `UNLICENSED`: the package is not licensed for use by others.
+  Unlicensed,
+}
+
+impl LicenseSpec {
+  pub fn parse(value: &str) -> Result<Self, SpdxError> {
+    if value == "UNLICENSED" {
+      return Ok(LicenseSpec::Unlicensed);
+    }
+
+    if let Some(file) = value.strip_prefix("SEE LICENSE IN ") {
+      let file = file.trim();
+      if file.is_empty() {
+        return Err(SpdxError {
+          message: "missing file name after `SEE LICENSE IN`".to_string(),
+          offset: value.len(),
+          len: 0,
+        });
+      }
+      return Ok(LicenseSpec::SeeLicenseIn(file.to_string()));
+    }
+
+    SpdxExpression::parse(value).map(LicenseSpec::Spdx)
+  }
+}
+
+/// Validates a license string, labeling the offending token inside the value.
+pub(crate) fn validate_license_value(value: &str, node: Option<&Value>) -> miette::Result<()> {
+  let Err(error) = LicenseSpec::parse(value) else {
+    return Ok(());
+  };
+
+  // Skip the opening quote; offsets are exact unless the string contains escapes.
+  let range = node_range(node).map(|range| {
+    let start = range.start + 1 + error.offset;
+    start..(start + error.len).min(range.end)
+  });
+
+  Err(validation_error(
+    "Invalid license",
+    &LICENSE_INVALID_EXPRESSION,
+    &format!(
+      "{}. Use an SPDX expression, `UNLICENSED` or `SEE LICENSE IN <file>`",
+      error.message
+    ),
+    range,
+    "here",
+  ))
+}
+
+/// Validates a deprecated `{ type, url }` license object.
+pub(crate) fn validate_license_object(
+  object: &LicenseObject,
+  node: Option<&Value>,
+) -> miette::Result<()> {
+  validate_license_value(&object.r#type, object_child(node, "type"))?;
+
+  if let Some(url) = object.url.as_ref() {
+    if !url.validate_url() {
+      return Err(validation_error(
+        "Invalid URL",
+        &LICENSE_INVALID_URL,
+        "Please provide a valid URL",
+        node_range(object_child(node, "url")),
+        "Invalid URL",
+      ));
+    }
+  }
+
+  Ok(())
+}
+
 impl Validator for License {
   fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
-    let regex = lazy_regex::regex_is_match!(
-      r"^(AGPL-3.0-only|Apache-2.0|BSD-2-Clause|BSD-3-Clause|BSL-1.0|CC0-1.0|CDDL-1.0|CDDL-1.1|EPL-1.0|EPL-2.0|GPL-2.0-only|GPL-3.0-only|ISC|LGPL-2.0-only|LGPL-2.1-only|LGPL-2.1-or-later|LGPL-3.0-only|LGPL-3.0-or-later|MIT|MPL-2.0|MSPL|UnLicense)$",
-      &self
-    );
+    let node = prop.map(|prop| &prop.value);
 
-    if regex {
-      return Ok(());
+    match self {
+      License::String(value) => validate_license_value(value, node),
+      License::Object(object) => validate_license_object(object, node),
     }
-
-    Err(validation_error(
-      "Invalid license",
-      Some("invalid_license"),
-      "Please provide a valid license",
-      value_range(prop, &[]),
-      "here",
-    ))
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::PackageJsonParser;
+  use crate::{LicenseSpec, PackageJsonParser};
 
   #[test]
   fn should_pass_validate_license() {
@@ -74,6 +290,12 @@ mod tests {
       r#"{"license": "Apache-2.0"}"#,
       r#"{"license": "BSD-2-Clause"}"#,
       r#"{"license": "BSD-3-Clause"}"#,
+      r#"{"license": "MIT OR Apache-2.0"}"#,
+      r#"{"license": "(MIT AND BSD-3-Clause)"}"#,
+      r#"{"license": "Apache-2.0 WITH LLVM-exception"}"#,
+      r#"{"license": "SEE LICENSE IN LICENSE.txt"}"#,
+      r#"{"license": "UNLICENSED"}"#,
+      r#"{"license": {"type": "MIT", "url": "https://opensource.org/licenses/MIT"}}"#,
     ];
 
     for json in jsones {
@@ -85,7 +307,13 @@ mod tests {
 
   #[test]
   fn should_fail_when_license_is_invalid() {
-    let jsones = [r#"{"license": "MIT1"}"#];
+    let jsones = [
+      r#"{"license": "MIT1"}"#,
+      r#"{"license": "MIT or ISC"}"#,
+      r#"{"license": "SEE LICENSE IN "}"#,
+      r#"{"license": {"type": "Nope"}}"#,
+      r#"{"license": {"type": "MIT", "url": "invalid"}}"#,
+    ];
 
     for json in jsones {
       let res = PackageJsonParser::parse_str(json).unwrap();
@@ -94,6 +322,38 @@ mod tests {
     }
   }
 
+  #[test]
+  fn should_label_invalid_license_token() {
+    let json = r#"{"license": "MIT OR Apache2"}"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+
+    let report = parser.license().unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      "Apache2"
+    );
+  }
+
+  #[test]
+  fn should_expose_license_spec() {
+    let parser = PackageJsonParser::parse_str(r#"{"license": "MIT OR Apache-2.0"}"#).unwrap();
+    let Some(LicenseSpec::Spdx(expression)) = parser.license().unwrap().unwrap().spec() else {
+      panic!("expected an SPDX expression");
+    };
+    assert_eq!(expression.licenses().len(), 2);
+  }
+
+  #[test]
+  fn should_read_license_as_string() {
+    let parser = PackageJsonParser::parse_str(r#"{"license": "MIT"}"#).unwrap();
+    assert_eq!(parser.license().unwrap().unwrap().as_str(), "MIT");
+
+    let parser = PackageJsonParser::parse_str(r#"{"license": {"type": "ISC"}}"#).unwrap();
+    assert_eq!(parser.license().unwrap().unwrap().as_str(), "ISC");
+  }
+
   #[test]
   fn should_deserialize_license_successfully() {
     let parsed = PackageJsonParser::parse_str(r#"{"license":"MIT"}"#);
@@ -108,6 +368,14 @@ mod tests {
     assert!(parsed.license().is_err());
   }
 
+  #[test]
+  fn should_fail_deserialize_license_object_when_type_is_missing() {
+    let parsed = PackageJsonParser::parse_str(r#"{"license":{"url":"https://example.com"}}"#);
+    assert!(parsed.is_ok());
+    let parsed = parsed.unwrap();
+    assert!(parsed.license().is_err());
+  }
+
   #[test]
   fn should_fail_deserialize_license_when_json_is_invalid() {
     let parsed = PackageJsonParser::parse_str("{");
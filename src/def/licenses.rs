@@ -0,0 +1,101 @@
+use derive_more::{Deref, DerefMut};
+use jsonc_parser::ast::ObjectProp;
+use serde::de::{SeqAccess, Visitor, value::SeqAccessDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::def::{LicenseObject, validate_license_object};
+use crate::ext::{Validator, array_child};
+
+/// The deprecated `licenses` array of `{ type, url }` objects.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Deref, DerefMut)]
+pub struct Licenses(Vec<LicenseObject>);
+
+impl<'de> Deserialize<'de> for Licenses {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct LicensesVisitor;
+
+    impl<'de> Visitor<'de> for LicensesVisitor {
+      type Value = Licenses;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an array of license objects for licenses")
+      }
+
+      fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+      where
+        S: SeqAccess<'de>,
+      {
+        let values = Vec::<LicenseObject>::deserialize(SeqAccessDeserializer::new(seq))?;
+        Ok(Licenses(values))
+      }
+    }
+
+    deserializer.deserialize_seq(LicensesVisitor)
+  }
+}
+
+impl Validator for Licenses {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    let node = prop.map(|prop| &prop.value);
+
+    for (index, license) in self.iter().enumerate() {
+      validate_license_object(license, array_child(node, index))?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::PackageJsonParser;
+
+  #[test]
+  fn should_pass_validate_licenses() {
+    let json = r#"{"licenses": [
+      {"type": "MIT", "url": "https://opensource.org/licenses/MIT"},
+      {"type": "Apache-2.0"}
+    ]}"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+    assert!(parser.validate().is_ok());
+    assert_eq!(parser.licenses().unwrap().unwrap().len(), 2);
+  }
+
+  #[test]
+  fn should_fail_validate_licenses() {
+    let json = r#"{"licenses": [{"type": "MIT"}, {"type": "Apache2"}]}"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+
+    let report = parser.licenses().unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      "Apache2"
+    );
+  }
+
+  #[test]
+  fn should_deserialize_licenses_successfully() {
+    let parsed = PackageJsonParser::parse_str(r#"{"licenses":[]}"#);
+    assert!(parsed.is_ok());
+  }
+
+  #[test]
+  fn should_fail_deserialize_licenses_when_type_is_invalid() {
+    let parsed = PackageJsonParser::parse_str(r#"{"licenses":"MIT"}"#);
+    assert!(parsed.is_ok());
+    let parsed = parsed.unwrap();
+    assert!(parsed.licenses().is_err());
+  }
+
+  #[test]
+  fn should_fail_deserialize_licenses_when_json_is_invalid() {
+    let parsed = PackageJsonParser::parse_str("{");
+    assert!(parsed.is_err());
+  }
+}
@@ -18,7 +18,7 @@ impl<'de> Deserialize<'de> for Main {
   {
     struct MainVisitor;
 
-    impl<'de> Visitor<'de> for MainVisitor {
+    impl Visitor<'_> for MainVisitor {
       type Value = Main;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1,17 +1,24 @@
 mod bin;
 mod bugs;
+mod bundle_dependencies;
 mod contributors;
 mod cpu;
 mod dependencies;
+mod dependencies_meta;
+mod dependency_spec;
 mod description;
 mod dev_dependencies;
 mod directories;
 mod engine_strict;
 mod engines;
+mod exports;
 mod files;
+mod funding;
 mod home_page;
+mod imports;
 mod keywords;
 mod license;
+mod licenses;
 mod main;
 mod maintainers;
 mod man;
@@ -19,33 +26,44 @@ mod module;
 mod name;
 mod optional_dependencies;
 mod os;
+mod overrides;
 mod package_manager;
 mod peer_dependencies;
+mod peer_dependencies_meta;
 mod person;
 mod private;
 mod publish_config;
 mod readme;
 mod repository;
+mod resolutions;
 mod scripts;
 mod r#type;
 mod types;
 mod typings;
 mod version;
+mod workspaces;
 
 pub use bin::*;
 pub use bugs::*;
+pub use bundle_dependencies::*;
 pub use contributors::*;
 pub use cpu::*;
 pub use dependencies::*;
+pub use dependencies_meta::*;
+pub use dependency_spec::*;
 pub use description::*;
 pub use dev_dependencies::*;
 pub use directories::*;
 pub use engine_strict::*;
 pub use engines::*;
+pub use exports::*;
 pub use files::*;
+pub use funding::*;
 pub use home_page::*;
+pub use imports::*;
 pub use keywords::*;
 pub use license::*;
+pub use licenses::*;
 pub use main::*;
 pub use maintainers::*;
 pub use man::*;
@@ -53,15 +71,19 @@ pub use module::*;
 pub use name::*;
 pub use optional_dependencies::*;
 pub use os::*;
+pub use overrides::*;
 pub use package_manager::*;
 pub use peer_dependencies::*;
+pub use peer_dependencies_meta::*;
 pub use person::*;
 pub use private::*;
 pub use publish_config::*;
 pub use readme::*;
 pub use repository::*;
+pub use resolutions::*;
 pub use scripts::*;
 pub use r#type::*;
 pub use types::*;
 pub use typings::*;
 pub use version::*;
+pub use workspaces::*;
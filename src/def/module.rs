@@ -16,7 +16,7 @@ impl<'de> Deserialize<'de> for Module {
   {
     struct ModuleVisitor;
 
-    impl<'de> Visitor<'de> for ModuleVisitor {
+    impl Visitor<'_> for ModuleVisitor {
       type Value = Module;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
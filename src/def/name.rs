@@ -4,6 +4,7 @@ use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 
+use crate::codes::NAME_INVALID_CHARS;
 use crate::ext::{Validator, validation_error, value_range};
 
 #[derive(Debug, PartialEq, Serialize, Clone, Deref, DerefMut)]
@@ -16,7 +17,7 @@ impl<'de> Deserialize<'de> for Name {
   {
     struct NameVisitor;
 
-    impl<'de> Visitor<'de> for NameVisitor {
+    impl Visitor<'_> for NameVisitor {
       type Value = Name;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -48,20 +49,23 @@ impl Name {
   }
 }
 
+/// Whether `name` is a valid npm package name.
+pub(crate) fn is_valid_package_name(name: &str) -> bool {
+  lazy_regex::regex_is_match!(
+    r"^(?:(?:@(?:[a-z0-9-*~][a-z0-9-*._~]*)?/[a-z0-9-._~])|[a-z0-9-~])[a-z0-9-._~]*$",
+    name
+  )
+}
+
 impl Validator for Name {
   fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
-    let reg_name = lazy_regex::regex_is_match!(
-      r"^(?:(?:@(?:[a-z0-9-*~][a-z0-9-*._~]*)?/[a-z0-9-._~])|[a-z0-9-~])[a-z0-9-._~]*$",
-      &self
-    );
-
-    if reg_name {
+    if is_valid_package_name(self) {
       return Ok(());
     }
 
     Err(validation_error(
       "Package name does not match required pattern",
-      Some("invalid_package_name"),
+      &NAME_INVALID_CHARS,
       r"Expected pattern: ^(?:(?:@(?:[a-z0-9-*~][a-z0-9-*._~]*)?/[a-z0-9-._~])|[a-z0-9-~])[a-z0-9-._~]*$",
       value_range(prop, &[]),
       "here",
@@ -0,0 +1,338 @@
+use derive_more::{Deref, DerefMut};
+use indexmap::IndexMap;
+use jsonc_parser::ast::{ObjectProp, Value};
+use serde::de::{MapAccess, Visitor, value::MapAccessDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::{
+  OVERRIDES_INVALID_SELECTOR, OVERRIDES_INVALID_SPEC, OVERRIDES_MISPLACED_SELF_KEY,
+  OVERRIDES_UNKNOWN_REFERENCE,
+};
+use crate::def::{DependencySpec, is_valid_package_name, split_selector};
+use crate::ext::{Validator, key_range, node_range, object_child, validation_error};
+
+/// The key of a nested override object that overrides the parent package
+/// itself.
+const SELF_KEY: &str = ".";
+
+/// A value in the npm `overrides` tree.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum OverrideValue {
+  /// A dependency specifier such as `1.0.0` or `npm:other@^2`.
+  Spec(String),
+  /// `$name`, the specifier of the direct dependency `name`.
+  Reference(String),
+  /// Overrides applied below the selected package, with an optional `.`
+  /// key for the package itself.
+  Nested(Overrides),
+}
+
+impl<'de> Deserialize<'de> for OverrideValue {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct OverrideValueVisitor;
+
+    impl<'de> Visitor<'de> for OverrideValueVisitor {
+      type Value = OverrideValue;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string or an object for overrides value")
+      }
+
+      fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+      where
+        E: serde::de::Error,
+      {
+        Ok(OverrideValue::from(value))
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let value = Overrides::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(OverrideValue::Nested(value))
+      }
+    }
+
+    deserializer.deserialize_any(OverrideValueVisitor)
+  }
+}
+
+impl From<&str> for OverrideValue {
+  fn from(value: &str) -> Self {
+    match value.strip_prefix('$') {
+      Some(name) => OverrideValue::Reference(name.to_string()),
+      None => OverrideValue::Spec(value.to_string()),
+    }
+  }
+}
+
+/// The npm `overrides` field: package selectors mapped to replacement
+/// specifiers or to nested overrides.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Deref, DerefMut)]
+pub struct Overrides(IndexMap<String, OverrideValue>);
+
+impl<'de> Deserialize<'de> for Overrides {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct OverridesVisitor;
+
+    impl<'de> Visitor<'de> for OverridesVisitor {
+      type Value = Overrides;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object map for overrides")
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let value =
+          IndexMap::<String, OverrideValue>::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Overrides(value))
+      }
+    }
+
+    deserializer.deserialize_any(OverridesVisitor)
+  }
+}
+
+fn validate_selector(selector: &str, parent: Option<&Value>) -> miette::Result<()> {
+  let (name, spec) = split_selector(selector);
+  let help = if !is_valid_package_name(name) {
+    Some(format!("`{name}` is not a valid package name"))
+  } else {
+    match spec {
+      None => None,
+      Some("") => Some("missing version spec after `@`".to_string()),
+      Some(spec) => DependencySpec::parse(spec).err(),
+    }
+  };
+
+  match help {
+    Some(help) => Err(validation_error(
+      "Invalid overrides selector",
+      &OVERRIDES_INVALID_SELECTOR,
+      &format!("`{selector}` must be `<name>` or `<name>@<spec>`: {help}"),
+      key_range(parent, selector),
+      "here",
+    )),
+    None => Ok(()),
+  }
+}
+
+fn validate_overrides(
+  overrides: &Overrides,
+  node: Option<&Value>,
+  nested: bool,
+) -> miette::Result<()> {
+  for (key, value) in overrides.iter() {
+    let child = object_child(node, key);
+
+    if key == SELF_KEY {
+      if !nested || matches!(value, OverrideValue::Nested(_)) {
+        return Err(validation_error(
+          "Invalid overrides key",
+          &OVERRIDES_MISPLACED_SELF_KEY,
+          "`.` is only allowed inside a nested override and must be a string",
+          node_range(child),
+          "here",
+        ));
+      }
+    } else {
+      validate_selector(key, node)?;
+    }
+
+    match value {
+      OverrideValue::Spec(spec) => {
+        if let Err(reason) = DependencySpec::parse(spec) {
+          return Err(validation_error(
+            "Invalid overrides specifier",
+            &OVERRIDES_INVALID_SPEC,
+            &format!("`{key}`: {reason}"),
+            node_range(child),
+            "here",
+          ));
+        }
+      }
+      OverrideValue::Reference(name) => {
+        if !is_valid_package_name(name) {
+          return Err(validation_error(
+            "Invalid overrides specifier",
+            &OVERRIDES_INVALID_SPEC,
+            &format!("`{key}`: `${name}` does not reference a valid package name"),
+            node_range(child),
+            "here",
+          ));
+        }
+      }
+      OverrideValue::Nested(overrides) => validate_overrides(overrides, child, true)?,
+    }
+  }
+
+  Ok(())
+}
+
+fn validate_references(
+  overrides: &Overrides,
+  direct: &[&str],
+  node: Option<&Value>,
+) -> miette::Result<()> {
+  for (key, value) in overrides.iter() {
+    let child = object_child(node, key);
+    match value {
+      OverrideValue::Reference(name) if !direct.contains(&name.as_str()) => {
+        return Err(validation_error(
+          "Unknown overrides reference",
+          &OVERRIDES_UNKNOWN_REFERENCE,
+          &format!("`${name}` must name a direct dependency of this package"),
+          node_range(child),
+          "here",
+        ));
+      }
+      OverrideValue::Nested(overrides) => validate_references(overrides, direct, child)?,
+      _ => {}
+    }
+  }
+
+  Ok(())
+}
+
+impl Overrides {
+  /// Every `$name` reference in the tree, in declaration order.
+  pub fn references(&self) -> Vec<&str> {
+    self
+      .values()
+      .flat_map(|value| match value {
+        OverrideValue::Reference(name) => vec![name.as_str()],
+        OverrideValue::Nested(overrides) => overrides.references(),
+        OverrideValue::Spec(_) => Vec::new(),
+      })
+      .collect()
+  }
+
+  /// Checks that every `$name` reference names one of the `direct`
+  /// dependencies, which the field alone cannot know.
+  pub(crate) fn validate_references(
+    &self,
+    direct: &[&str],
+    prop: Option<&ObjectProp>,
+  ) -> miette::Result<()> {
+    validate_references(self, direct, prop.map(|prop| &prop.value))
+  }
+}
+
+impl Validator for Overrides {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    validate_overrides(self, prop.map(|prop| &prop.value), false)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{OverrideValue, PackageJsonParser};
+
+  #[test]
+  fn should_pass_validate_overrides() {
+    let jsones = [
+      r#"{"overrides": {"foo": "1.0.0"}}"#,
+      r#"{"overrides": {"foo@^2": "2.1.0", "@scope/bar@1": "npm:baz@1"}}"#,
+      r#"{"overrides": {"foo": {".": "1.0.0", "bar": "1.0.0"}}}"#,
+      r#"{"overrides": {"baz": {"bar": {"foo": "1.0.0"}}}}"#,
+      r#"{"dependencies": {"foo": "^1.0.0"}, "overrides": {"foo": "$foo"}}"#,
+      r#"{"devDependencies": {"@s/foo": "^1.0.0"}, "overrides": {"bar": {".": "$@s/foo"}}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_overrides() {
+    let jsones = [
+      r#"{"overrides": {"Foo": "1.0.0"}}"#,
+      r#"{"overrides": {"é": "1.0.0"}}"#,
+      r#"{"overrides": {"foo": {"ü@1": "1.0.0"}}}"#,
+      r#"{"overrides": {"foo@": "1.0.0"}}"#,
+      r#"{"overrides": {"foo@^1.0.0.": "1.0.0"}}"#,
+      r#"{"overrides": {"foo": "^1.0.0."}}"#,
+      r#"{"overrides": {".": "1.0.0"}}"#,
+      r#"{"overrides": {"foo": {".": {"bar": "1.0.0"}}}}"#,
+      r#"{"overrides": {"foo": "$Foo"}}"#,
+      r#"{"overrides": {"foo": "$foo"}}"#,
+      r#"{"dependencies": {"bar": "^1.0.0"}, "overrides": {"baz": {"foo": "$foo"}}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_label_unknown_override_reference() {
+    let json = r#"{"overrides": {"baz": {"foo": "$foo"}}}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .overrides()
+      .unwrap_err();
+    assert_eq!(
+      report.code().unwrap().to_string(),
+      "pkg::overrides::unknown-reference"
+    );
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""$foo""#
+    );
+  }
+
+  #[test]
+  fn should_label_invalid_override_selector() {
+    let json = r#"{"overrides": {"foo": {"Bar@1": "1.0.0"}}}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .overrides()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""Bar@1""#
+    );
+  }
+
+  #[test]
+  fn should_deserialize_overrides_tree() {
+    let json = r#"{
+      "dependencies": {"foo": "^1.0.0"},
+      "overrides": {"foo": "$foo", "bar": {".": "2.0.0", "baz": "1.0.0"}}
+    }"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+    let overrides = parser.overrides().unwrap().unwrap();
+
+    assert_eq!(
+      overrides["foo"],
+      OverrideValue::Reference("foo".to_string())
+    );
+    let OverrideValue::Nested(bar) = &overrides["bar"] else {
+      panic!("expected nested overrides");
+    };
+    assert_eq!(bar["."], OverrideValue::Spec("2.0.0".to_string()));
+    assert_eq!(overrides.references(), ["foo"]);
+  }
+
+  #[test]
+  fn should_fail_deserialize_overrides_when_type_is_invalid() {
+    let parsed = PackageJsonParser::parse_str(r#"{"overrides": {"foo": 1}}"#).unwrap();
+    assert!(parsed.overrides().is_err());
+  }
+}
@@ -4,8 +4,13 @@ use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 
+use crate::codes::PACKAGE_MANAGER_INVALID_VALUE;
 use crate::ext::{Validator, validation_error, value_range};
 
+/// Package managers accepted before the `@<version>` of `packageManager`.
+#[cfg(feature = "lsp")]
+pub(crate) const PACKAGE_MANAGERS: &[&str] = &["npm", "pnpm", "yarn", "bun"];
+
 #[derive(Debug, PartialEq, Serialize, Eq, Clone, Deref, DerefMut)]
 pub struct PackageManager(String);
 
@@ -16,7 +21,7 @@ impl<'de> Deserialize<'de> for PackageManager {
   {
     struct PackageManagerVisitor;
 
-    impl<'de> Visitor<'de> for PackageManagerVisitor {
+    impl Visitor<'_> for PackageManagerVisitor {
       type Value = PackageManager;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -52,7 +57,7 @@ impl Validator for PackageManager {
 
     Err(validation_error(
       "Invalid package manager",
-      Some("invalid_package_manager"),
+      &PACKAGE_MANAGER_INVALID_VALUE,
       "Please provide a valid package manager",
       value_range(prop, &[]),
       "here",
@@ -1,3 +1,6 @@
+use crate::def::{
+  DependencySpec, parse_declared_spec, parse_declared_specs, validate_dependency_map,
+};
 use crate::ext::Validator;
 use derive_more::{Deref, DerefMut};
 use jsonc_parser::ast::ObjectProp;
@@ -36,9 +39,22 @@ impl<'de> Deserialize<'de> for PeerDependencies {
   }
 }
 
+impl PeerDependencies {
+  /// Parses the specifier `peerDependencies` declares for `name`, or `None` when
+  /// it is not declared.
+  pub fn spec(&self, name: &str) -> Option<Result<DependencySpec, String>> {
+    parse_declared_spec(self, name)
+  }
+
+  /// Parses every specifier in `peerDependencies`, failing on the first invalid one.
+  pub fn specs(&self) -> Result<FxHashMap<&str, DependencySpec>, String> {
+    parse_declared_specs(self)
+  }
+}
+
 impl Validator for PeerDependencies {
-  fn validate(&self, _prop: Option<&ObjectProp>) -> miette::Result<()> {
-    Ok(())
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    validate_dependency_map(self, prop)
   }
 }
 
@@ -46,6 +62,22 @@ impl Validator for PeerDependencies {
 mod tests {
   use crate::PackageJsonParser;
 
+  #[test]
+  fn should_pass_validate_peer_dependencies() {
+    let parsed =
+      PackageJsonParser::parse_str(r#"{"peerDependencies":{"react":"^18.0.0 || ^19.0.0"}}"#)
+        .unwrap();
+    assert!(parsed.validate().is_ok());
+  }
+
+  #[test]
+  fn should_fail_validate_peer_dependencies_when_spec_is_invalid() {
+    let parsed =
+      PackageJsonParser::parse_str(r#"{"peerDependencies":{"react":"^18.0.0 ||| ^19"}}"#).unwrap();
+    assert!(parsed.validate().is_err());
+    assert!(parsed.peer_dependencies().is_err());
+  }
+
   #[test]
   fn should_deserialize_peer_dependencies_successfully() {
     let parsed = PackageJsonParser::parse_str(r#"{"peerDependencies":{"react":"^18.0.0"}}"#);
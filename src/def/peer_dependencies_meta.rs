@@ -0,0 +1,182 @@
+use derive_more::{Deref, DerefMut};
+use indexmap::IndexMap;
+use jsonc_parser::ast::ObjectProp;
+use serde::de::{self, IgnoredAny, MapAccess, Visitor, value::MapAccessDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::PEER_DEPENDENCIES_META_INVALID_NAME;
+use crate::def::is_valid_package_name;
+use crate::ext::{Validator, key_range, validation_error};
+
+/// Extra information about one peer dependency.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Default)]
+pub struct PeerDependencyMeta {
+  /// An optional peer is not installed automatically and is not reported
+  /// when it is missing.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub optional: Option<bool>,
+}
+
+impl<'de> Deserialize<'de> for PeerDependencyMeta {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct PeerDependencyMetaVisitor;
+
+    impl<'de> Visitor<'de> for PeerDependencyMetaVisitor {
+      type Value = PeerDependencyMeta;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object for peerDependenciesMeta entry")
+      }
+
+      fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let mut optional = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+          match key.as_str() {
+            "optional" => {
+              if optional.is_some() {
+                return Err(de::Error::duplicate_field("optional"));
+              }
+              optional = Some(map.next_value()?);
+            }
+            _ => {
+              let _: IgnoredAny = map.next_value()?;
+            }
+          }
+        }
+
+        Ok(PeerDependencyMeta { optional })
+      }
+    }
+
+    deserializer.deserialize_map(PeerDependencyMetaVisitor)
+  }
+}
+
+/// The `peerDependenciesMeta` field: peer dependency names mapped to extra
+/// information about them.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Deref, DerefMut)]
+pub struct PeerDependenciesMeta(IndexMap<String, PeerDependencyMeta>);
+
+impl<'de> Deserialize<'de> for PeerDependenciesMeta {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct PeerDependenciesMetaVisitor;
+
+    impl<'de> Visitor<'de> for PeerDependenciesMetaVisitor {
+      type Value = PeerDependenciesMeta;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object map for peerDependenciesMeta")
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let value =
+          IndexMap::<String, PeerDependencyMeta>::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(PeerDependenciesMeta(value))
+      }
+    }
+
+    deserializer.deserialize_any(PeerDependenciesMetaVisitor)
+  }
+}
+
+impl PeerDependenciesMeta {
+  /// Whether the peer dependency `name` is marked optional.
+  pub fn is_optional(&self, name: &str) -> bool {
+    self
+      .get(name)
+      .and_then(|meta| meta.optional)
+      .unwrap_or(false)
+  }
+}
+
+impl Validator for PeerDependenciesMeta {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    for name in self.keys() {
+      if !is_valid_package_name(name) {
+        return Err(validation_error(
+          "Invalid peerDependenciesMeta entry",
+          &PEER_DEPENDENCIES_META_INVALID_NAME,
+          &format!("`{name}` is not a valid package name"),
+          key_range(prop.map(|prop| &prop.value), name),
+          "here",
+        ));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::PackageJsonParser;
+
+  #[test]
+  fn should_pass_validate_peer_dependencies_meta() {
+    let jsones = [
+      r#"{"peerDependenciesMeta": {}}"#,
+      r#"{"peerDependenciesMeta": {"react": {"optional": true}, "@types/react": {}}}"#,
+      r#"{"peerDependenciesMeta": {"vue": {"optional": false, "unknown": 1}}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_peer_dependencies_meta() {
+    let jsones = [
+      r#"{"peerDependenciesMeta": {"React": {"optional": true}}}"#,
+      r#"{"peerDependenciesMeta": {"react": {"optional": "yes"}}}"#,
+      r#"{"peerDependenciesMeta": {"react": true}}"#,
+      r#"{"peerDependenciesMeta": ["react"]}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_read_optional_peer_dependencies() {
+    let json = r#"{"peerDependenciesMeta": {"react": {"optional": true}, "vue": {}}}"#;
+    let parser = PackageJsonParser::parse_str(json).unwrap();
+    let meta = parser.peer_dependencies_meta().unwrap().unwrap();
+
+    assert!(meta.is_optional("react"));
+    assert!(!meta.is_optional("vue"));
+    assert!(!meta.is_optional("svelte"));
+  }
+
+  #[test]
+  fn should_label_invalid_peer_dependencies_meta_name() {
+    let json =
+      r#"{"peerDependenciesMeta": {"react": {"optional": true}, "React": {"optional": true}}}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .peer_dependencies_meta()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""React""#
+    );
+  }
+}
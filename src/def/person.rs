@@ -4,6 +4,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use validator::{ValidateEmail, ValidateUrl};
 
+use crate::codes::{PERSON_INVALID_EMAIL, PERSON_INVALID_NAME, PERSON_INVALID_URL};
 use crate::ext::{Validator, validation_error, value_range};
 
 #[derive(Debug, Serialize, Eq, PartialEq, Clone)]
@@ -41,7 +42,7 @@ impl<'de> Deserialize<'de> for PersonObject {
       {
         struct FieldVisitor;
 
-        impl<'de> Visitor<'de> for FieldVisitor {
+        impl Visitor<'_> for FieldVisitor {
           type Value = Field;
 
           fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -178,7 +179,7 @@ impl Validator for Person {
 
         Err(validation_error(
           "Invalid name",
-          Some("invalid_name"),
+          &PERSON_INVALID_NAME,
           "Please provide a valid name",
           value_range(prop, &[]),
           "Invalid name",
@@ -188,7 +189,7 @@ impl Validator for Person {
         if person.name.is_empty() {
           return Err(validation_error(
             "Invalid name",
-            Some("invalid_name"),
+            &PERSON_INVALID_NAME,
             "Please provide a valid name",
             value_range(prop, &["name"]),
             "Invalid name",
@@ -199,7 +200,7 @@ impl Validator for Person {
           if !email.validate_email() {
             return Err(validation_error(
               "Invalid email",
-              Some("invalid_email"),
+              &PERSON_INVALID_EMAIL,
               "Please provide a valid email",
               value_range(prop, &["email"]),
               "Invalid email",
@@ -211,7 +212,7 @@ impl Validator for Person {
           if !url.validate_url() {
             return Err(validation_error(
               "Invalid URL",
-              Some("invalid_url"),
+              &PERSON_INVALID_URL,
               "Please provide a valid URL",
               value_range(prop, &["url"]),
               "Invalid URL",
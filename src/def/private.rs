@@ -16,7 +16,7 @@ impl<'de> Deserialize<'de> for Private {
   {
     struct PrivateVisitor;
 
-    impl<'de> Visitor<'de> for PrivateVisitor {
+    impl Visitor<'_> for PrivateVisitor {
       type Value = Private;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
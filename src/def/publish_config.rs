@@ -4,6 +4,10 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use validator::ValidateUrl;
 
+use crate::codes::{
+  PUBLISH_CONFIG_INVALID_ACCESS, PUBLISH_CONFIG_INVALID_PROVENANCE,
+  PUBLISH_CONFIG_INVALID_REGISTRY, PUBLISH_CONFIG_INVALID_TAG,
+};
 use crate::ext::{Validator, validation_error, value_range};
 
 #[derive(Debug, PartialEq, Serialize, Clone)]
@@ -40,7 +44,7 @@ impl<'de> Deserialize<'de> for PublishConfig {
       {
         struct FieldVisitor;
 
-        impl<'de> Visitor<'de> for FieldVisitor {
+        impl Visitor<'_> for FieldVisitor {
           type Value = Field;
 
           fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -143,7 +147,7 @@ impl Validator for PublishConfig {
       if !access_regex {
         return Err(validation_error(
           "Invalid access",
-          None,
+          &PUBLISH_CONFIG_INVALID_ACCESS,
           "Please provide a valid access",
           value_range(publish_config, &["access"]),
           "Invalid access",
@@ -155,7 +159,7 @@ impl Validator for PublishConfig {
       if !registry.validate_url() {
         return Err(validation_error(
           "Invalid registry",
-          None,
+          &PUBLISH_CONFIG_INVALID_REGISTRY,
           "Please provide a valid registry",
           value_range(publish_config, &["registry"]),
           "Invalid registry",
@@ -168,7 +172,7 @@ impl Validator for PublishConfig {
       if !tag_regex {
         return Err(validation_error(
           "Invalid tag",
-          None,
+          &PUBLISH_CONFIG_INVALID_TAG,
           "Please provide a valid tag",
           value_range(publish_config, &["tag"]),
           "Invalid tag",
@@ -180,7 +184,7 @@ impl Validator for PublishConfig {
       if !provenance {
         return Err(validation_error(
           "Invalid provenance",
-          None,
+          &PUBLISH_CONFIG_INVALID_PROVENANCE,
           "Please provide a valid provenance",
           value_range(publish_config, &["provenance"]),
           "Invalid provenance",
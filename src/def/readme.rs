@@ -37,7 +37,7 @@ impl<'de> Deserialize<'de> for ReadmeContent {
       {
         struct FieldVisitor;
 
-        impl<'de> Visitor<'de> for FieldVisitor {
+        impl Visitor<'_> for FieldVisitor {
           type Value = Field;
 
           fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
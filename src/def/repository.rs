@@ -4,6 +4,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use validator::ValidateUrl;
 
+use crate::codes::REPOSITORY_INVALID_URL;
 use crate::ext::{Validator, validation_error, value_range};
 
 #[derive(Debug, Serialize, Clone)]
@@ -39,7 +40,7 @@ impl<'de> Deserialize<'de> for Repository {
       {
         struct FieldVisitor;
 
-        impl<'de> Visitor<'de> for FieldVisitor {
+        impl Visitor<'_> for FieldVisitor {
           type Value = Field;
 
           fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -179,7 +180,7 @@ impl Validator for RepositoryOrString {
           if !url.validate_url() {
             return Err(validation_error(
               "Invalid url",
-              Some("invalid_url"),
+              &REPOSITORY_INVALID_URL,
               "Please provide a valid url",
               value_range(repository, &["url"]),
               "Invalid url",
@@ -192,7 +193,7 @@ impl Validator for RepositoryOrString {
         if !string.validate_url() {
           return Err(validation_error(
             "Invalid url",
-            Some("invalid_url"),
+            &REPOSITORY_INVALID_URL,
             "Please provide a valid url",
             value_range(repository, &[]),
             "Invalid url",
@@ -0,0 +1,235 @@
+use derive_more::{Deref, DerefMut};
+use indexmap::IndexMap;
+use jsonc_parser::ast::ObjectProp;
+use serde::de::{MapAccess, Visitor, value::MapAccessDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::{RESOLUTIONS_INVALID_SELECTOR, RESOLUTIONS_INVALID_SPEC};
+use crate::def::{DependencySpec, is_valid_package_name, split_selector};
+use crate::ext::{Validator, key_range, validation_error, value_range};
+
+/// One `/`-separated step of a resolutions selector.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum SelectorSegment {
+  /// `**`, any number of packages in between.
+  AnyDepth,
+  /// A package, optionally narrowed to the versions matching `range`.
+  Package { name: String, range: Option<String> },
+}
+
+/// A parsed yarn resolutions key such as `**/foo`, `a/b` or
+/// `@scope/a@^1/**/b`, read from the root package down to the resolved one.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub struct ResolutionSelector(Vec<SelectorSegment>);
+
+impl ResolutionSelector {
+  pub fn parse(selector: &str) -> Result<Self, String> {
+    let mut segments = Vec::new();
+    let mut parts = selector.split('/');
+
+    while let Some(part) = parts.next() {
+      if part == "**" {
+        segments.push(SelectorSegment::AnyDepth);
+        continue;
+      }
+
+      let owned;
+      let unit = if part.starts_with('@') {
+        let Some(rest) = parts.next() else {
+          return Err(format!(
+            "`{part}` is missing the package name after the scope"
+          ));
+        };
+        owned = format!("{part}/{rest}");
+        owned.as_str()
+      } else {
+        part
+      };
+
+      let (name, range) = split_selector(unit);
+      if !is_valid_package_name(name) {
+        return Err(format!("`{name}` is not a valid package name or `**`"));
+      }
+      if let Some(range) = range {
+        if range.is_empty() {
+          return Err(format!("`{unit}` is missing the range after `@`"));
+        }
+        DependencySpec::parse(range)?;
+      }
+
+      segments.push(SelectorSegment::Package {
+        name: name.to_string(),
+        range: range.map(str::to_string),
+      });
+    }
+
+    match segments.last() {
+      Some(SelectorSegment::Package { .. }) => Ok(ResolutionSelector(segments)),
+      _ => Err("the last segment must name a package".to_string()),
+    }
+  }
+
+  /// The segments, from the root package down; the last one is a package.
+  pub fn segments(&self) -> &[SelectorSegment] {
+    &self.0
+  }
+
+  /// The name of the package whose version is forced.
+  pub fn package(&self) -> &str {
+    match self.0.last() {
+      Some(SelectorSegment::Package { name, .. }) => name,
+      _ => unreachable!("a parsed selector ends with a package"),
+    }
+  }
+}
+
+/// The yarn `resolutions` field: selectors mapped to the specifier forced
+/// for the packages they match.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Deref, DerefMut)]
+pub struct Resolutions(IndexMap<String, String>);
+
+impl<'de> Deserialize<'de> for Resolutions {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct ResolutionsVisitor;
+
+    impl<'de> Visitor<'de> for ResolutionsVisitor {
+      type Value = Resolutions;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object map for resolutions")
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let value = IndexMap::<String, String>::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Resolutions(value))
+      }
+    }
+
+    deserializer.deserialize_any(ResolutionsVisitor)
+  }
+}
+
+impl Resolutions {
+  /// Parses every selector, skipping the ones that fail to parse.
+  pub fn selectors(&self) -> Vec<(ResolutionSelector, &str)> {
+    self
+      .iter()
+      .filter_map(|(key, spec)| Some((ResolutionSelector::parse(key).ok()?, spec.as_str())))
+      .collect()
+  }
+}
+
+impl Validator for Resolutions {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    for (key, spec) in self.iter() {
+      if let Err(reason) = ResolutionSelector::parse(key) {
+        return Err(validation_error(
+          "Invalid resolutions selector",
+          &RESOLUTIONS_INVALID_SELECTOR,
+          &format!("`{key}`: {reason}"),
+          key_range(prop.map(|prop| &prop.value), key),
+          "here",
+        ));
+      }
+
+      if let Err(reason) = DependencySpec::parse(spec) {
+        return Err(validation_error(
+          "Invalid resolutions specifier",
+          &RESOLUTIONS_INVALID_SPEC,
+          &format!("`{key}`: {reason}"),
+          value_range(prop, &[key]),
+          "here",
+        ));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{ResolutionSelector, SelectorSegment};
+  use crate::PackageJsonParser;
+
+  #[test]
+  fn should_pass_validate_resolutions() {
+    let jsones = [
+      r#"{"resolutions": {"foo": "1.0.0"}}"#,
+      r#"{"resolutions": {"**/foo": "^1.2.0", "a/b": "file:./b"}}"#,
+      r#"{"resolutions": {"@scope/a/**/@scope/b": "npm:c@1"}}"#,
+      r#"{"resolutions": {"a@^1/b": "2.0.0", "lodash@^4": "4.17.21"}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_resolutions() {
+    let jsones = [
+      r#"{"resolutions": {"foo/**": "1.0.0"}}"#,
+      r#"{"resolutions": {"é": "1.0.0"}}"#,
+      r#"{"resolutions": {"**/ü@1": "1.0.0"}}"#,
+      r#"{"resolutions": {"**/Foo": "1.0.0"}}"#,
+      r#"{"resolutions": {"a//b": "1.0.0"}}"#,
+      r#"{"resolutions": {"@scope": "1.0.0"}}"#,
+      r#"{"resolutions": {"foo@": "1.0.0"}}"#,
+      r#"{"resolutions": {"foo": "^1.0.0."}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err(), "{json}");
+    }
+  }
+
+  #[test]
+  fn should_parse_resolution_selectors() {
+    let selector = ResolutionSelector::parse("@s/a@^1/**/b").unwrap();
+    assert_eq!(
+      selector.segments(),
+      [
+        SelectorSegment::Package {
+          name: "@s/a".to_string(),
+          range: Some("^1".to_string()),
+        },
+        SelectorSegment::AnyDepth,
+        SelectorSegment::Package {
+          name: "b".to_string(),
+          range: None,
+        },
+      ]
+    );
+    assert_eq!(selector.package(), "b");
+  }
+
+  #[test]
+  fn should_label_invalid_resolutions_selector() {
+    let json = r#"{"resolutions": {"**/Foo": "1.0.0"}}"#;
+    let report = PackageJsonParser::parse_str(json)
+      .unwrap()
+      .resolutions()
+      .unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""**/Foo""#
+    );
+  }
+
+  #[test]
+  fn should_fail_deserialize_resolutions_when_type_is_invalid() {
+    let parsed = PackageJsonParser::parse_str(r#"{"resolutions": ["foo"]}"#).unwrap();
+    assert!(parsed.resolutions().is_err());
+  }
+}
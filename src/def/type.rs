@@ -4,6 +4,7 @@ use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 
+use crate::codes::TYPE_INVALID_VALUE;
 use crate::ext::{Validator, validation_error, value_range};
 
 #[derive(Debug, PartialEq, Eq, Serialize, Clone, Deref, DerefMut)]
@@ -16,7 +17,7 @@ impl<'de> Deserialize<'de> for Type {
   {
     struct TypeVisitor;
 
-    impl<'de> Visitor<'de> for TypeVisitor {
+    impl Visitor<'_> for TypeVisitor {
       type Value = Type;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -52,7 +53,7 @@ impl Validator for Type {
 
     Err(validation_error(
       "Invalid type",
-      Some("invalid_type"),
+      &TYPE_INVALID_VALUE,
       "Please provide a valid type",
       value_range(prop, &[]),
       "here",
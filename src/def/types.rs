@@ -16,7 +16,7 @@ impl<'de> Deserialize<'de> for Types {
   {
     struct TypesVisitor;
 
-    impl<'de> Visitor<'de> for TypesVisitor {
+    impl Visitor<'_> for TypesVisitor {
       type Value = Types;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16,7 +16,7 @@ impl<'de> Deserialize<'de> for Typings {
   {
     struct TypingsVisitor;
 
-    impl<'de> Visitor<'de> for TypingsVisitor {
+    impl Visitor<'_> for TypingsVisitor {
       type Value = Typings;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4,6 +4,7 @@ use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 
+use crate::codes::VERSION_INVALID_SEMVER;
 use crate::ext::{Validator, validation_error, value_range};
 
 #[derive(Debug, PartialEq, Serialize, Clone, Deref, DerefMut)]
@@ -16,7 +17,7 @@ impl<'de> Deserialize<'de> for Version {
   {
     struct VersionVisitor;
 
-    impl<'de> Visitor<'de> for VersionVisitor {
+    impl Visitor<'_> for VersionVisitor {
       type Value = Version;
 
       fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -55,7 +56,7 @@ impl Validator for Version {
 
     Err(validation_error(
       "Package version does not match required pattern",
-      Some("E0001"),
+      &VERSION_INVALID_SEMVER,
       r"Expected pattern: ^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)(?:-((?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+([0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$",
       value_range(prop, &[]),
       "here",
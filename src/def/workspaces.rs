@@ -0,0 +1,249 @@
+use jsonc_parser::ast::ObjectProp;
+use serde::de::{
+  self, IgnoredAny, MapAccess, SeqAccess, Visitor, value::MapAccessDeserializer,
+  value::SeqAccessDeserializer,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::codes::{WORKSPACES_ABSOLUTE_PATTERN, WORKSPACES_EMPTY_PATTERN};
+use crate::ext::{Validator, array_child, node_range, object_child, validation_error};
+
+/// The yarn `{ "packages": [...], "nohoist": [...] }` form.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Default)]
+pub struct WorkspacesObject {
+  pub packages: Vec<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nohoist: Option<Vec<String>>,
+}
+
+impl<'de> Deserialize<'de> for WorkspacesObject {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    const FIELDS: &[&str] = &["packages", "nohoist"];
+
+    enum Field {
+      Packages,
+      Nohoist,
+      Ignore,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+      fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+      where
+        D: Deserializer<'de>,
+      {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+          type Value = Field;
+
+          fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("`packages` or `nohoist`")
+          }
+
+          fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+          where
+            E: de::Error,
+          {
+            Ok(match value {
+              "packages" => Field::Packages,
+              "nohoist" => Field::Nohoist,
+              _ => Field::Ignore,
+            })
+          }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+      }
+    }
+
+    struct WorkspacesObjectVisitor;
+
+    impl<'de> Visitor<'de> for WorkspacesObjectVisitor {
+      type Value = WorkspacesObject;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an object with `packages` and optional `nohoist`")
+      }
+
+      fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let mut packages: Option<Vec<String>> = None;
+        let mut nohoist: Option<Vec<String>> = None;
+
+        while let Some(key) = map.next_key::<Field>()? {
+          match key {
+            Field::Packages => {
+              if packages.is_some() {
+                return Err(de::Error::duplicate_field("packages"));
+              }
+              packages = Some(map.next_value()?);
+            }
+            Field::Nohoist => {
+              if nohoist.is_some() {
+                return Err(de::Error::duplicate_field("nohoist"));
+              }
+              nohoist = Some(map.next_value()?);
+            }
+            Field::Ignore => {
+              let _: IgnoredAny = map.next_value()?;
+            }
+          }
+        }
+
+        Ok(WorkspacesObject {
+          packages: packages.unwrap_or_default(),
+          nohoist,
+        })
+      }
+    }
+
+    deserializer.deserialize_struct("WorkspacesObject", FIELDS, WorkspacesObjectVisitor)
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub enum Workspaces {
+  Array(Vec<String>),
+  Object(WorkspacesObject),
+}
+
+impl<'de> Deserialize<'de> for Workspaces {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct WorkspacesVisitor;
+
+    impl<'de> Visitor<'de> for WorkspacesVisitor {
+      type Value = Workspaces;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an array of glob patterns or an object for workspaces")
+      }
+
+      fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+      where
+        S: SeqAccess<'de>,
+      {
+        let values = Vec::<String>::deserialize(SeqAccessDeserializer::new(seq))?;
+        Ok(Workspaces::Array(values))
+      }
+
+      fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+      where
+        M: MapAccess<'de>,
+      {
+        let object = WorkspacesObject::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Workspaces::Object(object))
+      }
+    }
+
+    deserializer.deserialize_any(WorkspacesVisitor)
+  }
+}
+
+impl Workspaces {
+  /// The member glob patterns, whichever form the field uses.
+  pub fn packages(&self) -> &[String] {
+    match self {
+      Workspaces::Array(packages) => packages,
+      Workspaces::Object(object) => &object.packages,
+    }
+  }
+}
+
+impl Validator for Workspaces {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    let node = prop.map(|prop| &prop.value);
+    let packages = match self {
+      Workspaces::Array(_) => node,
+      Workspaces::Object(_) => object_child(node, "packages"),
+    };
+
+    for (index, pattern) in self.packages().iter().enumerate() {
+      let reason = if pattern.trim().is_empty() {
+        Some((
+          &WORKSPACES_EMPTY_PATTERN,
+          "Workspace patterns must not be empty",
+        ))
+      } else if pattern.starts_with('/') || pattern.contains(":\\") {
+        Some((
+          &WORKSPACES_ABSOLUTE_PATTERN,
+          "Workspace patterns must be relative to the package root",
+        ))
+      } else {
+        None
+      };
+
+      if let Some((code, reason)) = reason {
+        return Err(validation_error(
+          "Invalid workspace pattern",
+          code,
+          reason,
+          node_range(array_child(packages, index)),
+          "here",
+        ));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::PackageJsonParser;
+
+  #[test]
+  fn should_pass_validate_workspaces() {
+    let jsones = [
+      r#"{"workspaces": ["packages/*", "apps/web"]}"#,
+      r#"{"workspaces": {"packages": ["packages/*"], "nohoist": ["**/react-native"]}}"#,
+      r#"{"workspaces": {"nohoist": ["**/react-native"]}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_ok());
+    }
+  }
+
+  #[test]
+  fn should_fail_validate_workspaces() {
+    let jsones = [
+      r#"{"workspaces": ["packages/*", ""]}"#,
+      r#"{"workspaces": {"packages": ["/abs/path"]}}"#,
+    ];
+
+    for json in jsones {
+      let res = PackageJsonParser::parse_str(json).unwrap();
+      assert!(res.validate().is_err());
+    }
+  }
+
+  #[test]
+  fn should_deserialize_workspaces_successfully() {
+    let parsed = PackageJsonParser::parse_str(r#"{"workspaces": {"packages": ["a/*"]}}"#).unwrap();
+    let workspaces = parsed.workspaces().unwrap().unwrap();
+    assert_eq!(workspaces.packages(), ["a/*"]);
+  }
+
+  #[test]
+  fn should_fail_deserialize_workspaces_when_type_is_invalid() {
+    let parsed = PackageJsonParser::parse_str(r#"{"workspaces": "packages/*"}"#).unwrap();
+    assert!(parsed.workspaces().is_err());
+  }
+
+  #[test]
+  fn should_fail_deserialize_workspaces_when_json_is_invalid() {
+    let parsed = PackageJsonParser::parse_str("{");
+    assert!(parsed.is_err());
+  }
+}
@@ -0,0 +1,673 @@
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use jsonc_parser::ast::{Object, ObjectProp};
+use jsonc_parser::common::Ranged;
+use jsonc_parser::{CollectOptions, ParseOptions, parse_to_ast};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use serde_json::ser::{PrettyFormatter, Serializer};
+
+use crate::err::{EditError, ErrorKind};
+
+/// Edits package.json text in place, touching only the spans that change.
+///
+/// Every other byte — key order, indentation, line endings, comments and the
+/// trailing newline — is kept as it was in the original document. Inserted
+/// values follow the indentation and `key: value` spacing the file already
+/// uses.
+///
+/// ```
+/// use package_json_parser::PackageJsonEditor;
+///
+/// let mut editor = PackageJsonEditor::parse_str("{\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+/// editor.set_version("1.1.0").unwrap();
+/// editor.add_dependency("serde", "^1.0.0").unwrap();
+///
+/// assert_eq!(
+///   editor.as_str(),
+///   "{\n  \"version\": \"1.1.0\",\n  \"dependencies\": {\n    \"serde\": \"^1.0.0\"\n  }\n}\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageJsonEditor {
+  text: String,
+}
+
+struct Edit {
+  range: Range<usize>,
+  text: String,
+}
+
+struct Style {
+  /// One level of indentation, or `None` when the document is on one line.
+  indent: Option<String>,
+  colon: String,
+  newline: &'static str,
+}
+
+impl PackageJsonEditor {
+  pub fn parse_str(content: &str) -> Result<Self, EditError> {
+    let editor = Self {
+      text: content.to_string(),
+    };
+    editor.with_root(|_, _| Ok(()))?;
+    Ok(editor)
+  }
+
+  pub fn parse<P: AsRef<Path>>(path: P) -> miette::Result<Self> {
+    let content = fs::read_to_string(path).map_err(ErrorKind::IoError)?;
+    Ok(Self::parse_str(&content)?)
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.text
+  }
+
+  pub fn into_string(self) -> String {
+    self.text
+  }
+
+  /// Sets the value at `path`, creating missing parent objects on the way.
+  pub fn set<T: Serialize>(&mut self, path: &[&str], value: T) -> Result<(), EditError> {
+    let value = serde_json::to_value(value).map_err(|error| EditError::InvalidValue {
+      message: error.to_string(),
+    })?;
+    let Some((last, parents)) = path.split_last() else {
+      return Err(EditError::EmptyPath);
+    };
+
+    let edits = self.with_root(|text, root| {
+      let style = Style::detect(text, root);
+      let mut object = root;
+
+      for (index, key) in parents.iter().enumerate() {
+        let Some(prop) = object.get(key) else {
+          // Wrap the value in the objects that do not exist yet and insert
+          // the outermost one here.
+          let value = path[index + 1..]
+            .iter()
+            .rev()
+            .fold(value.clone(), |value, key| {
+              let mut map = serde_json::Map::new();
+              map.insert(key.to_string(), value);
+              JsonValue::Object(map)
+            });
+          return Ok(insert_prop(text, &style, object, key, &value));
+        };
+
+        object = prop
+          .value
+          .as_object()
+          .ok_or_else(|| EditError::NotAnObject {
+            path: path[..=index].join("."),
+          })?;
+      }
+
+      match object.get(last) {
+        Some(prop) => {
+          let range = prop.value.range();
+          let indent = line_indent(text, prop.range.start);
+          Ok(vec![Edit {
+            range: range.start..range.end,
+            text: style.render(&value, indent),
+          }])
+        }
+        None => Ok(insert_prop(text, &style, object, last, &value)),
+      }
+    })?;
+
+    self.apply(edits);
+    Ok(())
+  }
+
+  /// Removes the key at `path`, returning whether it existed.
+  pub fn remove(&mut self, path: &[&str]) -> Result<bool, EditError> {
+    let Some((last, parents)) = path.split_last() else {
+      return Err(EditError::EmptyPath);
+    };
+
+    let edits = self.with_root(|text, root| {
+      let mut object = root;
+
+      for (index, key) in parents.iter().enumerate() {
+        let Some(prop) = object.get(key) else {
+          return Ok(Vec::new());
+        };
+        object = prop
+          .value
+          .as_object()
+          .ok_or_else(|| EditError::NotAnObject {
+            path: path[..=index].join("."),
+          })?;
+      }
+
+      Ok(remove_prop(text, object, last))
+    })?;
+
+    let removed = !edits.is_empty();
+    self.apply(edits);
+    Ok(removed)
+  }
+
+  pub fn set_version(&mut self, version: &str) -> Result<(), EditError> {
+    self.set(&["version"], version)
+  }
+
+  pub fn add_dependency(&mut self, name: &str, spec: &str) -> Result<(), EditError> {
+    self.set(&["dependencies", name], spec)
+  }
+
+  pub fn add_dev_dependency(&mut self, name: &str, spec: &str) -> Result<(), EditError> {
+    self.set(&["devDependencies", name], spec)
+  }
+
+  pub fn remove_dependency(&mut self, name: &str) -> Result<bool, EditError> {
+    self.remove(&["dependencies", name])
+  }
+
+  pub fn set_script(&mut self, name: &str, command: &str) -> Result<(), EditError> {
+    self.set(&["scripts", name], command)
+  }
+
+  pub fn remove_script(&mut self, name: &str) -> Result<bool, EditError> {
+    self.remove(&["scripts", name])
+  }
+
+  fn with_root<T>(
+    &self,
+    f: impl FnOnce(&str, &Object) -> Result<T, EditError>,
+  ) -> Result<T, EditError> {
+    let ast = parse_to_ast(
+      &self.text,
+      &CollectOptions::default(),
+      &ParseOptions::default(),
+    )
+    .map_err(|error| EditError::InvalidDocument {
+      message: error.to_string(),
+    })?;
+
+    let root = ast
+      .value
+      .as_ref()
+      .and_then(|value| value.as_object())
+      .ok_or(EditError::RootNotObject)?;
+
+    f(&self.text, root)
+  }
+
+  fn apply(&mut self, mut edits: Vec<Edit>) {
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.range.start));
+    for edit in edits {
+      self.text.replace_range(edit.range, &edit.text);
+    }
+  }
+}
+
+impl fmt::Display for PackageJsonEditor {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.text)
+  }
+}
+
+impl Style {
+  fn detect(text: &str, root: &Object) -> Self {
+    let newline = if text.contains("\r\n") { "\r\n" } else { "\n" };
+
+    let Some(first) = root.properties.first() else {
+      return Self {
+        indent: Some("  ".to_string()),
+        colon: ": ".to_string(),
+        newline,
+      };
+    };
+
+    let start = line_start(text, first.range.start);
+    let indent = (start > root.range.start).then(|| {
+      let indent = &text[start..first.range.start];
+      if indent.is_empty() {
+        "  ".to_string()
+      } else {
+        indent.to_string()
+      }
+    });
+
+    let colon = text[first.name.range().end..first.value.range().start].to_string();
+    let colon = if colon.trim() == ":" {
+      colon
+    } else {
+      ": ".to_string()
+    };
+
+    Self {
+      indent,
+      colon,
+      newline,
+    }
+  }
+
+  fn render(&self, value: &JsonValue, line_indent: &str) -> String {
+    let Some(indent) = self.indent.as_ref() else {
+      return value.to_string();
+    };
+
+    let mut out = Vec::new();
+    let mut serializer =
+      Serializer::with_formatter(&mut out, PrettyFormatter::with_indent(indent.as_bytes()));
+    // Serializing a `serde_json::Value` into memory cannot fail.
+    value.serialize(&mut serializer).unwrap_or_default();
+    let pretty = String::from_utf8(out).unwrap_or_default();
+
+    pretty
+      .split('\n')
+      .collect::<Vec<_>>()
+      .join(&format!("{}{line_indent}", self.newline))
+  }
+
+  fn prop(&self, key: &str, value: &JsonValue, line_indent: &str) -> String {
+    format!(
+      "{}{}{}",
+      JsonValue::from(key),
+      self.colon,
+      self.render(value, line_indent)
+    )
+  }
+}
+
+fn insert_prop(
+  text: &str,
+  style: &Style,
+  object: &Object,
+  key: &str,
+  value: &JsonValue,
+) -> Vec<Edit> {
+  let open = object.range.start + 1;
+  let close = object.range.end - 1;
+
+  let Some(last) = object.properties.last() else {
+    let Some(unit) = style.indent.as_ref() else {
+      return vec![Edit {
+        range: open..open,
+        text: style.prop(key, value, ""),
+      }];
+    };
+
+    let outer = line_indent(text, object.range.start);
+    let inner = format!("{outer}{unit}");
+    let prop = style.prop(key, value, &inner);
+    let newline = style.newline;
+
+    if text[open..close].trim().is_empty() {
+      return vec![Edit {
+        range: open..close,
+        text: format!("{newline}{inner}{prop}{newline}{outer}"),
+      }];
+    }
+
+    // Keep comments that live inside the empty object.
+    return vec![Edit {
+      range: close..close,
+      text: format!("{newline}{inner}{prop}{newline}{outer}"),
+    }];
+  };
+
+  let end = last.range.end;
+  let after = &text[end..close];
+  let indent = line_indent(text, last.range.start);
+
+  let Some(line_break) = after.find('\n') else {
+    let separator = if style.indent.is_some() { ", " } else { "," };
+    return vec![Edit {
+      range: end..end,
+      text: format!("{separator}{}", style.prop(key, value, indent)),
+    }];
+  };
+
+  let line_end = end + after[..line_break].trim_end_matches('\r').len();
+  let has_comma = after.trim_start().starts_with(',');
+  let prop = style.prop(key, value, indent);
+  let newline = style.newline;
+
+  let mut edits = vec![Edit {
+    range: line_end..line_end,
+    text: format!(
+      "{newline}{indent}{prop}{}",
+      if has_comma { "," } else { "" }
+    ),
+  }];
+
+  if !has_comma {
+    edits.push(Edit {
+      range: end..end,
+      text: ",".to_string(),
+    });
+  }
+
+  edits
+}
+
+fn remove_prop(text: &str, object: &Object, key: &str) -> Vec<Edit> {
+  let Some(target) = object.get(key) else {
+    return Vec::new();
+  };
+  let Some(index) = object
+    .properties
+    .iter()
+    .position(|prop| prop.range == target.range)
+  else {
+    return Vec::new();
+  };
+
+  let open = object.range.start + 1;
+  let close = object.range.end - 1;
+  let remove = |range: Range<usize>| Edit {
+    range,
+    text: String::new(),
+  };
+
+  if let Some(comma) = comma_after(text, target, close) {
+    let gap = &text[target.range.end..comma];
+    if gap.trim().is_empty() {
+      return vec![remove(prop_span(text, target, open, comma + 1, close))];
+    }
+    // Comments sit between the value and its comma: keep them, drop the comma.
+    let comment = comma - gap.trim_start().len();
+    return vec![
+      remove(target.range.start..comment),
+      remove(comma..comma + 1),
+    ];
+  }
+
+  let span = prop_span(text, target, open, target.range.end, close);
+
+  if index == 0 {
+    // Collapse to `{}` unless comments are left around the property.
+    if text[open..span.start].trim().is_empty() && text[span.end..close].trim().is_empty() {
+      return vec![remove(open..close)];
+    }
+    return vec![remove(span)];
+  }
+
+  // The last property has no comma of its own, so drop the one before it.
+  let previous = &object.properties[index - 1];
+  match comma_after(text, previous, close) {
+    Some(comma) => vec![remove(comma..comma + 1), remove(span)],
+    None => vec![remove(span)],
+  }
+}
+
+/// The span of a property up to `end` (its value or its comma), with its
+/// trailing `//` comment and, when it sits on a line of its own, that line.
+fn prop_span(text: &str, prop: &ObjectProp, open: usize, end: usize, close: usize) -> Range<usize> {
+  let start = line_start(text, prop.range.start);
+  let owns_line = start >= open && text[start..prop.range.start].trim().is_empty();
+  let from = if owns_line { start } else { prop.range.start };
+
+  let mut to = trailing_comment_end(text, end, close);
+  if owns_line {
+    if text[to..].starts_with("\r\n") {
+      to += 2;
+    } else if text[to..].starts_with('\n') {
+      to += 1;
+    }
+  }
+
+  from..to
+}
+
+/// Finds the comma after a property, skipping whitespace and comments.
+fn comma_after(text: &str, prop: &ObjectProp, close: usize) -> Option<usize> {
+  let mut offset = prop.range.end;
+  loop {
+    let rest = &text[offset..close];
+    let trimmed = rest.trim_start();
+    offset += rest.len() - trimmed.len();
+
+    if trimmed.starts_with("//") {
+      offset += trimmed.find('\n').unwrap_or(trimmed.len());
+    } else if trimmed.starts_with("/*") {
+      offset += trimmed.find("*/").map_or(trimmed.len(), |end| end + 2);
+    } else {
+      return trimmed.starts_with(',').then_some(offset);
+    }
+  }
+}
+
+/// Skips spaces and a `//` comment after `offset` on the same line.
+fn trailing_comment_end(text: &str, offset: usize, close: usize) -> usize {
+  let rest = &text[offset..close];
+  let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+  let line = line.trim_end_matches('\r');
+  let trimmed = line.trim_start_matches([' ', '\t']);
+
+  if trimmed.starts_with("//") {
+    offset + line.len()
+  } else {
+    offset + line.len() - trimmed.len()
+  }
+}
+
+fn line_start(text: &str, offset: usize) -> usize {
+  text[..offset].rfind('\n').map_or(0, |index| index + 1)
+}
+
+/// The leading whitespace of the line that contains `offset`.
+fn line_indent(text: &str, offset: usize) -> &str {
+  let start = line_start(text, offset);
+  let line = &text[start..offset];
+  &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::PackageJsonEditor;
+  use crate::err::EditError;
+
+  const MANIFEST: &str = r#"{
+    "name": "demo",
+    "version": "1.0.0",
+    // build helpers
+    "scripts": {
+        "build": "tsc",
+        "test": "vitest" // keep me
+    },
+    "dependencies": {
+        "react": "^18.0.0"
+    }
+}
+"#;
+
+  #[test]
+  fn should_set_existing_value_in_place() {
+    let mut editor = PackageJsonEditor::parse_str(MANIFEST).unwrap();
+    editor.set_version("2.0.0").unwrap();
+
+    assert_eq!(
+      editor.as_str(),
+      MANIFEST.split("1.0.0").collect::<Vec<_>>().join("2.0.0")
+    );
+  }
+
+  #[test]
+  fn should_add_dependency_with_file_indentation() {
+    let mut editor = PackageJsonEditor::parse_str(MANIFEST).unwrap();
+    editor.add_dependency("lodash", "^4.17.21").unwrap();
+    editor.add_dev_dependency("typescript", "^5.0.0").unwrap();
+
+    let expected = r#"{
+    "name": "demo",
+    "version": "1.0.0",
+    // build helpers
+    "scripts": {
+        "build": "tsc",
+        "test": "vitest" // keep me
+    },
+    "dependencies": {
+        "react": "^18.0.0",
+        "lodash": "^4.17.21"
+    },
+    "devDependencies": {
+        "typescript": "^5.0.0"
+    }
+}
+"#;
+    assert_eq!(editor.as_str(), expected);
+  }
+
+  #[test]
+  fn should_remove_script_and_keep_comments() {
+    let mut editor = PackageJsonEditor::parse_str(MANIFEST).unwrap();
+    assert!(editor.remove_script("build").unwrap());
+    assert!(!editor.remove_script("missing").unwrap());
+
+    let expected = r#"{
+    "name": "demo",
+    "version": "1.0.0",
+    // build helpers
+    "scripts": {
+        "test": "vitest" // keep me
+    },
+    "dependencies": {
+        "react": "^18.0.0"
+    }
+}
+"#;
+    assert_eq!(editor.as_str(), expected);
+  }
+
+  #[test]
+  fn should_remove_last_property() {
+    let mut editor = PackageJsonEditor::parse_str(MANIFEST).unwrap();
+    assert!(editor.remove(&["dependencies"]).unwrap());
+    assert!(editor.remove_script("test").unwrap());
+
+    let expected = r#"{
+    "name": "demo",
+    "version": "1.0.0",
+    // build helpers
+    "scripts": {
+        "build": "tsc"
+    }
+}
+"#;
+    assert_eq!(editor.as_str(), expected);
+  }
+
+  #[test]
+  fn should_keep_comments_when_removing_only_property() {
+    let mut editor = PackageJsonEditor::parse_str(
+      r#"{
+  "scripts": {
+    // the only script
+    "build": "tsc" // compile
+  }
+}
+"#,
+    )
+    .unwrap();
+    assert!(editor.remove_script("build").unwrap());
+
+    let expected = r#"{
+  "scripts": {
+    // the only script
+  }
+}
+"#;
+    assert_eq!(editor.as_str(), expected);
+  }
+
+  #[test]
+  fn should_keep_comments_when_removing_last_property() {
+    let mut editor = PackageJsonEditor::parse_str(
+      r#"{
+  "scripts": {
+    "build": "tsc", // compile
+    // tests
+    "test": "vitest"
+  }
+}
+"#,
+    )
+    .unwrap();
+    assert!(editor.remove_script("test").unwrap());
+
+    let expected = r#"{
+  "scripts": {
+    "build": "tsc" // compile
+    // tests
+  }
+}
+"#;
+    assert_eq!(editor.as_str(), expected);
+  }
+
+  #[test]
+  fn should_keep_comments_before_removed_comma() {
+    let cases = [
+      (
+        "{\n  \"a\": 1 /* keep */,\n  \"b\": 2\n}",
+        "{\n  /* keep */\n  \"b\": 2\n}",
+      ),
+      (
+        "{\n  \"a\": 1 // keep\n  , \"b\": 2\n}",
+        "{\n  // keep\n   \"b\": 2\n}",
+      ),
+    ];
+
+    for (source, expected) in cases {
+      let mut editor = PackageJsonEditor::parse_str(source).unwrap();
+      assert!(editor.remove(&["a"]).unwrap());
+      assert_eq!(editor.as_str(), expected);
+      assert!(PackageJsonEditor::parse_str(editor.as_str()).is_ok());
+    }
+  }
+
+  #[test]
+  fn should_edit_single_line_documents() {
+    let mut editor = PackageJsonEditor::parse_str(r#"{"name":"demo","scripts":{}}"#).unwrap();
+    editor.set_script("build", "tsc").unwrap();
+    editor.set(&["publishConfig", "access"], "public").unwrap();
+    editor.remove(&["name"]).unwrap();
+
+    assert_eq!(
+      editor.as_str(),
+      r#"{"scripts":{"build":"tsc"},"publishConfig":{"access":"public"}}"#
+    );
+  }
+
+  #[test]
+  fn should_keep_crlf_line_endings() {
+    let mut editor = PackageJsonEditor::parse_str("{\r\n  \"name\": \"demo\"\r\n}\r\n").unwrap();
+    editor.set_version("1.0.0").unwrap();
+
+    assert_eq!(
+      editor.as_str(),
+      "{\r\n  \"name\": \"demo\",\r\n  \"version\": \"1.0.0\"\r\n}\r\n"
+    );
+  }
+
+  #[test]
+  fn should_fail_to_edit_through_non_object() {
+    let mut editor = PackageJsonEditor::parse_str(MANIFEST).unwrap();
+    let result = editor.set(&["name", "first"], "x");
+
+    assert_eq!(
+      result,
+      Err(EditError::NotAnObject {
+        path: "name".to_string()
+      })
+    );
+    assert_eq!(editor.as_str(), MANIFEST);
+  }
+
+  #[test]
+  fn should_fail_to_parse_invalid_document() {
+    assert!(PackageJsonEditor::parse_str("{").is_err());
+    assert_eq!(
+      PackageJsonEditor::parse_str("[]"),
+      Err(EditError::RootNotObject)
+    );
+  }
+}
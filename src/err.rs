@@ -1,11 +1,16 @@
+// `miette::Diagnostic` derive expands to `str::replace` calls we don't control.
+#![allow(clippy::disallowed_methods)]
+
 use std::fmt::Debug;
 
-use miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode, SourceSpan};
+use miette::{Diagnostic, MietteDiagnostic, NamedSource, SourceCode, SourceSpan};
 use thiserror::Error;
 
+use crate::codes;
+
 #[derive(Error, Diagnostic, Debug)]
-#[error("JSON parsing failed")]
-#[diagnostic(code(package_json::json_parse_failed), url(docsrs))]
+#[error("JSON parsing failed: {message}")]
+#[diagnostic(code("pkg::syntax::invalid-json"), url("{}", codes::SYNTAX_INVALID_JSON.url()))]
 pub struct JsonParseError<S>
 where
   S: SourceCode + Debug,
@@ -13,11 +18,10 @@ where
   #[source_code]
   pub src: S,
 
-  #[label]
-  pub primary_span: Option<SourceSpan>,
+  pub message: String,
 
-  #[label(collection, "related to this")]
-  pub other_spans: Vec<LabeledSpan>,
+  #[label("here")]
+  pub span: SourceSpan,
 
   #[help]
   pub advice: Option<String>,
@@ -29,9 +33,26 @@ where
 pub type JsonFileParseError = JsonParseError<NamedSource<String>>;
 pub type JsonStrParseError = JsonParseError<String>;
 
+#[derive(Error, Diagnostic, Debug)]
+#[error("YAML parsing failed: {message}")]
+#[diagnostic(code("pkg::syntax::invalid-yaml"), url("{}", codes::SYNTAX_INVALID_YAML.url()))]
+pub struct YamlParseError<S>
+where
+  S: SourceCode + Debug,
+{
+  #[source_code]
+  pub src: S,
+
+  pub message: String,
+
+  #[label("here")]
+  pub span: SourceSpan,
+}
+
 #[derive(Debug, Error, Diagnostic)]
 pub enum ErrorKind {
   #[error("name is required")]
+  #[diagnostic(code("pkg::name::required"), url("{}", codes::NAME_REQUIRED.url()))]
   NameRequired,
 
   #[error(transparent)]
@@ -41,6 +62,111 @@ pub enum ErrorKind {
   JsonStrParseError(JsonStrParseError),
 
   #[error("IO error")]
-  #[diagnostic(code(package_json::io_error), url(docsrs))]
+  #[diagnostic(code("pkg::io::read-failed"), url("{}", codes::IO_READ_FAILED.url()))]
   IoError(#[from] std::io::Error),
+
+  #[error("No package.json, package.json5 or package.yaml found in `{dir}`")]
+  #[diagnostic(code("pkg::io::manifest-not-found"), url("{}", codes::IO_MANIFEST_NOT_FOUND.url()))]
+  ManifestNotFound { dir: String },
+}
+
+/// Aggregated validation failure with one related diagnostic per broken field.
+#[derive(Debug, Error, Diagnostic)]
+#[error("package.json validation failed with {} error(s)", related.len())]
+#[diagnostic(code("pkg::validation-failed"), url("{}", codes::VALIDATION_FAILED.url()))]
+pub struct ValidationReport {
+  #[related]
+  pub related: Vec<MietteDiagnostic>,
+}
+
+/// Why a specifier could not be resolved through `exports` or `imports`.
+#[derive(Debug, Error, Diagnostic, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+  #[error("package.json does not define \"exports\"")]
+  #[diagnostic(code("pkg::resolve::exports-not-defined"), url("{}", codes::RESOLVE_EXPORTS_NOT_DEFINED.url()))]
+  ExportsNotDefined,
+
+  #[error("Package subpath `{subpath}` is not defined by \"exports\"")]
+  #[diagnostic(code("pkg::resolve::path-not-exported"), url("{}", codes::RESOLVE_PATH_NOT_EXPORTED.url()))]
+  PackagePathNotExported { subpath: String },
+
+  #[error("Package import specifier `{specifier}` is not defined in \"imports\"")]
+  #[diagnostic(code("pkg::resolve::import-not-defined"), url("{}", codes::RESOLVE_IMPORT_NOT_DEFINED.url()))]
+  PackageImportNotDefined { specifier: String },
+
+  #[error("Invalid module specifier `{specifier}`: {reason}")]
+  #[diagnostic(code("pkg::resolve::invalid-specifier"), url("{}", codes::RESOLVE_INVALID_SPECIFIER.url()))]
+  InvalidModuleSpecifier { specifier: String, reason: String },
+
+  #[error("Invalid package target `{target}` for `{key}`")]
+  #[diagnostic(code("pkg::resolve::invalid-target"), url("{}", codes::RESOLVE_INVALID_TARGET.url()))]
+  InvalidPackageTarget { key: String, target: String },
+
+  #[error("Invalid package configuration: {reason}")]
+  #[diagnostic(code("pkg::resolve::invalid-configuration"), url("{}", codes::RESOLVE_INVALID_CONFIGURATION.url()))]
+  InvalidPackageConfiguration { reason: String },
+}
+
+/// Why a [`crate::PackageJsonEditor`] could not load or change a document.
+#[derive(Debug, Error, Diagnostic, Clone, PartialEq, Eq)]
+pub enum EditError {
+  #[error("Failed to parse package.json for editing: {message}")]
+  #[diagnostic(code("pkg::edit::invalid-document"), url("{}", codes::EDIT_INVALID_DOCUMENT.url()))]
+  InvalidDocument { message: String },
+
+  #[error("package.json root must be a JSON object")]
+  #[diagnostic(code("pkg::edit::root-not-object"), url("{}", codes::EDIT_ROOT_NOT_OBJECT.url()))]
+  RootNotObject,
+
+  #[error("`{path}` is not an object")]
+  #[diagnostic(code("pkg::edit::not-an-object"), url("{}", codes::EDIT_NOT_AN_OBJECT.url()))]
+  NotAnObject { path: String },
+
+  #[error("An edit path must contain at least one key")]
+  #[diagnostic(code("pkg::edit::empty-path"), url("{}", codes::EDIT_EMPTY_PATH.url()))]
+  EmptyPath,
+
+  #[error("Value cannot be written as JSON: {message}")]
+  #[diagnostic(code("pkg::edit::invalid-value"), url("{}", codes::EDIT_INVALID_VALUE.url()))]
+  InvalidValue { message: String },
+}
+
+/// Why the members of a workspace could not be discovered.
+#[derive(Debug, Error, Diagnostic, Clone, PartialEq, Eq)]
+pub enum WorkspaceError {
+  #[error("Invalid workspace pattern `{pattern}`: {reason}")]
+  #[diagnostic(code("pkg::workspace::invalid-pattern"), url("{}", codes::WORKSPACE_INVALID_PATTERN.url()))]
+  InvalidPattern { pattern: String, reason: String },
+
+  #[error("Failed to read `{path}`: {reason}")]
+  #[diagnostic(code("pkg::workspace::invalid-pnpm-workspace"), url("{}", codes::WORKSPACE_INVALID_PNPM_WORKSPACE.url()))]
+  InvalidPnpmWorkspace { path: String, reason: String },
+}
+
+/// Why a workspace dependency graph has no valid build order.
+#[derive(Debug, Error, Diagnostic, Clone, PartialEq, Eq)]
+pub enum GraphError {
+  #[error("Dependency cycle detected: {}", cycle.join(" -> "))]
+  #[diagnostic(
+    code("pkg::graph::dependency-cycle"),
+    url("{}", codes::GRAPH_DEPENDENCY_CYCLE.url()),
+    help("Break the cycle or leave the offending dependency kind out of the graph")
+  )]
+  DependencyCycle { cycle: Vec<String> },
+}
+
+/// Why a lint rule configuration could not be loaded.
+#[derive(Debug, Error, Diagnostic, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+  #[error("Unsupported rule config file `{path}`")]
+  #[diagnostic(
+    code("pkg::config::unsupported-format"),
+    url("{}", codes::CONFIG_UNSUPPORTED_FORMAT.url()),
+    help("Use a `.json` or `.toml` file")
+  )]
+  UnsupportedFormat { path: String },
+
+  #[error("Invalid rule config: {reason}")]
+  #[diagnostic(code("pkg::config::invalid"), url("{}", codes::CONFIG_INVALID.url()))]
+  InvalidConfig { reason: String },
 }
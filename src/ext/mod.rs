@@ -1,7 +1,11 @@
 mod diagnostic;
 mod range;
+mod source_map;
+mod syntax;
 mod validator;
 
 pub use diagnostic::*;
 pub use range::*;
+pub use source_map::*;
+pub use syntax::*;
 pub use validator::*;
@@ -1,6 +1,9 @@
 use std::ops::Range;
 
-use jsonc_parser::{ast::ObjectProp, common::Ranged};
+use jsonc_parser::{
+  ast::{ObjectProp, Value},
+  common::Ranged,
+};
 
 /// 从 ObjectProp 中按路径提取值的 range。
 ///
@@ -27,3 +30,25 @@ pub fn value_range(prop: Option<&ObjectProp>, path: &[&str]) -> Option<Range<usi
 
   None
 }
+
+/// 返回任意 AST 值节点自身的 range，用于数组元素、嵌套条件等无法按 key 路径定位的场景。
+pub fn node_range(value: Option<&Value>) -> Option<Range<usize>> {
+  let r = value?.range();
+  Some(r.start..r.end)
+}
+
+/// 取对象节点中指定 key 对应的值节点。
+pub fn object_child<'a, 'b>(value: Option<&'b Value<'a>>, key: &str) -> Option<&'b Value<'a>> {
+  value?.as_object()?.get(key).map(|prop| &prop.value)
+}
+
+/// 返回对象节点中指定 key 本身（而非其值）的 range，用于 key 不合法的场景。
+pub fn key_range(value: Option<&Value>, key: &str) -> Option<Range<usize>> {
+  let r = value?.as_object()?.get(key)?.name.range();
+  Some(r.start..r.end)
+}
+
+/// 取数组节点中指定下标对应的元素节点。
+pub fn array_child<'a, 'b>(value: Option<&'b Value<'a>>, index: usize) -> Option<&'b Value<'a>> {
+  value?.as_array()?.elements.get(index)
+}
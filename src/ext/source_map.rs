@@ -0,0 +1,110 @@
+use std::ops::Range;
+
+use jsonc_parser::{ast::Value, common::Ranged};
+use rustc_hash::FxHashMap;
+
+/// 将生成的 JSON 文本中的 range 映射回原始源文件（例如 YAML）中的 range。
+///
+/// 每个 JSON 值节点按路径与原始文件中的节点配对；若字符串在两边逐字相同，
+/// 则字符串内部的子 range（例如某个 SPDX 标识符）也能精确映射。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+  nodes: Vec<MappedNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MappedNode {
+  generated: Range<usize>,
+  original: Range<usize>,
+  /// 逐字相同的字符串内容在两边的起始偏移。
+  verbatim: Option<(Range<usize>, usize)>,
+}
+
+impl SourceMap {
+  pub fn new(
+    generated: &str,
+    root: &Value,
+    original: &str,
+    spans: &FxHashMap<Vec<String>, Range<usize>>,
+  ) -> Self {
+    let mut map = Self::default();
+    let mut path = Vec::new();
+    map.collect(generated, root, original, spans, &mut path);
+    map
+  }
+
+  fn collect(
+    &mut self,
+    generated: &str,
+    value: &Value,
+    original: &str,
+    spans: &FxHashMap<Vec<String>, Range<usize>>,
+    path: &mut Vec<String>,
+  ) {
+    if let Some(span) = spans.get(path.as_slice()) {
+      let range = value.range();
+      let generated_range = range.start..range.end;
+      let verbatim = value.as_string_lit().and_then(|_| {
+        let inner = generated_range.start + 1..generated_range.end - 1;
+        let text = generated.get(inner.clone())?;
+        let source = original.get(span.clone())?;
+        let offset = source.find(text)?;
+        (source.len() - text.len() <= 2).then_some((inner, span.start + offset))
+      });
+
+      self.nodes.push(MappedNode {
+        generated: generated_range,
+        original: span.clone(),
+        verbatim,
+      });
+    }
+
+    match value {
+      Value::Object(object) => {
+        for prop in &object.properties {
+          path.push(prop.name.as_str().to_string());
+          self.collect(generated, &prop.value, original, spans, path);
+          path.pop();
+        }
+      }
+      Value::Array(array) => {
+        for (index, element) in array.elements.iter().enumerate() {
+          path.push(index.to_string());
+          self.collect(generated, element, original, spans, path);
+          path.pop();
+        }
+      }
+      _ => {}
+    }
+  }
+
+  /// 仅当 `range` 恰好是某个值节点时返回其在原始文件中的 range，用于需要精确替换的修复。
+  pub fn translate_node(&self, range: Range<usize>) -> Option<Range<usize>> {
+    self
+      .nodes
+      .iter()
+      .find(|node| node.generated == range)
+      .map(|node| node.original.clone())
+  }
+
+  /// 返回最内层包含 `range` 的节点在原始文件中的 range。
+  pub fn translate(&self, range: Range<usize>) -> Option<Range<usize>> {
+    let node = self
+      .nodes
+      .iter()
+      .filter(|node| node.generated.start <= range.start && range.end <= node.generated.end)
+      .min_by_key(|node| node.generated.len())?;
+
+    if node.generated == range {
+      return Some(node.original.clone());
+    }
+
+    match &node.verbatim {
+      Some((inner, start)) if inner.start <= range.start && range.end <= inner.end => {
+        let offset = start + range.start - inner.start;
+        Some(offset..offset + range.len())
+      }
+      _ => Some(node.original.clone()),
+    }
+  }
+}
@@ -0,0 +1,160 @@
+use std::ops::Range;
+
+use jsonc_parser::ParseStringErrorKind;
+use jsonc_parser::errors::{ParseError, ParseErrorKind};
+
+/// Finds the exact span and a specific message for a syntax error.
+///
+/// The jsonc parser reports the range of the offending token, so its error
+/// is preferred. Input it accepts but serde rejects (single quotes,
+/// out-of-range numbers, ...) falls back to serde's line and column.
+pub fn locate_syntax_error(
+  content: &str,
+  jsonc_error: Option<&ParseError>,
+  serde_error: Option<&serde_json::Error>,
+) -> (String, Range<usize>) {
+  if let Some(error) = jsonc_error {
+    let range = error.range();
+    return (
+      describe_syntax_error(error.kind()),
+      widen(content, range.start..range.end),
+    );
+  }
+
+  let Some(error) = serde_error else {
+    return ("invalid JSON".to_string(), widen(content, 0..0));
+  };
+
+  let offset = line_column_offset(content, error.line(), error.column());
+  let message = error.to_string();
+  let suffix = format!(" at line {} column {}", error.line(), error.column());
+  let message = message
+    .strip_suffix(&suffix)
+    .unwrap_or(&message)
+    .to_string();
+
+  (message, widen(content, offset..offset))
+}
+
+fn describe_syntax_error(kind: &ParseErrorKind) -> String {
+  let message = match kind {
+    ParseErrorKind::CommentsNotAllowed => "comments are not allowed in JSON",
+    ParseErrorKind::ExpectedColonAfterObjectKey => "expected `:` after object key",
+    ParseErrorKind::ExpectedObjectValue => "expected a value after `:`",
+    ParseErrorKind::ExpectedDigit
+    | ParseErrorKind::ExpectedDigitFollowingNegativeSign
+    | ParseErrorKind::ExpectedPlusMinusOrDigitInNumberLiteral => "invalid number",
+    ParseErrorKind::ExpectedStringObjectProperty => "object keys must be double-quoted strings",
+    ParseErrorKind::MultipleRootJsonValues => "trailing characters after the JSON value",
+    ParseErrorKind::String(ParseStringErrorKind::UnterminatedStringLiteral) => {
+      "unterminated string"
+    }
+    ParseErrorKind::String(ParseStringErrorKind::ExpectedFourHexDigits) => {
+      "expected four hex digits in unicode escape"
+    }
+    ParseErrorKind::String(ParseStringErrorKind::InvalidUnicodeEscapeSequence(_)) => {
+      "invalid unicode escape sequence"
+    }
+    ParseErrorKind::String(_) => "invalid escape in string",
+    ParseErrorKind::TrailingCommasNotAllowed => "trailing commas are not allowed in JSON",
+    ParseErrorKind::UnexpectedCloseBrace => "unexpected `}`",
+    ParseErrorKind::UnexpectedCloseBracket => "unexpected `]`",
+    ParseErrorKind::UnexpectedColon => "unexpected `:`",
+    ParseErrorKind::UnexpectedComma => "unexpected `,`",
+    ParseErrorKind::UnexpectedWord => "unexpected word, expected a JSON value",
+    ParseErrorKind::UnexpectedToken => "unexpected token",
+    ParseErrorKind::UnexpectedTokenInObject => "expected `,` or `}`",
+    ParseErrorKind::UnterminatedArray => "expected `,` or `]`",
+    ParseErrorKind::UnterminatedCommentBlock => "unterminated block comment",
+    ParseErrorKind::UnterminatedObject => "expected `,` or `}`",
+  };
+  message.to_string()
+}
+
+/// Converts serde's one-based line and column into a byte offset.
+fn line_column_offset(content: &str, line: usize, column: usize) -> usize {
+  let line_start = content
+    .match_indices('\n')
+    .nth(line.saturating_sub(2))
+    .filter(|_| line > 1)
+    .map_or(0, |(index, _)| index + 1);
+
+  let mut offset = (line_start + column.saturating_sub(1)).min(content.len());
+  while !content.is_char_boundary(offset) {
+    offset -= 1;
+  }
+  offset
+}
+
+/// Trims whitespace the scanner may include and widens empty ranges to the
+/// next character so they stay visible.
+fn widen(content: &str, range: Range<usize>) -> Range<usize> {
+  let text = &content[range.clone()];
+  let trimmed = text.trim_end();
+  if !trimmed.is_empty() {
+    return range.start..range.start + trimmed.len();
+  }
+
+  let start = range.start.min(content.len());
+  match content[start..].chars().next() {
+    Some(next) => start..start + next.len_utf8(),
+    None => {
+      let previous = content[..start]
+        .chars()
+        .next_back()
+        .map_or(0, char::len_utf8);
+      start - previous..start
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::locate_syntax_error;
+  use jsonc_parser::{CollectOptions, ParseOptions, parse_to_ast};
+
+  fn locate(content: &str) -> (String, String) {
+    let options = ParseOptions {
+      allow_comments: false,
+      allow_loose_object_property_names: false,
+      allow_trailing_commas: false,
+    };
+    let jsonc_error = parse_to_ast(content, &CollectOptions::default(), &options).err();
+    let serde_error = serde_json::from_str::<serde_json::Value>(content).err();
+    let (message, range) = locate_syntax_error(content, jsonc_error.as_ref(), serde_error.as_ref());
+    (message, content[range].to_string())
+  }
+
+  #[test]
+  fn should_locate_syntax_errors() {
+    assert_eq!(
+      locate("{\n  \"a\": 1\n  \"b\": 2\n}"),
+      ("expected `,` or `}`".to_string(), "\"".to_string())
+    );
+    assert_eq!(
+      locate(r#"{"a": "x"#),
+      ("unterminated string".to_string(), "\"x".to_string())
+    );
+    assert_eq!(
+      locate(r#"{"a": 1,}"#),
+      (
+        "trailing commas are not allowed in JSON".to_string(),
+        ",".to_string()
+      )
+    );
+    assert_eq!(
+      locate("{\"a\": 1} // done"),
+      (
+        "comments are not allowed in JSON".to_string(),
+        "// done".to_string()
+      )
+    );
+  }
+
+  #[test]
+  fn should_fall_back_to_serde_position() {
+    let (message, text) = locate("{\r\n  \"a\": 'x'\r\n}");
+    assert_eq!(message, "expected value");
+    assert_eq!(text, "'");
+  }
+}
@@ -0,0 +1,457 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::err::GraphError;
+use crate::{DependencySpec, PackageJsonParser, SemVersion, Workspace};
+
+/// The dependency field an edge was declared in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum DependencyKind {
+  Dependencies,
+  DevDependencies,
+  PeerDependencies,
+  OptionalDependencies,
+}
+
+impl DependencyKind {
+  pub const ALL: [DependencyKind; 4] = [
+    DependencyKind::Dependencies,
+    DependencyKind::DevDependencies,
+    DependencyKind::PeerDependencies,
+    DependencyKind::OptionalDependencies,
+  ];
+
+  /// The package.json key this kind is read from.
+  pub fn field(&self) -> &'static str {
+    match self {
+      DependencyKind::Dependencies => "dependencies",
+      DependencyKind::DevDependencies => "devDependencies",
+      DependencyKind::PeerDependencies => "peerDependencies",
+      DependencyKind::OptionalDependencies => "optionalDependencies",
+    }
+  }
+}
+
+/// `from` depends on the member `to`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyEdge {
+  pub from: String,
+  pub to: String,
+  pub kind: DependencyKind,
+  pub spec: String,
+}
+
+/// Dependencies between the members of a workspace.
+///
+/// Only specs that would link the local member are edges: `workspace:`,
+/// `file:` and `link:` specs, npm aliases of a member, and semver ranges that
+/// the member's own `version` satisfies. Anything else is assumed to come
+/// from the registry.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+  /// Member name to its version, in insertion order.
+  packages: IndexMap<String, Option<SemVersion>>,
+  edges: Vec<DependencyEdge>,
+}
+
+impl DependencyGraph {
+  /// Builds the graph from every dependency field.
+  pub fn new<'a>(packages: impl IntoIterator<Item = &'a PackageJsonParser>) -> Self {
+    Self::with_kinds(packages, &DependencyKind::ALL)
+  }
+
+  /// Builds the graph from the given dependency fields only, e.g. to leave
+  /// `devDependencies` out of a release order.
+  pub fn with_kinds<'a>(
+    packages: impl IntoIterator<Item = &'a PackageJsonParser>,
+    kinds: &[DependencyKind],
+  ) -> Self {
+    let manifests = packages
+      .into_iter()
+      .filter_map(|manifest| Some((manifest.raw_str("name")?, manifest)))
+      .collect::<Vec<_>>();
+
+    let mut graph = Self::default();
+    for (name, manifest) in &manifests {
+      let version = manifest
+        .raw_str("version")
+        .and_then(|version| SemVersion::parse(version).ok());
+      graph.packages.entry(name.to_string()).or_insert(version);
+    }
+
+    for (from, manifest) in &manifests {
+      for kind in kinds {
+        let Some(map) = manifest
+          .raw_field(kind.field())
+          .and_then(|value| value.as_object())
+        else {
+          continue;
+        };
+
+        for (name, spec) in map {
+          let Some(spec) = spec.as_str() else {
+            continue;
+          };
+          if let Some(to) = graph.link_target(name, spec) {
+            graph.edges.push(DependencyEdge {
+              from: from.to_string(),
+              to,
+              kind: *kind,
+              spec: spec.to_string(),
+            });
+          }
+        }
+      }
+    }
+
+    graph
+  }
+
+  /// Builds the graph from the members of a workspace that parsed.
+  pub fn from_workspace(workspace: &Workspace) -> Self {
+    Self::new(
+      workspace
+        .members()
+        .iter()
+        .filter_map(|member| member.manifest()),
+    )
+  }
+
+  fn link_target(&self, name: &str, spec: &str) -> Option<String> {
+    let member = |name: &str| self.packages.get_key_value(name);
+
+    match DependencySpec::parse(spec).ok()? {
+      DependencySpec::Workspace(_) | DependencySpec::File(_) | DependencySpec::Link(_) => {
+        member(name).map(|(name, _)| name.clone())
+      }
+      DependencySpec::Range(range) => {
+        let (name, version) = member(name)?;
+        match version {
+          Some(version) if !range.satisfies(version) => None,
+          _ => Some(name.clone()),
+        }
+      }
+      DependencySpec::Alias { name: target, spec } => {
+        let (target, version) = member(&target)?;
+        match (*spec, version) {
+          (DependencySpec::Range(range), Some(version)) if !range.satisfies(version) => None,
+          _ => Some(target.clone()),
+        }
+      }
+      _ => None,
+    }
+  }
+
+  /// Member names in the order they were given.
+  pub fn packages(&self) -> impl Iterator<Item = &str> {
+    self.packages.keys().map(String::as_str)
+  }
+
+  pub fn edges(&self) -> &[DependencyEdge] {
+    &self.edges
+  }
+
+  /// Members that `name` depends on directly.
+  pub fn dependencies(&self, name: &str) -> Vec<&str> {
+    self.neighbours(name, |edge| (&edge.from, &edge.to))
+  }
+
+  /// Members that depend on `name` directly.
+  pub fn dependents(&self, name: &str) -> Vec<&str> {
+    self.neighbours(name, |edge| (&edge.to, &edge.from))
+  }
+
+  fn neighbours<'a>(
+    &'a self,
+    name: &str,
+    direction: impl Fn(&'a DependencyEdge) -> (&'a String, &'a String),
+  ) -> Vec<&'a str> {
+    let neighbours = self
+      .edges
+      .iter()
+      .map(direction)
+      .filter(|(start, _)| start.as_str() == name)
+      .map(|(_, end)| end.as_str())
+      .collect::<BTreeSet<_>>();
+    neighbours.into_iter().collect()
+  }
+
+  /// Members ordered so that every package comes after its dependencies.
+  ///
+  /// Ties are broken by name, so the order is stable across runs. Fails with
+  /// the first cycle found when the graph is not acyclic.
+  pub fn topological_order(&self) -> Result<Vec<&str>, GraphError> {
+    let mut pending = self
+      .packages()
+      .map(|name| (name, self.dependencies(name).len()))
+      .collect::<IndexMap<_, _>>();
+
+    let mut ready = pending
+      .iter()
+      .filter(|(_, count)| **count == 0)
+      .map(|(name, _)| *name)
+      .collect::<BTreeSet<_>>();
+    let mut order = Vec::with_capacity(pending.len());
+
+    while let Some(name) = ready.pop_first() {
+      pending.shift_remove(name);
+      order.push(name);
+
+      for dependent in self.dependents(name) {
+        if let Some(count) = pending.get_mut(dependent) {
+          *count -= 1;
+          if *count == 0 {
+            ready.insert(dependent);
+          }
+        }
+      }
+    }
+
+    if !pending.is_empty() {
+      let cycle = self.cycles().into_iter().next().unwrap_or_default();
+      return Err(GraphError::DependencyCycle { cycle });
+    }
+
+    Ok(order)
+  }
+
+  /// Every dependency cycle, one per strongly connected component.
+  ///
+  /// Each cycle starts at its alphabetically first member and repeats it at
+  /// the end, e.g. `["a", "b", "a"]`.
+  pub fn cycles(&self) -> Vec<Vec<String>> {
+    let mut cycles = self
+      .strongly_connected_components()
+      .into_iter()
+      .filter_map(|component| self.cycle_in(&component))
+      .collect::<Vec<_>>();
+    cycles.sort();
+    cycles
+  }
+
+  /// Tarjan's algorithm.
+  fn strongly_connected_components(&self) -> Vec<BTreeSet<&str>> {
+    struct State<'a> {
+      index: usize,
+      indices: IndexMap<&'a str, (usize, usize)>,
+      stack: Vec<&'a str>,
+      components: Vec<BTreeSet<&'a str>>,
+    }
+
+    fn visit<'a>(graph: &'a DependencyGraph, name: &'a str, state: &mut State<'a>) {
+      state.indices.insert(name, (state.index, state.index));
+      state.index += 1;
+      state.stack.push(name);
+
+      for dependency in graph.dependencies(name) {
+        match state.indices.get(dependency).copied() {
+          None => {
+            visit(graph, dependency, state);
+            let low = state.indices[dependency].1;
+            let entry = state.indices.get_mut(name).unwrap();
+            entry.1 = entry.1.min(low);
+          }
+          Some((index, _)) if state.stack.contains(&dependency) => {
+            let entry = state.indices.get_mut(name).unwrap();
+            entry.1 = entry.1.min(index);
+          }
+          Some(_) => {}
+        }
+      }
+
+      let (index, low) = state.indices[name];
+      if index == low {
+        let mut component = BTreeSet::new();
+        while let Some(member) = state.stack.pop() {
+          component.insert(member);
+          if member == name {
+            break;
+          }
+        }
+        state.components.push(component);
+      }
+    }
+
+    let mut state = State {
+      index: 0,
+      indices: IndexMap::new(),
+      stack: Vec::new(),
+      components: Vec::new(),
+    };
+
+    for name in self.packages() {
+      if !state.indices.contains_key(name) {
+        visit(self, name, &mut state);
+      }
+    }
+
+    state.components
+  }
+
+  /// A concrete cycle through a component, or `None` for a single package
+  /// that does not depend on itself.
+  fn cycle_in(&self, component: &BTreeSet<&str>) -> Option<Vec<String>> {
+    let start = *component.first()?;
+    if component.len() == 1 && !self.dependencies(start).contains(&start) {
+      return None;
+    }
+
+    // Breadth-first search back to `start`, staying inside the component.
+    let mut parents = IndexMap::<&str, &str>::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(name) = queue.pop_front() {
+      for dependency in self.dependencies(name) {
+        if !component.contains(dependency) {
+          continue;
+        }
+        if dependency == start {
+          let mut cycle = vec![start.to_string()];
+          let mut current = name;
+          while current != start {
+            cycle.push(current.to_string());
+            current = parents[current];
+          }
+          cycle.push(start.to_string());
+          cycle.reverse();
+          return Some(cycle);
+        }
+        if !parents.contains_key(dependency) {
+          parents.insert(dependency, name);
+          queue.push_back(dependency);
+        }
+      }
+    }
+
+    None
+  }
+
+  /// The changed members plus everything that depends on them, directly or
+  /// transitively, in build order when the graph is acyclic and by name
+  /// otherwise. Unknown names are ignored.
+  pub fn affected<'a>(&self, changed: impl IntoIterator<Item = &'a str>) -> Vec<&str> {
+    let mut affected = BTreeSet::new();
+    let mut queue = changed
+      .into_iter()
+      .filter_map(|name| {
+        self
+          .packages
+          .get_key_value(name)
+          .map(|(name, _)| name.as_str())
+      })
+      .collect::<VecDeque<_>>();
+
+    while let Some(name) = queue.pop_front() {
+      if affected.insert(name) {
+        queue.extend(self.dependents(name));
+      }
+    }
+
+    match self.topological_order() {
+      Ok(order) => order
+        .into_iter()
+        .filter(|name| affected.contains(name))
+        .collect(),
+      Err(_) => affected.into_iter().collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{DependencyGraph, DependencyKind};
+  use crate::err::GraphError;
+  use crate::{PackageJsonParser, Workspace};
+
+  fn packages(jsones: &[&str]) -> Vec<PackageJsonParser> {
+    jsones
+      .iter()
+      .map(|json| PackageJsonParser::parse_str(json).unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn should_order_packages_after_their_dependencies() {
+    let packages = packages(&[
+      r#"{"name": "app", "dependencies": {"ui": "workspace:*", "react": "^18.0.0"}}"#,
+      r#"{"name": "ui", "version": "1.2.0", "peerDependencies": {"core": "^1.0.0"}}"#,
+      r#"{"name": "core", "version": "1.0.0"}"#,
+      r#"{"name": "docs", "devDependencies": {"ui": "file:../ui"}}"#,
+    ]);
+    let graph = DependencyGraph::new(&packages);
+
+    assert_eq!(graph.edges().len(), 3);
+    assert_eq!(graph.dependencies("app"), ["ui"]);
+    assert_eq!(graph.dependents("ui"), ["app", "docs"]);
+    assert_eq!(
+      graph.topological_order().unwrap(),
+      ["core", "ui", "app", "docs"]
+    );
+  }
+
+  #[test]
+  fn should_skip_ranges_the_member_does_not_satisfy() {
+    let packages = packages(&[
+      r#"{"name": "app", "dependencies": {"core": "^2.0.0", "util": "npm:helpers@^1.0.0"}}"#,
+      r#"{"name": "core", "version": "1.0.0"}"#,
+      r#"{"name": "helpers", "version": "1.4.0"}"#,
+    ]);
+    let graph = DependencyGraph::new(&packages);
+
+    assert_eq!(graph.dependencies("app"), ["helpers"]);
+  }
+
+  #[test]
+  fn should_report_dependency_cycles() {
+    let packages = packages(&[
+      r#"{"name": "a", "dependencies": {"b": "workspace:*"}}"#,
+      r#"{"name": "b", "dependencies": {"c": "workspace:*"}}"#,
+      r#"{"name": "c", "devDependencies": {"a": "workspace:*"}}"#,
+      r#"{"name": "d", "dependencies": {"d": "workspace:*"}}"#,
+    ]);
+    let graph = DependencyGraph::new(&packages);
+
+    assert_eq!(graph.cycles(), [vec!["a", "b", "c", "a"], vec!["d", "d"]]);
+    assert_eq!(
+      graph.topological_order(),
+      Err(GraphError::DependencyCycle {
+        cycle: vec!["a".into(), "b".into(), "c".into(), "a".into()]
+      })
+    );
+
+    let graph = DependencyGraph::with_kinds(&packages[..3], &[DependencyKind::Dependencies]);
+    assert_eq!(graph.topological_order().unwrap(), ["c", "b", "a"]);
+  }
+
+  #[test]
+  fn should_find_affected_packages() {
+    let packages = packages(&[
+      r#"{"name": "app", "dependencies": {"ui": "workspace:*"}}"#,
+      r#"{"name": "ui", "dependencies": {"core": "workspace:*"}}"#,
+      r#"{"name": "core"}"#,
+      r#"{"name": "cli", "dependencies": {"core": "workspace:*"}}"#,
+      r#"{"name": "docs"}"#,
+    ]);
+    let graph = DependencyGraph::new(&packages);
+
+    assert_eq!(graph.affected(["ui"]), ["ui", "app"]);
+    assert_eq!(
+      graph.affected(["core", "missing"]),
+      ["core", "cli", "ui", "app"]
+    );
+    assert!(graph.affected(["docs"]) == ["docs"]);
+  }
+
+  #[test]
+  fn should_build_graph_from_workspace() {
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/workspace-npm");
+    let workspace = Workspace::discover(root).unwrap();
+    let graph = DependencyGraph::from_workspace(&workspace);
+
+    assert_eq!(
+      graph.topological_order().unwrap(),
+      ["@demo/b", "@demo/a", "web"]
+    );
+  }
+}
@@ -2,26 +2,68 @@ pub use def::*;
 use miette::{MietteDiagnostic, Severity};
 use serde::de::DeserializeOwned;
 
-use crate::err::JsonParseError;
-use crate::ext::Validator;
-use jsonc_parser::{CollectOptions, ParseOptions, ast::ObjectProp, parse_to_ast};
+use crate::err::{JsonParseError, YamlParseError};
+use crate::ext::{SourceMap, Validator, locate_syntax_error, value_range};
+use crate::suppress::Suppression;
+use jsonc_parser::tokens::{Token, TokenAndRange};
+use jsonc_parser::{
+  CollectOptions, CommentCollectionStrategy, ParseOptions as JsoncParseOptions, ast::ObjectProp,
+  errors::ParseError as JsoncParseError, parse_to_ast,
+};
 pub use rustc_hash::FxHashMap;
 pub use serde::{Deserialize, Serialize};
 use serde_json::{Map as JsonMap, Value as JsonValue};
 use std::collections::HashMap;
 use std::io::Read;
+use std::ops::Range;
 use std::path::Path;
 use std::{fs::File, io::BufReader};
 
-pub use crate::err::ErrorKind;
+pub use crate::codes::DiagnosticCode;
+pub use crate::editor::PackageJsonEditor;
+pub use crate::err::{
+  ConfigError, EditError, ErrorKind, GraphError, ResolveError, ValidationReport, WorkspaceError,
+};
+pub use crate::graph::{DependencyEdge, DependencyGraph, DependencyKind};
+pub use crate::lint::{
+  DiagnosticOutput, Fix, LINT_RULES, LineColumn, LintContext, LintDiagnostic, LintReport, LintRule,
+  OUTPUT_VERSION, OutputRange, Rule, RuleConfig, RuleLevel, TextEdit, apply_fixes, sarif_log,
+};
+pub use crate::lockfile::{LegacyDependency, LockedDependency, LockedPackage, PackageLock};
+pub use crate::options::ParseOptions;
+pub use crate::semver::{Comparator, Operator, Prerelease, SemVersion, VersionRange};
+pub use crate::spdx::{LICENSE_LIST_VERSION, SpdxError, SpdxExpression, SpdxLicense};
+pub use crate::workspace::{PackageFunding, Workspace, WorkspaceMember};
 pub use miette::{LabeledSpan, NamedSource, Result, SourceSpan};
 
+pub mod codes;
 mod def;
+mod editor;
 mod err;
 mod ext;
+mod graph;
+mod lint;
+mod lockfile;
+#[cfg(feature = "lsp")]
+pub mod lsp;
+mod options;
+mod resolve;
+mod semver;
+mod spdx;
+mod suppress;
+mod workspace;
+mod yaml;
 
 type FieldResult<T> = std::result::Result<Option<T>, FieldError>;
 
+/// Fields whose keys are the direct dependencies of a package.
+const DIRECT_DEPENDENCY_FIELDS: &[&str] = &[
+  "dependencies",
+  "devDependencies",
+  "optionalDependencies",
+  "peerDependencies",
+];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum FieldErrorKind {
   Deserialize,
@@ -33,14 +75,26 @@ struct FieldError {
   field: &'static str,
   kind: FieldErrorKind,
   message: String,
+  code: Option<String>,
+  help: Option<String>,
+  labels: Vec<LabeledSpan>,
 }
 
 impl FieldError {
-  fn deserialize(field: &'static str, error: serde_json::Error) -> Self {
+  fn deserialize(
+    field: &'static str,
+    error: serde_json::Error,
+    range: Option<Range<usize>>,
+  ) -> Self {
     Self {
       field,
       kind: FieldErrorKind::Deserialize,
       message: error.to_string(),
+      code: Some(codes::INVALID_TYPE.code.to_string()),
+      help: None,
+      labels: range
+        .map(|range| vec![LabeledSpan::at(range, "here")])
+        .unwrap_or_default(),
     }
   }
 
@@ -49,6 +103,12 @@ impl FieldError {
       field,
       kind: FieldErrorKind::Validation,
       message: error.to_string(),
+      code: error.code().map(|code| code.to_string()),
+      help: error.help().map(|help| help.to_string()),
+      labels: error
+        .labels()
+        .map(|labels| labels.collect())
+        .unwrap_or_default(),
     }
   }
 
@@ -68,6 +128,26 @@ impl FieldError {
       }
     }
   }
+
+  fn to_diagnostic(&self) -> MietteDiagnostic {
+    let mut diagnostic = MietteDiagnostic::new(self.message())
+      .with_severity(Severity::Error)
+      .with_labels(self.labels.clone());
+
+    if let Some(code) = self.code.as_ref() {
+      diagnostic = diagnostic.with_code(code);
+    }
+
+    if let Some(code) = self.code.as_deref().and_then(codes::lookup) {
+      diagnostic = diagnostic.with_url(code.url());
+    }
+
+    if let Some(help) = self.help.as_ref() {
+      diagnostic = diagnostic.with_help(help);
+    }
+
+    diagnostic
+  }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -88,8 +168,12 @@ pub struct PackageJsonParser {
   #[serde(skip)]
   bugs: FieldResult<Bugs>,
   #[serde(skip)]
+  funding: FieldResult<Funding>,
+  #[serde(skip)]
   license: FieldResult<License>,
   #[serde(skip)]
+  licenses: FieldResult<Licenses>,
+  #[serde(skip)]
   author: FieldResult<Person>,
   #[serde(skip)]
   contributors: FieldResult<Contributors>,
@@ -141,11 +225,34 @@ pub struct PackageJsonParser {
   optional_dependencies: FieldResult<OptionalDependencies>,
   #[serde(skip)]
   peer_dependencies: FieldResult<PeerDependencies>,
+  #[serde(skip)]
+  peer_dependencies_meta: FieldResult<PeerDependenciesMeta>,
+  #[serde(skip)]
+  dependencies_meta: FieldResult<DependenciesMeta>,
+  #[serde(skip)]
+  bundle_dependencies: FieldResult<BundleDependencies>,
+  #[serde(skip)]
+  exports: FieldResult<Exports>,
+  #[serde(skip)]
+  imports: FieldResult<Imports>,
+  #[serde(skip)]
+  overrides: FieldResult<Overrides>,
+  #[serde(skip)]
+  resolutions: FieldResult<Resolutions>,
+  #[serde(skip)]
+  workspaces: FieldResult<Workspaces>,
 
   #[serde(skip)]
   __raw_source: Option<String>,
   #[serde(skip)]
   __raw_path: Option<String>,
+  #[serde(skip)]
+  __warnings: Vec<MietteDiagnostic>,
+  #[serde(skip)]
+  __suppressions: Vec<Suppression>,
+  /// Maps spans of the generated JSON back onto a YAML source.
+  #[serde(skip)]
+  __source_map: Option<SourceMap>,
 }
 
 impl<'de> Deserialize<'de> for PackageJsonParser {
@@ -160,7 +267,7 @@ impl<'de> Deserialize<'de> for PackageJsonParser {
       ));
     };
 
-    Ok(Self::new(raw_fields.clone(), None, None, None))
+    Ok(Self::new(raw_fields.clone(), None, None, None, None))
   }
 }
 
@@ -178,6 +285,7 @@ impl PackageJsonParser {
     raw_source: Option<String>,
     raw_path: Option<String>,
     root: Option<&jsonc_parser::ast::Object>,
+    source_map: Option<SourceMap>,
   ) -> Self {
     let mut parser = Self {
       raw_fields,
@@ -187,7 +295,9 @@ impl PackageJsonParser {
       keywords: Ok(None),
       homepage: Ok(None),
       bugs: Ok(None),
+      funding: Ok(None),
       license: Ok(None),
+      licenses: Ok(None),
       author: Ok(None),
       contributors: Ok(None),
       maintainers: Ok(None),
@@ -214,11 +324,32 @@ impl PackageJsonParser {
       dev_dependencies: Ok(None),
       optional_dependencies: Ok(None),
       peer_dependencies: Ok(None),
+      peer_dependencies_meta: Ok(None),
+      dependencies_meta: Ok(None),
+      bundle_dependencies: Ok(None),
+      exports: Ok(None),
+      imports: Ok(None),
+      overrides: Ok(None),
+      resolutions: Ok(None),
+      workspaces: Ok(None),
       __raw_source: raw_source,
       __raw_path: raw_path,
+      __warnings: Vec::new(),
+      __suppressions: Vec::new(),
+      __source_map: source_map,
     };
 
     parser.init_field_states(root);
+    parser.__suppressions = suppress::key_suppressions(&parser.raw_fields, root)
+      .into_iter()
+      .filter_map(|suppression| {
+        let range = match suppression.range() {
+          Some(range) => Some(parser.map_range(range.clone())?),
+          None => None,
+        };
+        Some(suppression.with_range(range))
+      })
+      .collect();
     parser
   }
 
@@ -232,7 +363,10 @@ impl PackageJsonParser {
     self.homepage =
       self.decode_field::<HomePage>("homepage", root.and_then(|obj| obj.get("homepage")));
     self.bugs = self.decode_field::<Bugs>("bugs", root.and_then(|obj| obj.get("bugs")));
+    self.funding = self.decode_field::<Funding>("funding", root.and_then(|obj| obj.get("funding")));
     self.license = self.decode_field::<License>("license", root.and_then(|obj| obj.get("license")));
+    self.licenses =
+      self.decode_field::<Licenses>("licenses", root.and_then(|obj| obj.get("licenses")));
     self.author = self.decode_field::<Person>("author", root.and_then(|obj| obj.get("author")));
     self.contributors = self
       .decode_field::<Contributors>("contributors", root.and_then(|obj| obj.get("contributors")));
@@ -280,6 +414,53 @@ impl PackageJsonParser {
       "peerDependencies",
       root.and_then(|obj| obj.get("peerDependencies")),
     );
+    self.peer_dependencies_meta = self.decode_field::<PeerDependenciesMeta>(
+      "peerDependenciesMeta",
+      root.and_then(|obj| obj.get("peerDependenciesMeta")),
+    );
+    self.dependencies_meta = self.decode_field::<DependenciesMeta>(
+      "dependenciesMeta",
+      root.and_then(|obj| obj.get("dependenciesMeta")),
+    );
+    self.bundle_dependencies = match self.bundle_dependencies_key() {
+      Some(key) => self.decode_field::<BundleDependencies>(key, root.and_then(|obj| obj.get(key))),
+      None => Ok(None),
+    };
+    self.exports = self.decode_field::<Exports>("exports", root.and_then(|obj| obj.get("exports")));
+    self.imports = self.decode_field::<Imports>("imports", root.and_then(|obj| obj.get("imports")));
+    self.overrides = self.decode_overrides(root.and_then(|obj| obj.get("overrides")));
+    self.resolutions =
+      self.decode_field::<Resolutions>("resolutions", root.and_then(|obj| obj.get("resolutions")));
+    self.workspaces =
+      self.decode_field::<Workspaces>("workspaces", root.and_then(|obj| obj.get("workspaces")));
+  }
+
+  /// The spelling of `bundleDependencies` in use; npm reads
+  /// `bundledDependencies` only when the other one is missing.
+  pub(crate) fn bundle_dependencies_key(&self) -> Option<&'static str> {
+    BUNDLE_DEPENDENCIES_KEYS
+      .iter()
+      .copied()
+      .find(|key| self.raw_fields.contains_key(*key))
+  }
+
+  /// `overrides` is validated on its own first; its `$name` references can
+  /// only be checked against the direct dependencies afterwards.
+  fn decode_overrides(&self, prop: Option<&ObjectProp>) -> FieldResult<Overrides> {
+    let Some(overrides) = self.decode_field::<Overrides>("overrides", prop)? else {
+      return Ok(None);
+    };
+
+    let direct = DIRECT_DEPENDENCY_FIELDS
+      .iter()
+      .filter_map(|field| self.raw_fields.get(*field)?.as_object())
+      .flat_map(|dependencies| dependencies.keys().map(String::as_str))
+      .collect::<Vec<_>>();
+    overrides
+      .validate_references(&direct, prop)
+      .map_err(|error| self.map_labels(FieldError::validation("overrides", error)))?;
+
+    Ok(Some(overrides))
   }
 
   fn decode_field<T>(&self, json_key: &'static str, prop: Option<&ObjectProp>) -> FieldResult<T>
@@ -290,20 +471,86 @@ impl PackageJsonParser {
       return Ok(None);
     };
 
-    let parsed = serde_json::from_value::<T>(raw_value.clone())
-      .map_err(|error| FieldError::deserialize(json_key, error))?;
+    let parsed = serde_json::from_value::<T>(raw_value.clone()).map_err(|error| {
+      self.map_labels(FieldError::deserialize(
+        json_key,
+        error,
+        value_range(prop, &[]),
+      ))
+    })?;
 
     parsed
       .validate(prop)
-      .map_err(|error| FieldError::validation(json_key, error))?;
+      .map_err(|error| self.map_labels(FieldError::validation(json_key, error)))?;
 
     Ok(Some(parsed))
   }
 
-  fn field_error_to_report(&self, error: &FieldError) -> miette::Report {
-    let diagnostic = MietteDiagnostic::new(error.message()).with_severity(Severity::Error);
-    let report = miette::miette!(diagnostic);
+  /// Moves labels from the generated JSON onto the original source, for
+  /// manifests that were not written as JSON.
+  fn map_labels(&self, mut error: FieldError) -> FieldError {
+    error.labels = self.map_spans(error.labels);
+    error
+  }
+
+  fn map_spans(&self, labels: Vec<LabeledSpan>) -> Vec<LabeledSpan> {
+    let Some(source_map) = self.__source_map.as_ref() else {
+      return labels;
+    };
+
+    labels
+      .into_iter()
+      .filter_map(|label| {
+        let range = label.offset()..label.offset() + label.len();
+        let range = source_map.translate(range)?;
+        let label = match label.label() {
+          Some(text) => LabeledSpan::at(range, text),
+          None => LabeledSpan::underline(range),
+        };
+        Some(label)
+      })
+      .collect()
+  }
+
+  fn map_range(&self, range: Range<usize>) -> Option<Range<usize>> {
+    match self.__source_map.as_ref() {
+      Some(source_map) => source_map.translate(range),
+      None => Some(range),
+    }
+  }
 
+  /// Whether an inline suppression covers a diagnostic with `code` labeled
+  /// at `labels`.
+  fn is_suppressed(&self, code: Option<&str>, labels: &[LabeledSpan]) -> bool {
+    self
+      .__suppressions
+      .iter()
+      .any(|suppression| suppression.matches(code, labels))
+  }
+
+  /// Maps a range that replaces a whole value, for fixes. Unlike labels,
+  /// ranges inside or between values have no exact counterpart in YAML.
+  fn map_node_range(&self, range: Range<usize>) -> Option<Range<usize>> {
+    match self.__source_map.as_ref() {
+      Some(source_map) => source_map.translate_node(range),
+      None => Some(range),
+    }
+  }
+
+  /// The JSON text spans are computed against: the source itself, or a JSON
+  /// rendering of it for YAML manifests.
+  fn span_source(&self) -> Option<String> {
+    if self.__source_map.is_some() {
+      return Some(Self::generated_json(&self.raw_fields));
+    }
+    self.__raw_source.clone()
+  }
+
+  fn generated_json(raw_fields: &JsonMap<String, JsonValue>) -> String {
+    format!("{:#}", JsonValue::Object(raw_fields.clone()))
+  }
+
+  fn with_raw_source(&self, report: miette::Report) -> miette::Report {
     let Some(source) = self.__raw_source.as_ref() else {
       return report;
     };
@@ -315,11 +562,8 @@ impl PackageJsonParser {
     report.with_source_code(source.clone())
   }
 
-  fn ensure_field_ok<T>(&self, state: &FieldResult<T>) -> miette::Result<()> {
-    if let Err(error) = state {
-      return Err(self.field_error_to_report(error));
-    }
-    Ok(())
+  fn field_error_to_report(&self, error: &FieldError) -> miette::Report {
+    self.with_raw_source(miette::miette!(error.to_diagnostic()))
   }
 
   fn field_result_to_ref<'a, T>(&'a self, state: &'a FieldResult<T>) -> Result<Option<&'a T>> {
@@ -330,68 +574,112 @@ impl PackageJsonParser {
     }
   }
 
+  fn field_errors(&self) -> Vec<&FieldError> {
+    [
+      self.name.as_ref().err(),
+      self.version.as_ref().err(),
+      self.description.as_ref().err(),
+      self.keywords.as_ref().err(),
+      self.homepage.as_ref().err(),
+      self.bugs.as_ref().err(),
+      self.funding.as_ref().err(),
+      self.license.as_ref().err(),
+      self.licenses.as_ref().err(),
+      self.author.as_ref().err(),
+      self.contributors.as_ref().err(),
+      self.maintainers.as_ref().err(),
+      self.files.as_ref().err(),
+      self.main.as_ref().err(),
+      self.r#type.as_ref().err(),
+      self.types.as_ref().err(),
+      self.typings.as_ref().err(),
+      self.package_manager.as_ref().err(),
+      self.publish_config.as_ref().err(),
+      self.bin.as_ref().err(),
+      self.man.as_ref().err(),
+      self.directories.as_ref().err(),
+      self.repository.as_ref().err(),
+      self.module.as_ref().err(),
+      self.readme.as_ref().err(),
+      self.private.as_ref().err(),
+      self.engines.as_ref().err(),
+      self.engine_strict.as_ref().err(),
+      self.os.as_ref().err(),
+      self.cpu.as_ref().err(),
+      self.scripts.as_ref().err(),
+      self.dependencies.as_ref().err(),
+      self.dev_dependencies.as_ref().err(),
+      self.optional_dependencies.as_ref().err(),
+      self.peer_dependencies.as_ref().err(),
+      self.peer_dependencies_meta.as_ref().err(),
+      self.dependencies_meta.as_ref().err(),
+      self.bundle_dependencies.as_ref().err(),
+      self.exports.as_ref().err(),
+      self.imports.as_ref().err(),
+      self.overrides.as_ref().err(),
+      self.resolutions.as_ref().err(),
+      self.workspaces.as_ref().err(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|error| !self.is_suppressed(error.code.as_deref(), &error.labels))
+    .collect()
+  }
+
+  /// Validates every field and fails on the first broken one.
   pub fn validate(&self) -> miette::Result<()> {
-    self.ensure_field_ok(&self.name)?;
-    self.ensure_field_ok(&self.version)?;
-    self.ensure_field_ok(&self.description)?;
-    self.ensure_field_ok(&self.keywords)?;
-    self.ensure_field_ok(&self.homepage)?;
-    self.ensure_field_ok(&self.bugs)?;
-    self.ensure_field_ok(&self.license)?;
-    self.ensure_field_ok(&self.author)?;
-    self.ensure_field_ok(&self.contributors)?;
-    self.ensure_field_ok(&self.maintainers)?;
-    self.ensure_field_ok(&self.files)?;
-    self.ensure_field_ok(&self.main)?;
-    self.ensure_field_ok(&self.r#type)?;
-    self.ensure_field_ok(&self.types)?;
-    self.ensure_field_ok(&self.typings)?;
-    self.ensure_field_ok(&self.package_manager)?;
-    self.ensure_field_ok(&self.publish_config)?;
-    self.ensure_field_ok(&self.bin)?;
-    self.ensure_field_ok(&self.man)?;
-    self.ensure_field_ok(&self.directories)?;
-    self.ensure_field_ok(&self.repository)?;
-    self.ensure_field_ok(&self.module)?;
-    self.ensure_field_ok(&self.readme)?;
-    self.ensure_field_ok(&self.private)?;
-    self.ensure_field_ok(&self.engines)?;
-    self.ensure_field_ok(&self.engine_strict)?;
-    self.ensure_field_ok(&self.os)?;
-    self.ensure_field_ok(&self.cpu)?;
-    self.ensure_field_ok(&self.scripts)?;
-    self.ensure_field_ok(&self.dependencies)?;
-    self.ensure_field_ok(&self.dev_dependencies)?;
-    self.ensure_field_ok(&self.optional_dependencies)?;
-    self.ensure_field_ok(&self.peer_dependencies)?;
+    if let Some(error) = self.field_errors().first() {
+      return Err(self.field_error_to_report(error));
+    }
 
     Ok(())
   }
 
-  fn build_parse_error<S: miette::SourceCode + std::fmt::Debug + 'static>(
-    src: S,
+  /// Validates every field and reports all broken ones at once.
+  ///
+  /// The returned report is a [`ValidationReport`] whose `related()`
+  /// diagnostics carry one entry per failing field, each labeled with the
+  /// span of the offending value.
+  pub fn validate_all(&self) -> miette::Result<()> {
+    let errors = self.field_errors();
+    if errors.is_empty() {
+      return Ok(());
+    }
+
+    let report = ValidationReport {
+      related: errors.iter().map(|error| error.to_diagnostic()).collect(),
+    };
+
+    Err(self.with_raw_source(miette::Report::new(report)))
+  }
+
+  /// Builds a syntax error labeled at the offending token, with the file
+  /// name as source when there is one.
+  fn build_parse_error(
     content: &str,
-    error: serde_json::Error,
+    path: Option<&str>,
+    jsonc_error: Option<&JsoncParseError>,
+    error: Option<serde_json::Error>,
   ) -> miette::Report {
-    let line = error.line();
-    let offset = content
-      .lines()
-      .take(line.saturating_sub(1))
-      .map(|l| l.len() + 1)
-      .sum::<usize>();
-    let len = content
-      .lines()
-      .nth(line.saturating_sub(1))
-      .unwrap_or("")
-      .len();
-
-    miette::miette!(JsonParseError {
-      src,
-      primary_span: Some(SourceSpan::from(0..content.len())),
-      other_spans: vec![LabeledSpan::new(Some("here".to_string()), offset, len)],
-      source: Some(error),
-      advice: Some("Please check the JSON syntax".to_string()),
-    })
+    let (message, range) = locate_syntax_error(content, jsonc_error, error.as_ref());
+    let advice = Some("Please check the JSON syntax".to_string());
+
+    match path {
+      Some(path) => miette::Report::new(JsonParseError {
+        src: NamedSource::new(path, content.to_string()),
+        message,
+        span: range.into(),
+        advice,
+        source: error,
+      }),
+      None => miette::Report::new(JsonParseError {
+        src: content.to_string(),
+        message,
+        span: range.into(),
+        advice,
+        source: error,
+      }),
+    }
   }
 
   fn root_object_error(content: &str) -> miette::Report {
@@ -403,75 +691,269 @@ impl PackageJsonParser {
   }
 
   pub fn parse_str(content: &str) -> Result<Self> {
-    let parsed_value: JsonValue = serde_json::from_str(content)
-      .map_err(|error| Self::build_parse_error(content.to_string(), content, error))?;
+    Self::parse_str_with_options(content, ParseOptions::default())
+  }
+
+  pub fn parse<P: AsRef<Path>>(path: P) -> Result<Self> {
+    Self::parse_with_options(path, ParseOptions::default())
+  }
 
-    let Some(raw_fields) = parsed_value.as_object() else {
-      return Err(Self::root_object_error(content));
+  pub fn parse_str_with_options(content: &str, options: ParseOptions) -> Result<Self> {
+    Self::from_content(content.to_string(), None, options)
+  }
+
+  pub fn parse_with_options<P: AsRef<Path>>(path: P, options: ParseOptions) -> Result<Self> {
+    let (content, path) = Self::read_file(path.as_ref())?;
+    Self::from_content(content, Some(path), options)
+  }
+
+  fn from_content(content: String, path: Option<String>, options: ParseOptions) -> Result<Self> {
+    let jsonc_options = JsoncParseOptions {
+      allow_comments: options.jsonc,
+      allow_loose_object_property_names: false,
+      allow_trailing_commas: options.jsonc,
+    };
+    let collect_options = CollectOptions {
+      comments: if options.jsonc {
+        CommentCollectionStrategy::AsTokens
+      } else {
+        CommentCollectionStrategy::Off
+      },
+      tokens: options.jsonc,
     };
 
-    let ast = parse_to_ast(
-      content,
-      &CollectOptions::default(),
-      &ParseOptions::default(),
-    )
-    .ok();
+    let ast = parse_to_ast(&content, &collect_options, &jsonc_options);
+    let raw_value = if options.jsonc {
+      let ast = ast
+        .as_ref()
+        .map_err(|error| Self::build_parse_error(&content, path.as_deref(), Some(error), None))?;
+      ast.value.clone().map(JsonValue::from)
+    } else {
+      let value = serde_json::from_str::<JsonValue>(&content).map_err(|error| {
+        Self::build_parse_error(&content, path.as_deref(), ast.as_ref().err(), Some(error))
+      })?;
+      Some(value)
+    };
+
+    let Some(JsonValue::Object(raw_fields)) = raw_value else {
+      return Err(Self::root_object_error(&content));
+    };
+
+    let ast = ast.ok();
     let root = ast
       .as_ref()
       .and_then(|result| result.value.as_ref())
       .and_then(|value| value.as_object());
 
-    Ok(Self::new(
-      raw_fields.clone(),
-      Some(content.to_string()),
-      None,
-      root,
-    ))
+    let tokens = ast.as_ref().and_then(|result| result.tokens.as_deref());
+
+    let mut parser = Self::new(raw_fields, Some(content.clone()), path.clone(), root, None);
+    if let Some(tokens) = tokens {
+      if options.strict {
+        parser.__warnings = Self::jsonc_warnings(tokens);
+      }
+      parser
+        .__suppressions
+        .extend(suppress::comment_suppressions(&content, tokens, root));
+    }
+    Ok(parser)
   }
 
-  pub fn parse<P: AsRef<Path>>(path: P) -> Result<Self> {
-    let file = File::open(path.as_ref()).map_err(ErrorKind::IoError)?;
+  /// Parses a package.json5 manifest.
+  ///
+  /// Comments, trailing commas, single-quoted strings and unquoted keys are
+  /// accepted. Hexadecimal numbers, `Infinity` and `NaN` are not.
+  pub fn parse_json5_str(content: &str) -> Result<Self> {
+    Self::from_json5(content.to_string(), None)
+  }
+
+  pub fn parse_json5<P: AsRef<Path>>(path: P) -> Result<Self> {
+    let (content, path) = Self::read_file(path.as_ref())?;
+    Self::from_json5(content, Some(path))
+  }
+
+  /// Parses a package.yaml manifest, as read by pnpm.
+  ///
+  /// Unquoted scalars follow the YAML core schema, so `version: 1.0` is a
+  /// number and fails validation just like it would in JSON. Diagnostics
+  /// point into the YAML source.
+  pub fn parse_yaml_str(content: &str) -> Result<Self> {
+    Self::from_yaml(content.to_string(), None)
+  }
+
+  pub fn parse_yaml<P: AsRef<Path>>(path: P) -> Result<Self> {
+    let (content, path) = Self::read_file(path.as_ref())?;
+    Self::from_yaml(content, Some(path))
+  }
+
+  /// Parses the manifest of the package in `dir`, trying `package.json`,
+  /// `package.json5` and `package.yaml` in that order.
+  pub fn parse_manifest<P: AsRef<Path>>(dir: P) -> Result<Self> {
+    let dir = dir.as_ref();
+
+    let json = dir.join("package.json");
+    if json.is_file() {
+      return Self::parse(json);
+    }
+    let json5 = dir.join("package.json5");
+    if json5.is_file() {
+      return Self::parse_json5(json5);
+    }
+    let yaml = dir.join("package.yaml");
+    if yaml.is_file() {
+      return Self::parse_yaml(yaml);
+    }
+
+    Err(
+      ErrorKind::ManifestNotFound {
+        dir: dir.display().to_string(),
+      }
+      .into(),
+    )
+  }
+
+  fn read_file(path: &Path) -> Result<(String, String)> {
+    let file = File::open(path).map_err(ErrorKind::IoError)?;
     let mut reader = BufReader::new(file);
     let mut content = String::new();
     reader
       .read_to_string(&mut content)
       .map_err(ErrorKind::IoError)?;
 
-    let parsed_value: JsonValue = serde_json::from_str(&content).map_err(|error| {
-      let src = NamedSource::new(path.as_ref().to_string_lossy(), content.clone());
-      Self::build_parse_error(src, &content, error)
+    Ok((content, path.to_string_lossy().to_string()))
+  }
+
+  fn from_json5(content: String, path: Option<String>) -> Result<Self> {
+    let options = JsoncParseOptions {
+      allow_comments: true,
+      allow_loose_object_property_names: true,
+      allow_trailing_commas: true,
+    };
+    let collect_options = CollectOptions {
+      comments: CommentCollectionStrategy::AsTokens,
+      tokens: true,
+    };
+    let ast = parse_to_ast(&content, &collect_options, &options)
+      .map_err(|error| Self::build_parse_error(&content, path.as_deref(), Some(&error), None))?;
+
+    let Some(value @ jsonc_parser::ast::Value::Object(root)) = ast.value.as_ref() else {
+      return Err(Self::root_object_error(&content));
+    };
+    let JsonValue::Object(raw_fields) = JsonValue::from(value.clone()) else {
+      return Err(Self::root_object_error(&content));
+    };
+
+    let mut parser = Self::new(raw_fields, Some(content.clone()), path, Some(root), None);
+    if let Some(tokens) = ast.tokens.as_deref() {
+      parser
+        .__suppressions
+        .extend(suppress::comment_suppressions(&content, tokens, Some(root)));
+    }
+    Ok(parser)
+  }
+
+  fn from_yaml(content: String, path: Option<String>) -> Result<Self> {
+    let document = yaml::parse_yaml(&content).map_err(|error| {
+      let span = error.range.into();
+      match path.as_deref() {
+        Some(path) => miette::Report::new(YamlParseError {
+          src: NamedSource::new(path, content.clone()),
+          message: error.message,
+          span,
+        }),
+        None => miette::Report::new(YamlParseError {
+          src: content.clone(),
+          message: error.message,
+          span,
+        }),
+      }
     })?;
 
-    let Some(raw_fields) = parsed_value.as_object() else {
+    let JsonValue::Object(raw_fields) = document.value else {
       return Err(Self::root_object_error(&content));
     };
 
+    // Field validators work on a JSON AST, so they run against a JSON
+    // rendering of the document and their labels are mapped back afterwards.
+    let generated = Self::generated_json(&raw_fields);
     let ast = parse_to_ast(
-      &content,
+      &generated,
       &CollectOptions::default(),
-      &ParseOptions::default(),
+      &JsoncParseOptions::default(),
     )
     .ok();
-    let root = ast
-      .as_ref()
-      .and_then(|result| result.value.as_ref())
-      .and_then(|value| value.as_object());
+    let root = ast.as_ref().and_then(|result| result.value.as_ref());
+    let source_map = root.map(|root| SourceMap::new(&generated, root, &content, &document.spans));
 
     Ok(Self::new(
-      raw_fields.clone(),
+      raw_fields,
       Some(content.clone()),
-      Some(path.as_ref().to_string_lossy().to_string()),
-      root,
+      path,
+      root.and_then(|root| root.as_object()),
+      source_map,
     ))
   }
 
+  /// Flags every comment and trailing comma, which plain JSON rejects.
+  fn jsonc_warnings(tokens: &[TokenAndRange]) -> Vec<MietteDiagnostic> {
+    let warning = |message: &str, code: &DiagnosticCode, token: &TokenAndRange| {
+      MietteDiagnostic::new(message)
+        .with_code(code.code)
+        .with_url(code.url())
+        .with_label(LabeledSpan::at(token.range.start..token.range.end, "here"))
+        .with_help("npm reads package.json as plain JSON and will fail to parse it")
+        .with_severity(Severity::Warning)
+    };
+
+    let mut warnings = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+      match token.token {
+        Token::CommentLine(_) | Token::CommentBlock(_) => warnings.push(warning(
+          "Comments are not allowed in JSON",
+          &codes::SYNTAX_COMMENT,
+          token,
+        )),
+        Token::Comma => {
+          let next = tokens[index + 1..]
+            .iter()
+            .find(|next| !matches!(next.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+          if let Some(TokenAndRange {
+            token: Token::CloseBrace | Token::CloseBracket,
+            ..
+          }) = next
+          {
+            warnings.push(warning(
+              "Trailing commas are not allowed in JSON",
+              &codes::SYNTAX_TRAILING_COMMA,
+              token,
+            ));
+          }
+        }
+        _ => {}
+      }
+    }
+    warnings
+  }
+
+  /// Comments and trailing commas found when parsing with
+  /// [`ParseOptions::jsonc_strict`].
+  pub fn warnings(&self) -> Vec<miette::Report> {
+    self
+      .__warnings
+      .iter()
+      .map(|warning| self.with_raw_source(miette::miette!(warning.clone())))
+      .collect()
+  }
+
   define_getter!(name, name, Name);
   define_getter!(version, version, Version);
   define_getter!(description, description, Description);
   define_getter!(keywords, keywords, Keywords);
   define_getter!(homepage, homepage, HomePage);
   define_getter!(bugs, bugs, Bugs);
+  define_getter!(funding, funding, Funding);
   define_getter!(license, license, License);
+  define_getter!(licenses, licenses, Licenses);
   define_getter!(author, author, Person);
   define_getter!(contributors, contributors, Contributors);
   define_getter!(maintainers, maintainers, Maintainers);
@@ -502,6 +984,121 @@ impl PackageJsonParser {
     OptionalDependencies
   );
   define_getter!(peer_dependencies, peer_dependencies, PeerDependencies);
+  define_getter!(
+    peer_dependencies_meta,
+    peer_dependencies_meta,
+    PeerDependenciesMeta
+  );
+  define_getter!(dependencies_meta, dependencies_meta, DependenciesMeta);
+  define_getter!(bundle_dependencies, bundle_dependencies, BundleDependencies);
+  define_getter!(exports, exports, Exports);
+  define_getter!(imports, imports, Imports);
+  define_getter!(overrides, overrides, Overrides);
+  define_getter!(resolutions, resolutions, Resolutions);
+  define_getter!(workspaces, workspaces, Workspaces);
+
+  /// Resolves a specifier such as `pkg`, `pkg/sub/path` or `./sub/path`
+  /// through `exports` under the given conditions.
+  ///
+  /// Resolution reads the raw `exports` value, so it follows Node.js even
+  /// when the field fails this crate's validation.
+  pub fn resolve_export(
+    &self,
+    specifier: &str,
+    conditions: &[&str],
+  ) -> std::result::Result<String, ResolveError> {
+    let subpath = self.package_subpath(specifier)?;
+
+    let Some(raw_exports) = self.raw_fields.get("exports") else {
+      return Err(ResolveError::ExportsNotDefined);
+    };
+
+    let exports = serde_json::from_value::<Exports>(raw_exports.clone()).map_err(|error| {
+      ResolveError::InvalidPackageConfiguration {
+        reason: error.to_string(),
+      }
+    })?;
+
+    exports.resolve(&subpath, conditions)
+  }
+
+  /// Resolves a `#`-prefixed specifier through `imports` under the given
+  /// conditions.
+  pub fn resolve_import(
+    &self,
+    specifier: &str,
+    conditions: &[&str],
+  ) -> std::result::Result<String, ResolveError> {
+    let imports = self
+      .raw_fields
+      .get("imports")
+      .map(|value| serde_json::from_value::<Imports>(value.clone()))
+      .transpose()
+      .map_err(|error| ResolveError::InvalidPackageConfiguration {
+        reason: error.to_string(),
+      })?;
+
+    resolve::resolve_imports(imports.as_deref(), specifier, conditions)
+  }
+
+  /// Looks up a value by key path, e.g. `["scripts", "build"]`; array
+  /// elements are addressed by index.
+  ///
+  /// Fails with the same report as the field's getter when the top-level
+  /// field does not deserialize or validate.
+  pub fn get(&self, path: &[&str]) -> Result<Option<&JsonValue>> {
+    let Some((field, rest)) = path.split_first() else {
+      return Ok(None);
+    };
+
+    if let Some(error) = self
+      .field_errors()
+      .into_iter()
+      .find(|error| error.field == *field)
+    {
+      return Err(self.field_error_to_report(error));
+    }
+
+    let mut value = self.raw_field(field);
+    for key in rest {
+      value = match value {
+        Some(JsonValue::Object(map)) => map.get(*key),
+        Some(JsonValue::Array(items)) => {
+          key.parse::<usize>().ok().and_then(|index| items.get(index))
+        }
+        _ => None,
+      };
+    }
+
+    Ok(value)
+  }
+
+  /// A top-level field straight from the document, before typing.
+  pub(crate) fn raw_field(&self, key: &str) -> Option<&JsonValue> {
+    self.raw_fields.get(key)
+  }
+
+  pub(crate) fn raw_str(&self, key: &str) -> Option<&str> {
+    self.raw_field(key).and_then(JsonValue::as_str)
+  }
+
+  fn package_subpath(&self, specifier: &str) -> std::result::Result<String, ResolveError> {
+    if specifier == "." || specifier.starts_with("./") {
+      return Ok(specifier.to_string());
+    }
+
+    let name = self.raw_str("name");
+    let rest = name.and_then(|name| specifier.strip_prefix(name));
+
+    match rest {
+      Some("") => Ok(".".to_string()),
+      Some(rest) if rest.starts_with('/') => Ok(format!(".{rest}")),
+      _ => Err(ResolveError::InvalidModuleSpecifier {
+        specifier: specifier.to_string(),
+        reason: "specifier does not match the package name".to_string(),
+      }),
+    }
+  }
 
   pub fn bin_to_hash_map(&self) -> Result<HashMap<String, String>> {
     let Some(bin) = self.bin()? else {
@@ -543,7 +1140,8 @@ impl TryFrom<&str> for PackageJsonParser {
 
 #[cfg(test)]
 mod tests {
-  use crate::PackageJsonParser;
+  use crate::{PackageJsonParser, ParseOptions};
+  use std::path::Path;
 
   #[test]
   fn test_validate() {
@@ -558,6 +1156,106 @@ mod tests {
     assert!(res.is_err());
   }
 
+  #[test]
+  fn should_get_value_by_path() {
+    let parser = PackageJsonParser::parse_str(
+      r#"{"scripts":{"build":"tsc"},"files":["dist","src"],"version":"x"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      parser.get(&["scripts", "build"]).unwrap(),
+      Some(&serde_json::json!("tsc"))
+    );
+    assert_eq!(
+      parser.get(&["files", "1"]).unwrap(),
+      Some(&serde_json::json!("src"))
+    );
+    assert_eq!(parser.get(&["scripts", "test"]).unwrap(), None);
+    assert!(parser.get(&["version"]).is_err());
+  }
+
+  #[test]
+  fn should_label_syntax_error_at_offending_token() {
+    let content = "{\n  \"name\": \"pkg\",\n  \"private\": tru\n}";
+    let report = PackageJsonParser::parse_str(content).unwrap_err();
+
+    assert!(report.to_string().contains("unexpected word"));
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      "tru"
+    );
+  }
+
+  #[test]
+  fn should_name_source_file_in_syntax_error() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/invalid-syntax.json");
+    let report = PackageJsonParser::parse(path).unwrap_err();
+
+    assert!(report.to_string().contains("expected `,` or `}`"));
+    let label = report.labels().unwrap().next().unwrap();
+    let span = report
+      .source_code()
+      .unwrap()
+      .read_span(label.inner(), 0, 0)
+      .unwrap();
+    assert_eq!(span.name(), Some(path));
+    assert_eq!(span.line(), 3);
+  }
+
+  #[test]
+  fn should_parse_jsonc_when_enabled() {
+    let content = r#"{
+      // the package name
+      "name": "pkg",
+      "files": ["dist",],
+    }"#;
+
+    assert!(PackageJsonParser::parse_str(content).is_err());
+
+    let parser = PackageJsonParser::parse_str_with_options(content, ParseOptions::jsonc()).unwrap();
+    assert_eq!(parser.name().unwrap().unwrap().as_str(), "pkg");
+    assert!(parser.warnings().is_empty());
+    assert!(parser.validate().is_ok());
+  }
+
+  #[test]
+  fn should_warn_about_jsonc_syntax_in_strict_mode() {
+    let content = "{\n  /* note */ \"name\": \"pkg\",\n  \"files\": [\"dist\",],\n}";
+    let parser =
+      PackageJsonParser::parse_str_with_options(content, ParseOptions::jsonc_strict()).unwrap();
+
+    let warnings = parser.warnings();
+    let codes = warnings
+      .iter()
+      .map(|warning| warning.code().unwrap().to_string())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      codes,
+      [
+        "pkg::syntax::comment",
+        "pkg::syntax::trailing-comma",
+        "pkg::syntax::trailing-comma"
+      ]
+    );
+    assert_eq!(warnings[0].severity(), Some(miette::Severity::Warning));
+
+    let label = warnings[0].labels().unwrap().next().unwrap();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      "/* note */"
+    );
+  }
+
+  #[test]
+  fn should_report_jsonc_syntax_errors() {
+    let result = PackageJsonParser::parse_str_with_options(r#"{"name": }"#, ParseOptions::jsonc());
+    let report = result.unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(label.offset(), 9);
+  }
+
   #[test]
   fn should_defer_deserialize_error_until_field_access() {
     let parser = PackageJsonParser::parse_str(r#"{"packageManager":false}"#).unwrap();
@@ -583,6 +1281,32 @@ mod tests {
     assert!(parser.name().unwrap().is_some());
   }
 
+  #[test]
+  fn should_collect_every_field_error_in_validate_all() {
+    let parser = PackageJsonParser::parse_str(
+      r#"{"name":"Bad Name","version":"x","packageManager":false,"private":true}"#,
+    )
+    .unwrap();
+
+    let report = parser.validate_all().unwrap_err();
+    let related = report.related().unwrap().collect::<Vec<_>>();
+
+    assert_eq!(related.len(), 3);
+    assert!(
+      related
+        .iter()
+        .all(|diagnostic| diagnostic.labels().is_some())
+    );
+    assert!(parser.name().is_err());
+    assert!(parser.private().unwrap().is_some());
+  }
+
+  #[test]
+  fn should_pass_validate_all_when_fields_are_valid() {
+    let parser = PackageJsonParser::parse_str(r#"{"name":"pkg","version":"1.0.0"}"#).unwrap();
+    assert!(parser.validate_all().is_ok());
+  }
+
   #[test]
   fn should_return_none_for_missing_field() {
     let parser = PackageJsonParser::parse_str(r#"{}"#).unwrap();
@@ -598,4 +1322,84 @@ mod tests {
     assert_eq!(json_value["packageManager"], serde_json::json!(false));
     assert_eq!(json_value["name"], serde_json::json!("pkg"));
   }
+
+  fn label_text<'a>(content: &'a str, report: &miette::Report) -> &'a str {
+    let label = report.labels().unwrap().next().unwrap();
+    &content[label.offset()..label.offset() + label.len()]
+  }
+
+  #[test]
+  fn should_parse_yaml_manifest() {
+    let path = concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/fixtures/manifest-yaml/package.yaml"
+    );
+    let parser = PackageJsonParser::parse_yaml(path).unwrap();
+
+    assert_eq!(parser.name().unwrap().unwrap().as_str(), "yaml-pkg");
+    assert_eq!(
+      parser.get(&["dependencies", "react"]).unwrap(),
+      Some(&serde_json::json!("^18.2.0"))
+    );
+    assert!(parser.validate_all().is_ok());
+  }
+
+  #[test]
+  fn should_label_yaml_errors_in_yaml_source() {
+    let content = "name: pkg\nversion: 1.0\nlicense: MIT OR Nope\n";
+    let parser = PackageJsonParser::parse_yaml_str(content).unwrap();
+
+    let report = parser.version().unwrap_err();
+    assert_eq!(label_text(content, &report), "1.0");
+
+    let report = parser.license().unwrap_err();
+    assert_eq!(label_text(content, &report), "Nope");
+  }
+
+  #[test]
+  fn should_report_yaml_syntax_errors() {
+    let report = PackageJsonParser::parse_yaml_str("name: [pkg\n").unwrap_err();
+    assert!(report.to_string().starts_with("YAML parsing failed"));
+  }
+
+  #[test]
+  fn should_parse_json5_manifest() {
+    let path = concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/fixtures/manifest-json5/package.json5"
+    );
+    let parser = PackageJsonParser::parse_json5(path).unwrap();
+
+    assert_eq!(parser.name().unwrap().unwrap().as_str(), "json5-pkg");
+    assert_eq!(
+      parser.get(&["scripts", "build"]).unwrap(),
+      Some(&serde_json::json!("tsc"))
+    );
+  }
+
+  #[test]
+  fn should_label_json5_errors_in_json5_source() {
+    let content = "{ name: 'pkg', version: 'one' }";
+    let parser = PackageJsonParser::parse_json5_str(content).unwrap();
+
+    let report = parser.version().unwrap_err();
+    assert_eq!(label_text(content, &report), "'one'");
+  }
+
+  #[test]
+  fn should_pick_manifest_format_by_file_name() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+
+    let parser = PackageJsonParser::parse_manifest(fixtures.join("manifest-yaml")).unwrap();
+    assert_eq!(parser.name().unwrap().unwrap().as_str(), "yaml-pkg");
+
+    let parser = PackageJsonParser::parse_manifest(fixtures.join("manifest-json5")).unwrap();
+    assert_eq!(parser.name().unwrap().unwrap().as_str(), "json5-pkg");
+
+    let parser = PackageJsonParser::parse_manifest(fixtures.join("workspace-npm")).unwrap();
+    assert!(parser.name().is_ok());
+
+    let report = PackageJsonParser::parse_manifest(fixtures.join("missing")).unwrap_err();
+    assert!(report.to_string().contains("No package.json"));
+  }
 }
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use miette::Severity;
+use serde::Deserialize;
+
+use super::Rule;
+use crate::err::{ConfigError, ErrorKind};
+
+/// The level a rule is reported at, or `off` to disable it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+  Off,
+  Advice,
+  Warning,
+  Error,
+}
+
+impl RuleLevel {
+  /// `None` for [`RuleLevel::Off`].
+  pub fn severity(self) -> Option<Severity> {
+    match self {
+      RuleLevel::Off => None,
+      RuleLevel::Advice => Some(Severity::Advice),
+      RuleLevel::Warning => Some(Severity::Warning),
+      RuleLevel::Error => Some(Severity::Error),
+    }
+  }
+}
+
+/// Which rules [`PackageJsonParser::lint_with`](crate::PackageJsonParser::lint_with)
+/// runs and how severe their findings are.
+///
+/// ```toml
+/// requiredFields = ["license", "repository"]
+/// allowedLicenses = ["MIT", "Apache-2.0"]
+/// forbiddenDependencies = ["left-pad"]
+/// requirePrivate = true
+///
+/// [rules]
+/// "pkg::description::missing" = "off"
+/// "pkg::typings::prefer-types" = "error"
+/// ```
+///
+/// Levels in `rules` apply to any diagnostic code, including the codes of
+/// field validation errors.
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RuleConfig {
+  pub rules: IndexMap<String, RuleLevel>,
+  pub required_fields: Vec<String>,
+  pub allowed_licenses: Vec<String>,
+  pub forbidden_dependencies: Vec<String>,
+  pub require_private: bool,
+  #[serde(skip)]
+  custom_rules: Vec<Arc<dyn Rule>>,
+}
+
+impl fmt::Debug for RuleConfig {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let custom_rules = self
+      .custom_rules
+      .iter()
+      .map(|rule| rule.meta().code)
+      .collect::<Vec<_>>();
+
+    f.debug_struct("RuleConfig")
+      .field("rules", &self.rules)
+      .field("required_fields", &self.required_fields)
+      .field("allowed_licenses", &self.allowed_licenses)
+      .field("forbidden_dependencies", &self.forbidden_dependencies)
+      .field("require_private", &self.require_private)
+      .field("custom_rules", &custom_rules)
+      .finish()
+  }
+}
+
+impl RuleConfig {
+  pub fn from_json_str(content: &str) -> Result<Self, ConfigError> {
+    serde_json::from_str(content).map_err(|error| ConfigError::InvalidConfig {
+      reason: error.to_string(),
+    })
+  }
+
+  pub fn from_toml_str(content: &str) -> Result<Self, ConfigError> {
+    toml::from_str(content).map_err(|error| ConfigError::InvalidConfig {
+      reason: error.message().to_string(),
+    })
+  }
+
+  /// Loads a `.json` or `.toml` config file.
+  pub fn load<P: AsRef<Path>>(path: P) -> miette::Result<Self> {
+    let path = path.as_ref();
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    let parse = match extension {
+      Some("json") => Self::from_json_str,
+      Some("toml") => Self::from_toml_str,
+      _ => {
+        return Err(
+          ConfigError::UnsupportedFormat {
+            path: path.display().to_string(),
+          }
+          .into(),
+        );
+      }
+    };
+
+    let content = std::fs::read_to_string(path).map_err(ErrorKind::IoError)?;
+    Ok(parse(&content)?)
+  }
+
+  /// Sets the level of the rule or diagnostic `code`.
+  pub fn with_level(mut self, code: impl Into<String>, level: RuleLevel) -> Self {
+    self.rules.insert(code.into(), level);
+    self
+  }
+
+  /// Adds a custom rule, run after the built-in ones.
+  pub fn with_rule<R: Rule + 'static>(mut self, rule: R) -> Self {
+    self.custom_rules.push(Arc::new(rule));
+    self
+  }
+
+  pub(super) fn custom_rules(&self) -> impl Iterator<Item = &dyn Rule> {
+    self.custom_rules.iter().map(|rule| rule.as_ref())
+  }
+
+  /// The configured level for `code`, if any.
+  pub fn level(&self, code: &str) -> Option<RuleLevel> {
+    self.rules.get(code).copied()
+  }
+}
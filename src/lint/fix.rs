@@ -0,0 +1,152 @@
+use std::ops::Range;
+
+use cow_utils::CowUtils;
+use serde_json::{Value as JsonValue, json};
+
+use super::LintContext;
+use crate::Version;
+use crate::def::is_valid_package_name;
+use crate::ext::Validator;
+
+/// Replaces `range` of the source with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+  pub range: Range<usize>,
+  pub replacement: String,
+}
+
+/// A mechanical fix attached to a [`LintDiagnostic`](super::LintDiagnostic).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+  /// What the fix does, e.g. "Convert the name to lowercase".
+  pub message: String,
+  pub edits: Vec<TextEdit>,
+}
+
+impl Fix {
+  pub fn replace(
+    message: impl Into<String>,
+    range: Range<usize>,
+    replacement: impl Into<String>,
+  ) -> Self {
+    Self {
+      message: message.into(),
+      edits: vec![TextEdit {
+        range,
+        replacement: replacement.into(),
+      }],
+    }
+  }
+
+  /// Replaces the value at `range` with `value`, rendered on one line.
+  pub fn replace_value(message: impl Into<String>, range: Range<usize>, value: &JsonValue) -> Self {
+    Self::replace(message, range, inline_json(value))
+  }
+}
+
+/// Applies `fixes` to `source`, leaving everything outside their edits
+/// untouched.
+///
+/// Fixes are taken in order; one that overlaps an edit already taken is
+/// skipped, so applying the result again can pick it up.
+pub fn apply_fixes<'a>(source: &str, fixes: impl IntoIterator<Item = &'a Fix>) -> String {
+  let mut edits: Vec<&TextEdit> = Vec::new();
+  for fix in fixes {
+    let fits = fix.edits.iter().all(|edit| {
+      edit.range.end <= source.len()
+        && source.is_char_boundary(edit.range.start)
+        && source.is_char_boundary(edit.range.end)
+        && !edits
+          .iter()
+          .any(|taken| overlaps(&taken.range, &edit.range))
+    });
+    if fits {
+      edits.extend(&fix.edits);
+    }
+  }
+  edits.sort_by_key(|edit| edit.range.start);
+
+  let mut output = String::with_capacity(source.len());
+  let mut cursor = 0;
+  for edit in edits {
+    output.push_str(&source[cursor..edit.range.start]);
+    output.push_str(&edit.replacement);
+    cursor = edit.range.end;
+  }
+  output.push_str(&source[cursor..]);
+  output
+}
+
+fn overlaps(a: &Range<usize>, b: &Range<usize>) -> bool {
+  a.start < b.end && b.start < a.end || a == b
+}
+
+/// Renders objects as `{ "a": 1 }`, which reads naturally in both JSON and
+/// YAML flow style.
+fn inline_json(value: &JsonValue) -> String {
+  match value {
+    JsonValue::Object(map) if !map.is_empty() => {
+      let entries = map
+        .iter()
+        .map(|(key, value)| format!("{}: {}", JsonValue::from(key.as_str()), inline_json(value)))
+        .collect::<Vec<_>>();
+      format!("{{ {} }}", entries.join(", "))
+    }
+    JsonValue::Array(items) => {
+      let items = items.iter().map(inline_json).collect::<Vec<_>>();
+      format!("[{}]", items.join(", "))
+    }
+    value => value.to_string(),
+  }
+}
+
+/// The fix for a field whose validation failed, if one is obvious.
+pub(super) fn field_fix(context: &LintContext, field: &str) -> Option<Fix> {
+  let value = context.package().raw_str(field)?;
+  let range = context.range(&[field])?;
+
+  match field {
+    "name" => {
+      let lowercase = value.cow_to_ascii_lowercase();
+      (lowercase != value && is_valid_package_name(&lowercase))
+        .then(|| Fix::replace_value("Convert the name to lowercase", range, &json!(lowercase)))
+    }
+    "version" => {
+      let stripped = value
+        .trim()
+        .trim_start_matches(['v', 'V', '='])
+        .trim_start();
+      let valid = serde_json::from_value::<Version>(json!(stripped))
+        .is_ok_and(|version| version.validate(None).is_ok());
+      (stripped != value && valid)
+        .then(|| Fix::replace_value("Remove the version prefix", range, &json!(stripped)))
+    }
+    "repository" => {
+      let is_shorthand = lazy_regex::regex_is_match!(r"^[\w.-]+/[\w.-]+$", value);
+      is_shorthand.then(|| {
+        let repository = json!({
+          "type": "git",
+          "url": format!("git+https://github.com/{value}.git"),
+        });
+        Fix::replace_value("Expand the GitHub shorthand", range, &repository)
+      })
+    }
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Fix, apply_fixes};
+
+  #[test]
+  fn should_apply_non_overlapping_fixes() {
+    let source = r#"{ "a": "x", "b": "y" }"#;
+    let fixes = [
+      Fix::replace("b", 17..20, "\"Y\""),
+      Fix::replace("a", 7..10, "\"X\""),
+      Fix::replace("overlap", 8..9, "z"),
+    ];
+    assert_eq!(apply_fixes(source, &fixes), r#"{ "a": "X", "b": "Y" }"#);
+  }
+}
@@ -0,0 +1,638 @@
+use std::fmt;
+use std::ops::Range;
+
+use jsonc_parser::ast::Object;
+use jsonc_parser::{CollectOptions, ParseOptions as JsoncParseOptions, parse_to_ast};
+use miette::{Diagnostic, LabeledSpan, NamedSource, Severity};
+
+use crate::codes;
+use crate::ext::{array_child, node_range, value_range};
+use crate::{FieldError, PackageJsonParser};
+
+mod config;
+mod fix;
+mod output;
+mod rules;
+
+pub use config::{RuleConfig, RuleLevel};
+pub use fix::{Fix, TextEdit, apply_fixes};
+pub use output::{DiagnosticOutput, LineColumn, OUTPUT_VERSION, OutputRange, sarif_log};
+pub use rules::LINT_RULES;
+
+/// A check run by [`PackageJsonParser::lint`], with its default severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LintRule {
+  pub code: &'static str,
+  pub severity: Severity,
+  pub description: &'static str,
+}
+
+/// A check run by the lint pass.
+///
+/// Implement it for organization-specific policies and register it with
+/// [`RuleConfig::with_rule`]. Build findings with [`LintDiagnostic::new`] so
+/// that levels from the config apply to them.
+pub trait Rule {
+  fn meta(&self) -> LintRule;
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic>;
+}
+
+/// One finding of the lint pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+  pub code: String,
+  pub severity: Severity,
+  pub message: String,
+  pub help: Option<String>,
+  pub labels: Vec<LabeledSpan>,
+  pub fix: Option<Fix>,
+}
+
+impl LintDiagnostic {
+  pub fn new(rule: &LintRule, message: impl Into<String>) -> Self {
+    Self {
+      code: rule.code.to_string(),
+      severity: rule.severity,
+      message: message.into(),
+      help: None,
+      labels: Vec::new(),
+      fix: None,
+    }
+  }
+
+  pub fn with_help(mut self, help: impl Into<String>) -> Self {
+    self.help = Some(help.into());
+    self
+  }
+
+  pub fn with_label(mut self, label: Option<LabeledSpan>) -> Self {
+    self.labels.extend(label);
+    self
+  }
+
+  pub fn with_fix(mut self, fix: Option<Fix>) -> Self {
+    self.fix = fix;
+    self
+  }
+
+  fn from_field_error(error: &FieldError) -> Self {
+    Self {
+      code: error
+        .code
+        .clone()
+        .unwrap_or_else(|| codes::INVALID_FIELD.code.to_string()),
+      severity: Severity::Error,
+      message: error.message(),
+      help: error.help.clone(),
+      labels: error.labels.clone(),
+      fix: None,
+    }
+  }
+}
+
+impl fmt::Display for LintDiagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.message)
+  }
+}
+
+impl std::error::Error for LintDiagnostic {}
+
+impl Diagnostic for LintDiagnostic {
+  fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+    Some(Box::new(&self.code))
+  }
+
+  fn severity(&self) -> Option<Severity> {
+    Some(self.severity)
+  }
+
+  fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+    codes::lookup(&self.code).map(|code| Box::new(code.url()) as Box<dyn fmt::Display>)
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+    self
+      .help
+      .as_ref()
+      .map(|help| Box::new(help) as Box<dyn fmt::Display>)
+  }
+
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+    if self.labels.is_empty() {
+      return None;
+    }
+    Some(Box::new(self.labels.iter().cloned()))
+  }
+}
+
+/// Everything the lint pass found: field errors first, then the findings of
+/// the built-in rules in [`LINT_RULES`] order, then those of custom rules.
+#[derive(Debug, Clone)]
+pub struct LintReport {
+  diagnostics: Vec<LintDiagnostic>,
+  source: Option<String>,
+  path: Option<String>,
+}
+
+impl LintReport {
+  pub fn diagnostics(&self) -> &[LintDiagnostic] {
+    &self.diagnostics
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.diagnostics.is_empty()
+  }
+
+  /// Whether any diagnostic has [`Severity::Error`].
+  pub fn has_errors(&self) -> bool {
+    self.errors().next().is_some()
+  }
+
+  pub fn errors(&self) -> impl Iterator<Item = &LintDiagnostic> {
+    self.with_severity(Severity::Error)
+  }
+
+  pub fn warnings(&self) -> impl Iterator<Item = &LintDiagnostic> {
+    self.with_severity(Severity::Warning)
+  }
+
+  pub fn advices(&self) -> impl Iterator<Item = &LintDiagnostic> {
+    self.with_severity(Severity::Advice)
+  }
+
+  fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &LintDiagnostic> {
+    self
+      .diagnostics
+      .iter()
+      .filter(move |diagnostic| diagnostic.severity == severity)
+  }
+
+  pub fn fixes(&self) -> impl Iterator<Item = &Fix> {
+    self
+      .diagnostics
+      .iter()
+      .filter_map(|diagnostic| diagnostic.fix.as_ref())
+  }
+
+  /// The manifest with every available fix applied, or `None` when the
+  /// report has no source text.
+  pub fn apply_fixes(&self) -> Option<String> {
+    let source = self.source.as_ref()?;
+    Some(apply_fixes(source, self.fixes()))
+  }
+
+  /// The diagnostics as printable reports, with the manifest attached as
+  /// source code.
+  pub fn reports(&self) -> Vec<miette::Report> {
+    self
+      .diagnostics
+      .iter()
+      .map(|diagnostic| {
+        let report = miette::Report::new(diagnostic.clone());
+        match (self.source.as_ref(), self.path.as_ref()) {
+          (Some(source), Some(path)) => {
+            report.with_source_code(NamedSource::new(path.clone(), source.clone()))
+          }
+          (Some(source), None) => report.with_source_code(source.clone()),
+          _ => report,
+        }
+      })
+      .collect()
+  }
+}
+
+/// What a [`Rule`] sees of the manifest being linted.
+pub struct LintContext<'a> {
+  package: &'a PackageJsonParser,
+  root: Option<&'a Object<'a>>,
+}
+
+impl<'a> LintContext<'a> {
+  pub fn package(&self) -> &'a PackageJsonParser {
+    self.package
+  }
+
+  /// The root object of the jsonc AST, for spans beyond [`LintContext::label`].
+  ///
+  /// For YAML manifests this is the AST of a JSON rendering of the document;
+  /// labels built from it are mapped back onto the YAML source.
+  pub fn root(&self) -> Option<&'a Object<'a>> {
+    self.root
+  }
+
+  /// Whether the top-level field `key` is present.
+  pub fn has(&self, key: &str) -> bool {
+    self.package.raw_field(key).is_some()
+  }
+
+  /// The span of the value at `path`, e.g. `&["dependencies", "react"]`.
+  pub fn range(&self, path: &[&str]) -> Option<Range<usize>> {
+    let (key, rest) = path.split_first()?;
+    let prop = self.root.and_then(|root| root.get(key));
+    value_range(prop, rest)
+  }
+
+  /// A label on the value at `path`.
+  pub fn label(&self, path: &[&str], text: &str) -> Option<LabeledSpan> {
+    self.range(path).map(|range| LabeledSpan::at(range, text))
+  }
+
+  /// A label on element `index` of the top-level array field `key`.
+  pub fn element_label(&self, key: &str, index: usize, text: &str) -> Option<LabeledSpan> {
+    let array = self
+      .root
+      .and_then(|root| root.get(key))
+      .map(|prop| &prop.value);
+    node_range(array_child(array, index)).map(|range| LabeledSpan::at(range, text))
+  }
+}
+
+impl PackageJsonParser {
+  /// Runs every field validator and the [`LINT_RULES`] with their default
+  /// levels, collecting all findings instead of stopping at the first one.
+  ///
+  /// Field validation failures are errors; the lint rules report softer
+  /// issues as warnings or advice, so callers can fail on
+  /// [`LintReport::has_errors`] and still show the rest.
+  pub fn lint(&self) -> LintReport {
+    self.lint_with(&RuleConfig::default())
+  }
+
+  /// Like [`PackageJsonParser::lint`], with rule levels, policies and custom
+  /// rules taken from `config`.
+  pub fn lint_with(&self, config: &RuleConfig) -> LintReport {
+    let source = self.span_source();
+    let options = JsoncParseOptions {
+      allow_comments: true,
+      allow_loose_object_property_names: true,
+      allow_trailing_commas: true,
+    };
+    let ast = source
+      .as_deref()
+      .and_then(|source| parse_to_ast(source, &CollectOptions::default(), &options).ok());
+    let context = LintContext {
+      package: self,
+      root: ast
+        .as_ref()
+        .and_then(|ast| ast.value.as_ref())
+        .and_then(|value| value.as_object()),
+    };
+
+    let mut diagnostics = self
+      .field_errors()
+      .into_iter()
+      .map(|error| {
+        let fix = fix::field_fix(&context, error.field);
+        LintDiagnostic::from_field_error(error).with_fix(self.map_fix(fix))
+      })
+      .collect::<Vec<_>>();
+
+    let builtin = rules::builtin_rules(config);
+    let rules = builtin
+      .iter()
+      .map(|rule| rule.as_ref())
+      .chain(config.custom_rules());
+    for rule in rules {
+      if config.level(rule.meta().code) == Some(RuleLevel::Off) {
+        continue;
+      }
+      diagnostics.extend(
+        rule
+          .check(&context)
+          .into_iter()
+          .map(|mut diagnostic| {
+            diagnostic.labels = self.map_spans(diagnostic.labels);
+            diagnostic.fix = self.map_fix(diagnostic.fix);
+            diagnostic
+          })
+          .filter(|diagnostic| !self.is_suppressed(Some(&diagnostic.code), &diagnostic.labels)),
+      );
+    }
+
+    let diagnostics = diagnostics
+      .into_iter()
+      .filter_map(|mut diagnostic| {
+        if let Some(level) = config.level(&diagnostic.code) {
+          diagnostic.severity = level.severity()?;
+        }
+        Some(diagnostic)
+      })
+      .collect();
+
+    LintReport {
+      diagnostics,
+      source: self.__raw_source.clone(),
+      path: self.__raw_path.clone(),
+    }
+  }
+
+  /// Moves a fix onto the original source, dropping it when an edit has no
+  /// exact counterpart there.
+  fn map_fix(&self, fix: Option<Fix>) -> Option<Fix> {
+    let mut fix = fix?;
+    for edit in &mut fix.edits {
+      edit.range = self.map_node_range(edit.range.clone())?;
+    }
+    Some(fix)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    LintContext, LintDiagnostic, LintRule, PackageJsonParser, Rule, RuleConfig, RuleLevel,
+  };
+  use miette::Severity;
+
+  fn codes(content: &str) -> Vec<String> {
+    let report = PackageJsonParser::parse_str(content).unwrap().lint();
+    report
+      .diagnostics()
+      .iter()
+      .map(|diagnostic| diagnostic.code.clone())
+      .collect()
+  }
+
+  #[test]
+  fn should_pass_lint_for_complete_package() {
+    let content = r#"{
+      "name": "pkg",
+      "description": "A package",
+      "license": "MIT",
+      "repository": "github:user/pkg",
+      "types": "index.d.ts"
+    }"#;
+    assert!(codes(content).is_empty());
+  }
+
+  #[test]
+  fn should_warn_about_missing_fields_of_public_package() {
+    assert_eq!(
+      codes(r#"{ "name": "pkg" }"#),
+      [
+        "pkg::description::missing",
+        "pkg::repository::missing",
+        "pkg::license::missing",
+      ]
+    );
+    assert!(codes(r#"{ "name": "pkg", "private": true }"#).is_empty());
+  }
+
+  #[test]
+  fn should_report_severity_per_rule() {
+    let content =
+      r#"{ "private": true, "types": "a.d.ts", "typings": "a.d.ts", "engineStrict": true }"#;
+    let report = PackageJsonParser::parse_str(content).unwrap().lint();
+
+    assert!(!report.has_errors());
+    assert_eq!(report.warnings().count(), 2);
+
+    let label = report.diagnostics()[0].labels[0].clone();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      "\"a.d.ts\""
+    );
+
+    let report = PackageJsonParser::parse_str(r#"{ "private": true, "typings": "a.d.ts" }"#)
+      .unwrap()
+      .lint();
+    assert_eq!(report.advices().count(), 1);
+  }
+
+  #[test]
+  fn should_report_field_errors_as_lint_errors() {
+    let content = r#"{ "private": true, "name": "Bad Name", "version": 1 }"#;
+    let report = PackageJsonParser::parse_str(content).unwrap().lint();
+
+    assert!(report.has_errors());
+    assert_eq!(report.errors().count(), 2);
+    assert!(
+      report
+        .errors()
+        .all(|diagnostic| diagnostic.severity == Severity::Error)
+    );
+    assert_eq!(report.reports().len(), 2);
+  }
+
+  #[test]
+  fn should_cross_check_dependency_meta_fields() {
+    let content = r#"{
+      "private": true,
+      "dependencies": { "shared": "workspace:*" },
+      "peerDependencies": { "react": "^18.0.0" },
+      "peerDependenciesMeta": { "react": { "optional": true }, "vue": { "optional": true } },
+      "dependenciesMeta": { "shared": { "injected": true }, "utils": { "injected": true } }
+    }"#;
+    let report = PackageJsonParser::parse_str(content).unwrap().lint();
+
+    assert_eq!(
+      codes(content),
+      [
+        "pkg::peer-dependencies-meta::without-peer",
+        "pkg::dependencies-meta::injected-without-dependency",
+      ]
+    );
+    let label = report.diagnostics()[0].labels[0].clone();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      r#"{ "optional": true }"#
+    );
+    assert!(report.diagnostics()[1].message.contains("utils"));
+  }
+
+  #[test]
+  fn should_label_bundled_names_without_dependency() {
+    let content = r#"{
+      "private": true,
+      "dependencies": { "foo": "^1.0.0" },
+      "optionalDependencies": { "bar": "^1.0.0" },
+      "bundledDependencies": ["foo", "bar", "baz"]
+    }"#;
+    let report = PackageJsonParser::parse_str(content).unwrap().lint();
+
+    assert_eq!(
+      codes(content),
+      ["pkg::bundle-dependencies::without-dependency"]
+    );
+    let label = report.diagnostics()[0].labels[0].clone();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      r#""baz""#
+    );
+  }
+
+  #[test]
+  fn should_label_lint_findings_in_yaml_source() {
+    let content = "private: true\ntypings: a.d.ts\n";
+    let report = PackageJsonParser::parse_yaml_str(content).unwrap().lint();
+
+    let label = report.diagnostics()[0].labels[0].clone();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      "a.d.ts"
+    );
+  }
+
+  #[test]
+  fn should_apply_rule_levels_from_toml_config() {
+    let config = RuleConfig::from_toml_str(
+      r#"
+      [rules]
+      "pkg::description::missing" = "off"
+      "pkg::repository::missing" = "error"
+      "pkg::name::invalid-chars" = "warning"
+      "#,
+    )
+    .unwrap();
+    let package = PackageJsonParser::parse_str(r#"{ "name": "Bad", "license": "MIT" }"#).unwrap();
+    let report = package.lint_with(&config);
+
+    let found = report
+      .diagnostics()
+      .iter()
+      .map(|diagnostic| (diagnostic.code.as_str(), diagnostic.severity))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      found,
+      [
+        ("pkg::name::invalid-chars", Severity::Warning),
+        ("pkg::repository::missing", Severity::Error),
+      ]
+    );
+  }
+
+  #[test]
+  fn should_enforce_policies_from_json_config() {
+    let config = RuleConfig::from_json_str(
+      r#"{
+        "requiredFields": ["license", "repository"],
+        "allowedLicenses": ["MIT", "Apache-2.0"],
+        "forbiddenDependencies": ["left-pad"],
+        "requirePrivate": true,
+        "rules": { "pkg::description::missing": "off" }
+      }"#,
+    )
+    .unwrap();
+
+    let content =
+      r#"{ "license": "MIT OR GPL-3.0-only", "dependencies": { "left-pad": "1.0.0" } }"#;
+    let report = PackageJsonParser::parse_str(content)
+      .unwrap()
+      .lint_with(&config);
+    let codes = report
+      .errors()
+      .map(|diagnostic| diagnostic.code.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      codes,
+      [
+        "pkg::policy::required-field",
+        "pkg::policy::forbidden-dependency",
+        "pkg::policy::private-required",
+      ]
+    );
+
+    let label = report.errors().nth(1).unwrap().labels[0].clone();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      "\"1.0.0\""
+    );
+
+    let content =
+      r#"{ "private": true, "license": "MIT AND GPL-3.0-only", "repository": "github:a/b" }"#;
+    let report = PackageJsonParser::parse_str(content)
+      .unwrap()
+      .lint_with(&config);
+    assert_eq!(
+      report.errors().next().unwrap().code,
+      "pkg::policy::license-not-allowed"
+    );
+  }
+
+  struct NoTodoScripts;
+
+  impl Rule for NoTodoScripts {
+    fn meta(&self) -> LintRule {
+      LintRule {
+        code: "acme::no_todo_scripts",
+        severity: Severity::Warning,
+        description: "Scripts must not be placeholders",
+      }
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+      let Ok(Some(scripts)) = context.package().scripts() else {
+        return Vec::new();
+      };
+      scripts
+        .iter()
+        .filter(|(_, command)| command.contains("TODO"))
+        .map(|(name, _)| {
+          LintDiagnostic::new(&self.meta(), format!("Script `{name}` is a placeholder"))
+            .with_label(context.label(&["scripts", name], "here"))
+        })
+        .collect()
+    }
+  }
+
+  #[test]
+  fn should_run_custom_rules() {
+    let content = r#"{ "private": true, "scripts": { "test": "echo TODO" } }"#;
+    let package = PackageJsonParser::parse_str(content).unwrap();
+
+    let config = RuleConfig::default().with_rule(NoTodoScripts);
+    let report = package.lint_with(&config);
+    assert_eq!(report.diagnostics().len(), 1);
+    let label = report.diagnostics()[0].labels[0].clone();
+    assert_eq!(
+      &content[label.offset()..label.offset() + label.len()],
+      "\"echo TODO\""
+    );
+
+    let config = config.with_level("acme::no_todo_scripts", RuleLevel::Off);
+    assert!(package.lint_with(&config).is_empty());
+  }
+
+  #[test]
+  fn should_fail_loading_invalid_config() {
+    assert!(RuleConfig::load("rules.yaml").is_err());
+    assert!(RuleConfig::from_json_str(r#"{ "rules": { "x": "loud" } }"#).is_err());
+    assert!(RuleConfig::from_toml_str("unknownOption = 1").is_err());
+  }
+
+  #[test]
+  fn should_apply_fixes_preserving_formatting() {
+    let content = "{\n    \"name\": \"MyPkg\",\n    \"version\": \"v1.2.3\",  // keep\n    \"repository\": \"user/repo\",\n    \"bugs\": \"bugs@example.com\",\n    \"typings\": \"index.d.ts\"\n}\n";
+    let package =
+      PackageJsonParser::parse_str_with_options(content, crate::ParseOptions::jsonc()).unwrap();
+    let report = package.lint();
+    assert_eq!(report.fixes().count(), 5);
+
+    assert_eq!(
+      report.apply_fixes().unwrap(),
+      "{\n    \"name\": \"mypkg\",\n    \"version\": \"1.2.3\",  // keep\n    \"repository\": { \"type\": \"git\", \"url\": \"git+https://github.com/user/repo.git\" },\n    \"bugs\": { \"email\": \"bugs@example.com\" },\n    \"types\": \"index.d.ts\"\n}\n"
+    );
+  }
+
+  #[test]
+  fn should_apply_fixes_to_yaml_source() {
+    let content = "name: MyPkg\nversion: v1.0.0\nprivate: true\ntypings: index.d.ts\n";
+    let report = PackageJsonParser::parse_yaml_str(content).unwrap().lint();
+
+    // Renaming a key has no exact counterpart in YAML, so only value fixes apply.
+    assert_eq!(report.fixes().count(), 2);
+    let fixed = report.apply_fixes().unwrap();
+    assert_eq!(
+      fixed,
+      "name: \"mypkg\"\nversion: \"1.0.0\"\nprivate: true\ntypings: index.d.ts\n"
+    );
+    assert!(
+      !PackageJsonParser::parse_yaml_str(&fixed)
+        .unwrap()
+        .lint()
+        .has_errors()
+    );
+  }
+}
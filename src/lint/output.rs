@@ -0,0 +1,391 @@
+use cow_utils::CowUtils;
+use miette::{Diagnostic, Severity};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+use std::fmt::Write;
+
+use super::{LINT_RULES, LintReport};
+use crate::codes;
+
+/// Version of the [`LintReport::to_json`] format. It only changes when a
+/// field is removed or changes meaning.
+pub const OUTPUT_VERSION: u32 = 1;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// A diagnostic in the machine-readable output formats.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticOutput {
+  pub code: Option<String>,
+  /// `error`, `warning` or `advice`.
+  pub severity: String,
+  pub message: String,
+  /// The manifest path, when it was parsed from a file.
+  pub path: Option<String>,
+  /// Where the first label points, when the source text is known.
+  pub range: Option<OutputRange>,
+  pub help: Option<String>,
+  /// Explanation of the code, for codes in the [registry](crate::codes).
+  pub url: Option<String>,
+}
+
+/// A span of the manifest. Lines and columns start at 1, columns count
+/// Unicode code points and `end` points just past the last character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputRange {
+  pub start: LineColumn,
+  pub end: LineColumn,
+  /// Byte offset of the span in the manifest.
+  pub offset: usize,
+  /// Length of the span in bytes.
+  pub length: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineColumn {
+  pub line: usize,
+  pub column: usize,
+}
+
+impl LineColumn {
+  fn at(source: &str, offset: usize) -> Self {
+    let prefix = source.get(..offset.min(source.len())).unwrap_or(source);
+    let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+
+    Self {
+      line: prefix.matches('\n').count() + 1,
+      column: prefix[line_start..].chars().count() + 1,
+    }
+  }
+}
+
+impl DiagnosticOutput {
+  /// Converts `diagnostic`, resolving its first label against `source`.
+  pub fn from_diagnostic(
+    diagnostic: &dyn Diagnostic,
+    source: Option<&str>,
+    path: Option<&str>,
+  ) -> Self {
+    let label = diagnostic.labels().and_then(|mut labels| labels.next());
+    let range = source.zip(label).map(|(source, label)| OutputRange {
+      start: LineColumn::at(source, label.offset()),
+      end: LineColumn::at(source, label.offset() + label.len()),
+      offset: label.offset(),
+      length: label.len(),
+    });
+
+    Self {
+      code: diagnostic.code().map(|code| code.to_string()),
+      severity: severity_name(diagnostic.severity().unwrap_or(Severity::Error)).to_string(),
+      message: diagnostic.to_string(),
+      path: path.map(str::to_string),
+      range,
+      help: diagnostic.help().map(|help| help.to_string()),
+      url: diagnostic.url().map(|url| url.to_string()),
+    }
+  }
+
+  /// Converts a report, one entry per related diagnostic of an aggregated
+  /// report such as the one of [`PackageJsonParser::validate_all`](crate::PackageJsonParser::validate_all).
+  pub fn from_report(
+    report: &dyn Diagnostic,
+    source: Option<&str>,
+    path: Option<&str>,
+  ) -> Vec<Self> {
+    match report.related() {
+      Some(related) => related
+        .map(|diagnostic| Self::from_diagnostic(diagnostic, source, path))
+        .collect(),
+      None => vec![Self::from_diagnostic(report, source, path)],
+    }
+  }
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+  match severity {
+    Severity::Error => "error",
+    Severity::Warning => "warning",
+    Severity::Advice => "advice",
+  }
+}
+
+impl LintReport {
+  pub fn outputs(&self) -> Vec<DiagnosticOutput> {
+    self
+      .diagnostics
+      .iter()
+      .map(|diagnostic| {
+        DiagnosticOutput::from_diagnostic(diagnostic, self.source.as_deref(), self.path.as_deref())
+      })
+      .collect()
+  }
+
+  /// The report in the stable JSON format:
+  ///
+  /// ```json
+  /// { "version": 1, "path": "package.json", "diagnostics": [DiagnosticOutput] }
+  /// ```
+  pub fn to_json(&self) -> JsonValue {
+    json!({
+      "version": OUTPUT_VERSION,
+      "path": self.path,
+      "diagnostics": self.outputs(),
+    })
+  }
+
+  /// The report as a SARIF 2.1.0 log. Use [`sarif_log`] to combine the
+  /// reports of several manifests into one run.
+  pub fn to_sarif(&self) -> JsonValue {
+    sarif_log(&self.outputs())
+  }
+}
+
+/// Builds a SARIF 2.1.0 log with a single run holding `diagnostics`, for
+/// code-scanning tools that ingest SARIF.
+pub fn sarif_log(diagnostics: &[DiagnosticOutput]) -> JsonValue {
+  let mut rule_ids: Vec<&str> = Vec::new();
+  for code in diagnostics
+    .iter()
+    .filter_map(|diagnostic| diagnostic.code.as_deref())
+  {
+    if !rule_ids.contains(&code) {
+      rule_ids.push(code);
+    }
+  }
+
+  let rules = rule_ids
+    .iter()
+    .map(|id| {
+      let mut rule = match LINT_RULES.iter().find(|rule| rule.code == *id) {
+        Some(rule) => json!({
+          "id": id,
+          "shortDescription": { "text": rule.description },
+          "defaultConfiguration": { "level": sarif_level(severity_name(rule.severity)) },
+        }),
+        None => match codes::lookup(id) {
+          Some(code) => json!({
+            "id": id,
+            "shortDescription": { "text": code.summary },
+            "defaultConfiguration": { "level": "error" },
+          }),
+          None => json!({ "id": id }),
+        },
+      };
+      if let Some(code) = codes::lookup(id) {
+        rule["helpUri"] = json!(code.url());
+      }
+      rule
+    })
+    .collect::<Vec<_>>();
+
+  let results = diagnostics
+    .iter()
+    .map(|diagnostic| {
+      let mut result = json!({
+        "level": sarif_level(&diagnostic.severity),
+        "message": { "text": diagnostic.message },
+      });
+      if let Some(code) = &diagnostic.code {
+        result["ruleId"] = json!(code);
+        result["ruleIndex"] = json!(rule_ids.iter().position(|id| id == code));
+      }
+      if let Some(path) = &diagnostic.path {
+        let mut location = json!({ "artifactLocation": artifact_location(path) });
+        if let Some(range) = &diagnostic.range {
+          location["region"] = json!({
+            "startLine": range.start.line,
+            "startColumn": range.start.column,
+            "endLine": range.end.line,
+            "endColumn": range.end.column,
+            "byteOffset": range.offset,
+            "byteLength": range.length,
+          });
+        }
+        result["locations"] = json!([{ "physicalLocation": location }]);
+      }
+      if let Some(help) = &diagnostic.help {
+        result["properties"] = json!({ "help": help });
+      }
+      result
+    })
+    .collect::<Vec<_>>();
+
+  json!({
+    "$schema": SARIF_SCHEMA,
+    "version": "2.1.0",
+    "runs": [{
+      "tool": {
+        "driver": {
+          "name": env!("CARGO_PKG_NAME"),
+          "version": env!("CARGO_PKG_VERSION"),
+          "informationUri": env!("CARGO_PKG_REPOSITORY"),
+          "rules": rules,
+        },
+      },
+      "columnKind": "unicodeCodePoints",
+      "results": results,
+    }],
+  })
+}
+
+/// SARIF locations are URIs: absolute paths become `file://` URIs and
+/// relative ones are resolved against `%SRCROOT%`, the directory the
+/// manifests were read from.
+fn artifact_location(path: &str) -> JsonValue {
+  let path = path.cow_replace('\\', "/");
+  if lazy_regex::regex_is_match!(r"^[A-Za-z]:/", &path) {
+    let (drive, rest) = path.split_at(2);
+    return json!({ "uri": format!("file:///{drive}{}", encode_uri_path(rest)) });
+  }
+  if path.starts_with('/') {
+    return json!({ "uri": format!("file://{}", encode_uri_path(&path)) });
+  }
+  json!({ "uri": encode_uri_path(&path), "uriBaseId": "%SRCROOT%" })
+}
+
+/// Percent-encodes every byte of `path` except unreserved characters and `/`.
+fn encode_uri_path(path: &str) -> String {
+  let mut encoded = String::with_capacity(path.len());
+  for byte in path.bytes() {
+    if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+      encoded.push(char::from(byte));
+    } else {
+      let _ = write!(encoded, "%{byte:02X}");
+    }
+  }
+  encoded
+}
+
+fn sarif_level(severity: &str) -> &'static str {
+  match severity {
+    "error" => "error",
+    "warning" => "warning",
+    _ => "note",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{DiagnosticOutput, LineColumn, artifact_location, sarif_log};
+  use crate::PackageJsonParser;
+  use serde_json::json;
+
+  #[test]
+  fn should_count_lines_and_columns_from_one() {
+    let source = "{\n  \"a\": \"é x\"\n}";
+    let offset = source.find('x').unwrap();
+    assert_eq!(LineColumn::at(source, 0), LineColumn { line: 1, column: 1 });
+    assert_eq!(
+      LineColumn::at(source, offset),
+      LineColumn {
+        line: 2,
+        column: 11
+      }
+    );
+  }
+
+  #[test]
+  fn should_serialize_lint_report_to_json() {
+    let content = "{\n  \"private\": true,\n  \"name\": \"Bad Name\"\n}";
+    let output = PackageJsonParser::parse_str(content)
+      .unwrap()
+      .lint()
+      .to_json();
+
+    assert_eq!(output["version"], 1);
+    let diagnostic = &output["diagnostics"][0];
+    assert_eq!(diagnostic["severity"], "error");
+    assert_eq!(diagnostic["range"]["start"]["line"], 3);
+    assert_eq!(diagnostic["range"]["start"]["column"], 11);
+    assert_eq!(diagnostic["range"]["end"]["column"], 21);
+    assert!(diagnostic["help"].is_string());
+  }
+
+  #[test]
+  fn should_convert_validation_report() {
+    let content = r#"{ "name": "Bad Name", "version": 1 }"#;
+    let report = PackageJsonParser::parse_str(content)
+      .unwrap()
+      .validate_all()
+      .unwrap_err();
+
+    let outputs = DiagnosticOutput::from_report(report.as_ref(), Some(content), None);
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[0].range.unwrap().offset, 10);
+  }
+
+  #[test]
+  fn should_build_sarif_log() {
+    let path = concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/fixtures/package_manager_fail.json"
+    );
+    let sarif = PackageJsonParser::parse(path).unwrap().lint().to_sarif();
+
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "package_json_parser");
+
+    let result = &run["results"][0];
+    assert_eq!(result["level"], "error");
+    let rule = &run["tool"]["driver"]["rules"][result["ruleIndex"].as_u64().unwrap() as usize];
+    assert_eq!(rule["id"], result["ruleId"]);
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert!(
+      location["artifactLocation"]["uri"]
+        .as_str()
+        .unwrap()
+        .ends_with("fixtures/package_manager_fail.json")
+    );
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 20);
+  }
+
+  #[test]
+  fn should_locate_sarif_results_in_file() {
+    let outputs = vec![DiagnosticOutput {
+      code: Some("pkg::license::missing".to_string()),
+      severity: "warning".to_string(),
+      message: "Missing `license` field".to_string(),
+      path: Some("packages\\a\\package.json".to_string()),
+      range: None,
+      help: None,
+      url: None,
+    }];
+    let sarif = sarif_log(&outputs);
+    let result = &sarif["runs"][0]["results"][0];
+
+    assert_eq!(result["level"], "warning");
+    assert_eq!(
+      result["locations"][0]["physicalLocation"]["artifactLocation"],
+      json!({ "uri": "packages/a/package.json", "uriBaseId": "%SRCROOT%" })
+    );
+    assert_eq!(
+      sarif["runs"][0]["tool"]["driver"]["rules"][0]["defaultConfiguration"]["level"],
+      "warning"
+    );
+    assert!(
+      sarif["runs"][0]["tool"]["driver"]["rules"][0]["helpUri"]
+        .as_str()
+        .unwrap()
+        .ends_with("#pkglicensemissing")
+    );
+  }
+
+  #[test]
+  fn should_encode_sarif_artifact_uris() {
+    assert_eq!(
+      artifact_location("/home/me/my pkg/package.json"),
+      json!({ "uri": "file:///home/me/my%20pkg/package.json" })
+    );
+    assert_eq!(
+      artifact_location("C:\\work\\a b\\package.json"),
+      json!({ "uri": "file:///C:/work/a%20b/package.json" })
+    );
+    assert_eq!(
+      artifact_location("pkgs/#1 é/package.json"),
+      json!({ "uri": "pkgs/%231%20%C3%A9/package.json", "uriBaseId": "%SRCROOT%" })
+    );
+  }
+}
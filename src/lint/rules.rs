@@ -0,0 +1,507 @@
+use jsonc_parser::common::Ranged;
+use miette::Severity;
+use serde_json::json;
+use validator::ValidateEmail;
+
+use super::{Fix, LintContext, LintDiagnostic, LintRule, Rule, RuleConfig};
+use crate::codes;
+use crate::def::split_selector;
+use crate::{LicenseSpec, SpdxExpression};
+
+const MISSING_DESCRIPTION: LintRule = LintRule {
+  code: codes::DESCRIPTION_MISSING.code,
+  severity: Severity::Warning,
+  description: "Public packages should have a `description`",
+};
+
+const MISSING_REPOSITORY: LintRule = LintRule {
+  code: codes::REPOSITORY_MISSING.code,
+  severity: Severity::Warning,
+  description: "Public packages should have a `repository`",
+};
+
+const MISSING_LICENSE: LintRule = LintRule {
+  code: codes::LICENSE_MISSING.code,
+  severity: Severity::Warning,
+  description: "Public packages should have a `license`",
+};
+
+const TYPES_AND_TYPINGS: LintRule = LintRule {
+  code: codes::TYPINGS_DUPLICATES_TYPES.code,
+  severity: Severity::Warning,
+  description: "`types` and `typings` should not both be set",
+};
+
+const PREFER_TYPES: LintRule = LintRule {
+  code: codes::TYPINGS_PREFER_TYPES.code,
+  severity: Severity::Advice,
+  description: "`types` is preferred over its `typings` alias",
+};
+
+const DEPRECATED_ENGINE_STRICT: LintRule = LintRule {
+  code: codes::ENGINE_STRICT_DEPRECATED.code,
+  severity: Severity::Warning,
+  description: "`engineStrict` is ignored since npm 3",
+};
+
+const BUGS_EMAIL: LintRule = LintRule {
+  code: codes::BUGS_EMAIL_STRING.code,
+  severity: Severity::Advice,
+  description: "An email in `bugs` reads clearer as `{ \"email\": ... }`",
+};
+
+const PEER_META_WITHOUT_PEER: LintRule = LintRule {
+  code: codes::PEER_DEPENDENCIES_META_WITHOUT_PEER.code,
+  severity: Severity::Warning,
+  description: "`peerDependenciesMeta` entries should have a `peerDependencies` entry",
+};
+
+const INJECTED_WITHOUT_DEPENDENCY: LintRule = LintRule {
+  code: codes::DEPENDENCIES_META_INJECTED_WITHOUT_DEPENDENCY.code,
+  severity: Severity::Warning,
+  description: "`dependenciesMeta` entries marked `injected` should name a dependency",
+};
+
+const BUNDLED_WITHOUT_DEPENDENCY: LintRule = LintRule {
+  code: codes::BUNDLE_DEPENDENCIES_WITHOUT_DEPENDENCY.code,
+  severity: Severity::Warning,
+  description: "Bundled packages should be in `dependencies` or `optionalDependencies`",
+};
+
+const REQUIRED_FIELD: LintRule = LintRule {
+  code: codes::POLICY_REQUIRED_FIELD.code,
+  severity: Severity::Error,
+  description: "Fields listed in `requiredFields` must be present",
+};
+
+const LICENSE_NOT_ALLOWED: LintRule = LintRule {
+  code: codes::POLICY_LICENSE_NOT_ALLOWED.code,
+  severity: Severity::Error,
+  description: "The license must be satisfiable with `allowedLicenses`",
+};
+
+const FORBIDDEN_DEPENDENCY: LintRule = LintRule {
+  code: codes::POLICY_FORBIDDEN_DEPENDENCY.code,
+  severity: Severity::Error,
+  description: "Packages in `forbiddenDependencies` must not be depended on",
+};
+
+const PRIVATE_REQUIRED: LintRule = LintRule {
+  code: codes::POLICY_PRIVATE_REQUIRED.code,
+  severity: Severity::Error,
+  description: "`private: true` is required when `requirePrivate` is set",
+};
+
+/// Every built-in lint rule. The policy rules at the end only report
+/// anything once [`RuleConfig`] configures them.
+pub const LINT_RULES: &[LintRule] = &[
+  MISSING_DESCRIPTION,
+  MISSING_REPOSITORY,
+  MISSING_LICENSE,
+  TYPES_AND_TYPINGS,
+  PREFER_TYPES,
+  DEPRECATED_ENGINE_STRICT,
+  BUGS_EMAIL,
+  PEER_META_WITHOUT_PEER,
+  INJECTED_WITHOUT_DEPENDENCY,
+  BUNDLED_WITHOUT_DEPENDENCY,
+  REQUIRED_FIELD,
+  LICENSE_NOT_ALLOWED,
+  FORBIDDEN_DEPENDENCY,
+  PRIVATE_REQUIRED,
+];
+
+/// Dependency fields checked by `forbiddenDependencies` and `injected`.
+const DEPENDENCY_FIELDS: &[&str] = &[
+  "dependencies",
+  "devDependencies",
+  "optionalDependencies",
+  "peerDependencies",
+];
+
+pub(super) fn builtin_rules(config: &RuleConfig) -> Vec<Box<dyn Rule>> {
+  vec![
+    Box::new(MissingField {
+      meta: MISSING_DESCRIPTION,
+      fields: &["description"],
+      help: "npm shows the description in search results",
+    }),
+    Box::new(MissingField {
+      meta: MISSING_REPOSITORY,
+      fields: &["repository"],
+      help: "Add a `repository` so users can find the source code",
+    }),
+    Box::new(MissingField {
+      meta: MISSING_LICENSE,
+      fields: &["license", "licenses"],
+      help: "Use an SPDX expression such as `MIT`, or `UNLICENSED` for proprietary code",
+    }),
+    Box::new(TypesAndTypings),
+    Box::new(PreferTypes),
+    Box::new(DeprecatedEngineStrict),
+    Box::new(BugsEmail),
+    Box::new(PeerMetaWithoutPeer),
+    Box::new(InjectedWithoutDependency),
+    Box::new(BundledWithoutDependency),
+    Box::new(RequiredFields(config.required_fields.clone())),
+    Box::new(AllowedLicenses(config.allowed_licenses.clone())),
+    Box::new(ForbiddenDependencies(config.forbidden_dependencies.clone())),
+    Box::new(RequirePrivate(config.require_private)),
+  ]
+}
+
+fn is_private(context: &LintContext) -> bool {
+  context.package().raw_field("private") == Some(&true.into())
+}
+
+/// A field npm warns about when a public package leaves it out.
+struct MissingField {
+  meta: LintRule,
+  fields: &'static [&'static str],
+  help: &'static str,
+}
+
+impl Rule for MissingField {
+  fn meta(&self) -> LintRule {
+    self.meta
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    if is_private(context) || self.fields.iter().any(|field| context.has(field)) {
+      return Vec::new();
+    }
+
+    let message = format!("Missing `{}` field", self.fields[0]);
+    vec![LintDiagnostic::new(&self.meta, message).with_help(self.help)]
+  }
+}
+
+struct TypesAndTypings;
+
+impl Rule for TypesAndTypings {
+  fn meta(&self) -> LintRule {
+    TYPES_AND_TYPINGS
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    if !context.has("types") || !context.has("typings") {
+      return Vec::new();
+    }
+
+    vec![
+      LintDiagnostic::new(&TYPES_AND_TYPINGS, "`types` and `typings` are both set")
+        .with_help("`typings` is an alias of `types`; keep only `types`")
+        .with_label(context.label(&["typings"], "duplicates `types`")),
+    ]
+  }
+}
+
+struct PreferTypes;
+
+impl Rule for PreferTypes {
+  fn meta(&self) -> LintRule {
+    PREFER_TYPES
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    if context.has("types") || !context.has("typings") {
+      return Vec::new();
+    }
+
+    let key = context
+      .root()
+      .and_then(|root| root.get("typings"))
+      .map(|prop| prop.name.range());
+    let fix = key.map(|key| {
+      Fix::replace(
+        "Rename `typings` to `types`",
+        key.start..key.end,
+        "\"types\"",
+      )
+    });
+
+    vec![
+      LintDiagnostic::new(&PREFER_TYPES, "`typings` is an alias of `types`")
+        .with_help("Rename `typings` to `types`")
+        .with_label(context.label(&["typings"], "here"))
+        .with_fix(fix),
+    ]
+  }
+}
+
+struct DeprecatedEngineStrict;
+
+impl Rule for DeprecatedEngineStrict {
+  fn meta(&self) -> LintRule {
+    DEPRECATED_ENGINE_STRICT
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    if !context.has("engineStrict") {
+      return Vec::new();
+    }
+
+    vec![
+      LintDiagnostic::new(&DEPRECATED_ENGINE_STRICT, "`engineStrict` is deprecated")
+        .with_help("npm ignores it; set `engine-strict=true` in .npmrc instead")
+        .with_label(context.label(&["engineStrict"], "here")),
+    ]
+  }
+}
+
+struct BugsEmail;
+
+impl Rule for BugsEmail {
+  fn meta(&self) -> LintRule {
+    BUGS_EMAIL
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    let Some(email) = context.package().raw_str("bugs") else {
+      return Vec::new();
+    };
+    if !email.validate_email() {
+      return Vec::new();
+    }
+
+    let fix = context
+      .range(&["bugs"])
+      .map(|range| Fix::replace_value("Use the object form", range, &json!({ "email": email })));
+    vec![
+      LintDiagnostic::new(&BUGS_EMAIL, "`bugs` is an email address")
+        .with_help("npm normalizes it to `{ \"email\": ... }`")
+        .with_label(context.label(&["bugs"], "here"))
+        .with_fix(fix),
+    ]
+  }
+}
+
+/// Whether `name` is a key of the top-level dependency map `field`.
+fn declares(context: &LintContext, field: &str, name: &str) -> bool {
+  context
+    .package()
+    .raw_field(field)
+    .and_then(|value| value.as_object())
+    .is_some_and(|dependencies| dependencies.contains_key(name))
+}
+
+struct PeerMetaWithoutPeer;
+
+impl Rule for PeerMetaWithoutPeer {
+  fn meta(&self) -> LintRule {
+    PEER_META_WITHOUT_PEER
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    let Ok(Some(meta)) = context.package().peer_dependencies_meta() else {
+      return Vec::new();
+    };
+
+    meta
+      .keys()
+      .filter(|name| !declares(context, "peerDependencies", name))
+      .map(|name| {
+        LintDiagnostic::new(
+          &PEER_META_WITHOUT_PEER,
+          format!("`{name}` has `peerDependenciesMeta` but is not a peer dependency"),
+        )
+        .with_help(format!(
+          "Add `{name}` to `peerDependencies` with the range it supports"
+        ))
+        .with_label(context.label(&["peerDependenciesMeta", name], "not in `peerDependencies`"))
+      })
+      .collect()
+  }
+}
+
+struct InjectedWithoutDependency;
+
+impl Rule for InjectedWithoutDependency {
+  fn meta(&self) -> LintRule {
+    INJECTED_WITHOUT_DEPENDENCY
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    let Ok(Some(meta)) = context.package().dependencies_meta() else {
+      return Vec::new();
+    };
+
+    meta
+      .iter()
+      .filter(|(_, entry)| entry.injected == Some(true))
+      .filter(|(selector, _)| {
+        let (name, _) = split_selector(selector);
+        !DEPENDENCY_FIELDS
+          .iter()
+          .any(|field| declares(context, field, name))
+      })
+      .map(|(selector, _)| {
+        LintDiagnostic::new(
+          &INJECTED_WITHOUT_DEPENDENCY,
+          format!("`{selector}` is marked `injected` but is not a dependency"),
+        )
+        .with_help("pnpm only injects direct dependencies")
+        .with_label(context.label(&["dependenciesMeta", selector], "not a dependency"))
+      })
+      .collect()
+  }
+}
+
+struct BundledWithoutDependency;
+
+impl Rule for BundledWithoutDependency {
+  fn meta(&self) -> LintRule {
+    BUNDLED_WITHOUT_DEPENDENCY
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    let package = context.package();
+    let (Some(key), Ok(Some(bundled))) = (
+      package.bundle_dependencies_key(),
+      package.bundle_dependencies(),
+    ) else {
+      return Vec::new();
+    };
+
+    bundled
+      .names()
+      .iter()
+      .enumerate()
+      .filter(|(_, name)| {
+        !["dependencies", "optionalDependencies"]
+          .iter()
+          .any(|field| declares(context, field, name))
+      })
+      .map(|(index, name)| {
+        LintDiagnostic::new(
+          &BUNDLED_WITHOUT_DEPENDENCY,
+          format!("`{name}` is bundled but is not a dependency"),
+        )
+        .with_help(format!(
+          "npm only bundles installed packages; add `{name}` to `dependencies`"
+        ))
+        .with_label(context.element_label(key, index, "not in `dependencies`"))
+      })
+      .collect()
+  }
+}
+
+struct RequiredFields(Vec<String>);
+
+impl Rule for RequiredFields {
+  fn meta(&self) -> LintRule {
+    REQUIRED_FIELD
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    self
+      .0
+      .iter()
+      .filter(|field| !context.has(field))
+      .map(|field| {
+        LintDiagnostic::new(&REQUIRED_FIELD, format!("Missing required field `{field}`"))
+          .with_help("The rule config lists this field in `requiredFields`")
+      })
+      .collect()
+  }
+}
+
+struct AllowedLicenses(Vec<String>);
+
+impl AllowedLicenses {
+  /// An `OR` needs one allowed branch, an `AND` needs all of them.
+  fn allows(&self, expression: &SpdxExpression) -> bool {
+    match expression {
+      SpdxExpression::License(license) => self.0.contains(&license.id),
+      SpdxExpression::And(left, right) => self.allows(left) && self.allows(right),
+      SpdxExpression::Or(left, right) => self.allows(left) || self.allows(right),
+    }
+  }
+}
+
+impl Rule for AllowedLicenses {
+  fn meta(&self) -> LintRule {
+    LICENSE_NOT_ALLOWED
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    if self.0.is_empty() {
+      return Vec::new();
+    }
+    let Ok(Some(license)) = context.package().license() else {
+      return Vec::new();
+    };
+
+    let allowed = match license.spec() {
+      Some(LicenseSpec::Spdx(expression)) => self.allows(&expression),
+      Some(LicenseSpec::Unlicensed) => self.0.iter().any(|id| id == "UNLICENSED"),
+      // Invalid licenses are already reported by field validation.
+      Some(LicenseSpec::SeeLicenseIn(_)) | None => return Vec::new(),
+    };
+    if allowed {
+      return Vec::new();
+    }
+
+    vec![
+      LintDiagnostic::new(
+        &LICENSE_NOT_ALLOWED,
+        format!("License `{}` is not allowed", license.value()),
+      )
+      .with_help(format!("Allowed licenses: {}", self.0.join(", ")))
+      .with_label(context.label(&["license"], "not in `allowedLicenses`")),
+    ]
+  }
+}
+
+struct ForbiddenDependencies(Vec<String>);
+
+impl Rule for ForbiddenDependencies {
+  fn meta(&self) -> LintRule {
+    FORBIDDEN_DEPENDENCY
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for field in DEPENDENCY_FIELDS {
+      let Some(dependencies) = context
+        .package()
+        .raw_field(field)
+        .and_then(|value| value.as_object())
+      else {
+        continue;
+      };
+
+      for name in dependencies.keys().filter(|name| self.0.contains(name)) {
+        diagnostics.push(
+          LintDiagnostic::new(
+            &FORBIDDEN_DEPENDENCY,
+            format!("`{name}` must not be used as a dependency"),
+          )
+          .with_help("The rule config lists this package in `forbiddenDependencies`")
+          .with_label(context.label(&[field, name], "forbidden")),
+        );
+      }
+    }
+    diagnostics
+  }
+}
+
+struct RequirePrivate(bool);
+
+impl Rule for RequirePrivate {
+  fn meta(&self) -> LintRule {
+    PRIVATE_REQUIRED
+  }
+
+  fn check(&self, context: &LintContext) -> Vec<LintDiagnostic> {
+    if !self.0 || is_private(context) {
+      return Vec::new();
+    }
+
+    vec![
+      LintDiagnostic::new(&PRIVATE_REQUIRED, "Package must set `private: true`")
+        .with_help("Applications are not meant to be published to the registry")
+        .with_label(context.label(&["private"], "here")),
+    ]
+  }
+}
@@ -0,0 +1,488 @@
+use std::fs;
+use std::path::Path;
+
+use indexmap::IndexMap;
+use jsonc_parser::ast::{Object, ObjectProp};
+use jsonc_parser::{CollectOptions, ParseOptions, parse_to_ast};
+use miette::{LabeledSpan, MietteDiagnostic, NamedSource, Severity};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+use crate::codes::{
+  LOCKFILE_INVALID_VERSION, LOCKFILE_MISSING_VERSION, LOCKFILE_UNSUPPORTED_VERSION,
+};
+use crate::ext::{Validator, validation_error, value_range};
+use crate::{
+  DependencyKind, ErrorKind, FieldError, FieldResult, PackageJsonParser, SemVersion, VersionRange,
+};
+
+/// An entry of the v2/v3 `packages` map, keyed by install location.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LockedPackage {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub resolved: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub integrity: Option<String>,
+  pub link: bool,
+  pub dev: bool,
+  pub optional: bool,
+  pub dev_optional: bool,
+  pub peer: bool,
+  pub dependencies: IndexMap<String, String>,
+  pub dev_dependencies: IndexMap<String, String>,
+  pub optional_dependencies: IndexMap<String, String>,
+  pub peer_dependencies: IndexMap<String, String>,
+}
+
+/// An entry of the v1 `dependencies` tree.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LegacyDependency {
+  pub version: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub resolved: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub integrity: Option<String>,
+  pub dev: bool,
+  pub optional: bool,
+  pub bundled: bool,
+  pub requires: IndexMap<String, String>,
+  pub dependencies: IndexMap<String, LegacyDependency>,
+}
+
+/// A dependency declared in a manifest, paired with the version the lockfile
+/// installs for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LockedDependency {
+  pub name: String,
+  pub kind: DependencyKind,
+  pub spec: String,
+  /// `None` when the lockfile has no entry for the dependency.
+  pub version: Option<String>,
+}
+
+impl LockedDependency {
+  /// Whether the locked version satisfies the declared range, or `None` when
+  /// either side is not semver (tags, git urls, missing entries, ...).
+  pub fn satisfies(&self) -> Option<bool> {
+    let range = VersionRange::parse(&self.spec).ok()?;
+    let version = SemVersion::parse(self.version.as_deref()?).ok()?;
+    Some(range.satisfies(&version))
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct LockfileVersion(u32);
+
+impl Validator for LockfileVersion {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    if !(1..=3).contains(&self.0) {
+      return Err(validation_error(
+        "Unsupported lockfile version",
+        &LOCKFILE_UNSUPPORTED_VERSION,
+        "Only lockfileVersion 1, 2 and 3 are supported",
+        value_range(prop, &[]),
+        "here",
+      ));
+    }
+    Ok(())
+  }
+}
+
+impl Validator for IndexMap<String, LockedPackage> {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    for (location, package) in self {
+      let Some(version) = package.version.as_ref() else {
+        continue;
+      };
+      if let Err(reason) = SemVersion::parse(version) {
+        return Err(validation_error(
+          "Invalid locked version",
+          &LOCKFILE_INVALID_VERSION,
+          &format!("`{location}`: {reason}"),
+          value_range(prop, &[location, "version"]),
+          "here",
+        ));
+      }
+    }
+    Ok(())
+  }
+}
+
+impl Validator for IndexMap<String, LegacyDependency> {
+  fn validate(&self, prop: Option<&ObjectProp>) -> miette::Result<()> {
+    for (name, dependency) in self {
+      if dependency.version.is_empty() {
+        return Err(validation_error(
+          "Missing locked version",
+          &LOCKFILE_MISSING_VERSION,
+          &format!("`{name}` has no version"),
+          value_range(prop, &[name]),
+          "here",
+        ));
+      }
+    }
+    Ok(())
+  }
+}
+
+/// A parsed npm `package-lock.json` (or `npm-shrinkwrap.json`).
+///
+/// Like [`PackageJsonParser`], fields are decoded up front but their errors
+/// are deferred to the getters and [`PackageLock::validate`], each labeled
+/// with the span of the offending value.
+#[derive(Debug, Clone)]
+pub struct PackageLock {
+  raw_fields: JsonMap<String, JsonValue>,
+  lockfile_version: FieldResult<LockfileVersion>,
+  packages: FieldResult<IndexMap<String, LockedPackage>>,
+  dependencies: FieldResult<IndexMap<String, LegacyDependency>>,
+  raw_source: String,
+  raw_path: Option<String>,
+}
+
+impl PackageLock {
+  pub fn parse_str(content: &str) -> miette::Result<Self> {
+    Self::from_content(content.to_string(), None)
+  }
+
+  pub fn parse<P: AsRef<Path>>(path: P) -> miette::Result<Self> {
+    let content = fs::read_to_string(path.as_ref()).map_err(ErrorKind::IoError)?;
+    Self::from_content(content, Some(path.as_ref().to_string_lossy().to_string()))
+  }
+
+  fn from_content(content: String, path: Option<String>) -> miette::Result<Self> {
+    let options = ParseOptions {
+      allow_comments: false,
+      allow_loose_object_property_names: false,
+      allow_trailing_commas: false,
+    };
+
+    let ast = parse_to_ast(&content, &CollectOptions::default(), &options);
+    let parsed_value: JsonValue = serde_json::from_str(&content).map_err(|error| {
+      PackageJsonParser::build_parse_error(
+        &content,
+        path.as_deref(),
+        ast.as_ref().err(),
+        Some(error),
+      )
+    })?;
+
+    let JsonValue::Object(raw_fields) = parsed_value else {
+      let label = LabeledSpan::new(Some("here".to_string()), 0, content.len().max(1));
+      let diagnostic = MietteDiagnostic::new("package-lock.json root must be a JSON object")
+        .with_label(label)
+        .with_severity(Severity::Error);
+      return Err(miette::miette!(diagnostic));
+    };
+
+    let ast = ast.ok();
+    let root = ast
+      .as_ref()
+      .and_then(|result| result.value.as_ref())
+      .and_then(|value| value.as_object());
+
+    let lockfile_version = decode_field(&raw_fields, root, "lockfileVersion");
+    let packages = decode_field(&raw_fields, root, "packages");
+    let dependencies = decode_field(&raw_fields, root, "dependencies");
+
+    Ok(Self {
+      raw_fields,
+      lockfile_version,
+      packages,
+      dependencies,
+      raw_source: content,
+      raw_path: path,
+    })
+  }
+
+  fn field_result_to_ref<'a, T>(
+    &'a self,
+    state: &'a FieldResult<T>,
+  ) -> miette::Result<Option<&'a T>> {
+    state
+      .as_ref()
+      .map(Option::as_ref)
+      .map_err(|error| self.field_error_to_report(error))
+  }
+
+  fn field_error_to_report(&self, error: &FieldError) -> miette::Report {
+    let report = miette::miette!(error.to_diagnostic());
+    match self.raw_path.as_ref() {
+      Some(path) => report.with_source_code(NamedSource::new(path, self.raw_source.clone())),
+      None => report.with_source_code(self.raw_source.clone()),
+    }
+  }
+
+  /// Fails on the first field that could not be decoded or validated.
+  pub fn validate(&self) -> miette::Result<()> {
+    let errors = [
+      self.lockfile_version.as_ref().err(),
+      self.packages.as_ref().err(),
+      self.dependencies.as_ref().err(),
+    ];
+
+    match errors.into_iter().flatten().next() {
+      Some(error) => Err(self.field_error_to_report(error)),
+      None => Ok(()),
+    }
+  }
+
+  pub fn name(&self) -> Option<&str> {
+    self.raw_fields.get("name").and_then(JsonValue::as_str)
+  }
+
+  pub fn version(&self) -> Option<&str> {
+    self.raw_fields.get("version").and_then(JsonValue::as_str)
+  }
+
+  /// `lockfileVersion`: 1 for npm 5/6, 2 for npm 7/8, 3 for npm 9+.
+  pub fn lockfile_version(&self) -> miette::Result<Option<u32>> {
+    Ok(
+      self
+        .field_result_to_ref(&self.lockfile_version)?
+        .map(|version| version.0),
+    )
+  }
+
+  /// The v2/v3 `packages` map, keyed by install location (`""` is the root).
+  pub fn packages(&self) -> miette::Result<Option<&IndexMap<String, LockedPackage>>> {
+    self.field_result_to_ref(&self.packages)
+  }
+
+  /// The v1 `dependencies` tree, also written by v2 for older npm clients.
+  pub fn dependencies(&self) -> miette::Result<Option<&IndexMap<String, LegacyDependency>>> {
+    self.field_result_to_ref(&self.dependencies)
+  }
+
+  /// The version installed for `name` at the top of the tree.
+  pub fn locked_version(&self, name: &str) -> Option<&str> {
+    self.resolve("", name)
+  }
+
+  /// The version Node would load for `name` from the package installed at
+  /// `location`, walking up through parent `node_modules` folders.
+  ///
+  /// `location` is a `packages` key such as `""`, `packages/app` or
+  /// `node_modules/a`. The v1 tree is only consulted at the top level.
+  pub fn resolve(&self, location: &str, name: &str) -> Option<&str> {
+    if let Ok(Some(packages)) = self.packages.as_ref() {
+      let mut location = location;
+      loop {
+        let key = match location {
+          "" => format!("node_modules/{name}"),
+          location => format!("{location}/node_modules/{name}"),
+        };
+
+        if let Some(package) = packages.get(&key) {
+          let package = match (package.link, package.resolved.as_ref()) {
+            (true, Some(target)) => packages.get(target)?,
+            _ => package,
+          };
+          return package.version.as_deref();
+        }
+
+        if location.is_empty() {
+          break;
+        }
+        location = location
+          .rfind("/node_modules/")
+          .map_or("", |index| &location[..index]);
+      }
+    }
+
+    match self.dependencies.as_ref() {
+      Ok(Some(dependencies)) => dependencies
+        .get(name)
+        .map(|dependency| dependency.version.as_str()),
+      _ => None,
+    }
+  }
+
+  /// Pairs every dependency declared in `manifest` with its locked version.
+  ///
+  /// Workspace members are looked up from their own location, found through
+  /// the `name` npm records on each workspace entry.
+  pub fn locked_dependencies(&self, manifest: &PackageJsonParser) -> Vec<LockedDependency> {
+    let location = self.location_of(manifest).unwrap_or_default();
+    let location = location.as_str();
+
+    DependencyKind::ALL
+      .iter()
+      .filter_map(|kind| {
+        let map = manifest.raw_field(kind.field())?.as_object()?;
+        Some(map.iter().filter_map(move |(name, spec)| {
+          Some(LockedDependency {
+            name: name.clone(),
+            kind: *kind,
+            spec: spec.as_str()?.to_string(),
+            version: self.resolve(location, name).map(str::to_string),
+          })
+        }))
+      })
+      .flatten()
+      .collect()
+  }
+
+  fn location_of(&self, manifest: &PackageJsonParser) -> Option<String> {
+    let name = manifest.raw_str("name")?;
+    if self.name() == Some(name) {
+      return Some(String::new());
+    }
+
+    let packages = self.packages.as_ref().ok()?.as_ref()?;
+    packages
+      .iter()
+      .find(|(location, package)| {
+        !location.is_empty()
+          && !location.contains("node_modules/")
+          && package.name.as_deref() == Some(name)
+      })
+      .map(|(location, _)| location.clone())
+  }
+}
+
+fn decode_field<T>(
+  raw_fields: &JsonMap<String, JsonValue>,
+  root: Option<&Object>,
+  json_key: &'static str,
+) -> FieldResult<T>
+where
+  T: DeserializeOwned + Validator,
+{
+  let Some(raw_value) = raw_fields.get(json_key) else {
+    return Ok(None);
+  };
+  let prop = root.and_then(|root| root.get(json_key));
+
+  let parsed = serde_json::from_value::<T>(raw_value.clone())
+    .map_err(|error| FieldError::deserialize(json_key, error, value_range(prop, &[])))?;
+
+  parsed
+    .validate(prop)
+    .map_err(|error| FieldError::validation(json_key, error))?;
+
+  Ok(Some(parsed))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::PackageLock;
+  use crate::{DependencyKind, PackageJsonParser};
+
+  const LOCK_V3: &str = r#"{
+  "name": "app",
+  "version": "1.0.0",
+  "lockfileVersion": 3,
+  "requires": true,
+  "packages": {
+    "": {
+      "name": "app",
+      "version": "1.0.0",
+      "workspaces": ["packages/*"],
+      "dependencies": { "react": "^18.0.0" },
+      "devDependencies": { "typescript": "~5.4.0" }
+    },
+    "node_modules/react": { "version": "18.3.1", "resolved": "https://registry.npmjs.org/react/-/react-18.3.1.tgz" },
+    "node_modules/typescript": { "version": "5.4.5", "dev": true },
+    "node_modules/@demo/ui": { "resolved": "packages/ui", "link": true },
+    "packages/ui": {
+      "name": "@demo/ui",
+      "version": "0.3.0",
+      "dependencies": { "react": "^17.0.0" }
+    },
+    "packages/ui/node_modules/react": { "version": "17.0.2" }
+  }
+}"#;
+
+  const LOCK_V1: &str = r#"{
+  "name": "legacy",
+  "version": "1.0.0",
+  "lockfileVersion": 1,
+  "requires": true,
+  "dependencies": {
+    "lodash": { "version": "4.17.21", "integrity": "sha512-x" },
+    "left-pad": { "version": "1.3.0", "dev": true }
+  }
+}"#;
+
+  #[test]
+  fn should_parse_v3_lockfile() {
+    let lock = PackageLock::parse_str(LOCK_V3).unwrap();
+    assert!(lock.validate().is_ok());
+    assert_eq!(lock.lockfile_version().unwrap(), Some(3));
+    assert_eq!(lock.packages().unwrap().unwrap().len(), 6);
+    assert_eq!(lock.locked_version("react"), Some("18.3.1"));
+    assert_eq!(lock.locked_version("@demo/ui"), Some("0.3.0"));
+    assert_eq!(lock.resolve("packages/ui", "react"), Some("17.0.2"));
+    assert_eq!(lock.resolve("packages/ui", "typescript"), Some("5.4.5"));
+    assert_eq!(lock.locked_version("missing"), None);
+  }
+
+  #[test]
+  fn should_parse_v1_lockfile() {
+    let lock = PackageLock::parse_str(LOCK_V1).unwrap();
+    assert_eq!(lock.lockfile_version().unwrap(), Some(1));
+    assert!(lock.packages().unwrap().is_none());
+    assert!(lock.dependencies().unwrap().unwrap()["left-pad"].dev);
+    assert_eq!(lock.locked_version("lodash"), Some("4.17.21"));
+  }
+
+  #[test]
+  fn should_lock_manifest_dependencies() {
+    let lock = PackageLock::parse_str(LOCK_V3).unwrap();
+
+    let manifest = PackageJsonParser::parse_str(
+      r#"{"name": "app", "dependencies": {"react": "^18.0.0"}, "devDependencies": {"typescript": "~5.4.0", "eslint": "^9.0.0"}}"#,
+    )
+    .unwrap();
+    let locked = lock.locked_dependencies(&manifest);
+    assert_eq!(locked.len(), 3);
+    assert_eq!(locked[0].version.as_deref(), Some("18.3.1"));
+    assert_eq!(locked[1].kind, DependencyKind::DevDependencies);
+    assert_eq!(locked[1].satisfies(), Some(true));
+    assert_eq!(locked[2].version, None);
+
+    let member =
+      PackageJsonParser::parse_str(r#"{"name": "@demo/ui", "dependencies": {"react": "^17.0.0"}}"#)
+        .unwrap();
+    let locked = lock.locked_dependencies(&member);
+    assert_eq!(locked[0].version.as_deref(), Some("17.0.2"));
+    assert_eq!(locked[0].satisfies(), Some(true));
+  }
+
+  #[test]
+  fn should_label_invalid_locked_version() {
+    let json = r#"{"lockfileVersion": 3, "packages": {"node_modules/a": {"version": "1.0"}}}"#;
+    let lock = PackageLock::parse_str(json).unwrap();
+
+    let report = lock.packages().unwrap_err();
+    let label = report.labels().unwrap().next().unwrap();
+    assert_eq!(
+      &json[label.offset()..label.offset() + label.len()],
+      r#""1.0""#
+    );
+    assert!(lock.validate().is_err());
+  }
+
+  #[test]
+  fn should_fail_unsupported_lockfile_version() {
+    let lock = PackageLock::parse_str(r#"{"lockfileVersion": 4}"#).unwrap();
+    assert!(lock.lockfile_version().is_err());
+
+    let lock = PackageLock::parse_str(r#"{"lockfileVersion": "3"}"#).unwrap();
+    assert!(lock.lockfile_version().is_err());
+  }
+
+  #[test]
+  fn should_fail_parse_invalid_lockfile() {
+    assert!(PackageLock::parse_str("{").is_err());
+    assert!(PackageLock::parse_str("[]").is_err());
+  }
+}
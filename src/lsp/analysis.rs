@@ -0,0 +1,416 @@
+use std::sync::LazyLock;
+
+use jsonc_parser::common::Ranged;
+use jsonc_parser::{CollectOptions, ParseOptions as JsoncParseOptions, parse_to_ast};
+use lsp_types::{
+  CodeDescription, CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity,
+  Documentation, Hover, HoverContents, MarkupContent, MarkupKind, NumberOrString, Position, Range,
+};
+use miette::{LabeledSpan, Severity};
+use serde_json::Value as JsonValue;
+
+use crate::PackageJsonParser;
+use crate::codes;
+use crate::def::PACKAGE_MANAGERS;
+
+const SOURCE: &str = "package-json";
+
+static SCHEMA: LazyLock<JsonValue> = LazyLock::new(|| {
+  serde_json::from_str(include_str!("../../schema.json")).unwrap_or(JsonValue::Null)
+});
+
+/// The `schema.json` entry of a top-level field.
+fn schema_property(name: &str) -> Option<&'static JsonValue> {
+  SCHEMA.get("properties")?.get(name)
+}
+
+fn schema_description(property: &JsonValue) -> Option<&str> {
+  property.get("description")?.as_str()
+}
+
+/// Converts byte offsets to LSP positions, which count UTF-16 code units.
+pub(crate) struct LineIndex<'a> {
+  text: &'a str,
+  line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+  pub(crate) fn new(text: &'a str) -> Self {
+    let line_starts = std::iter::once(0)
+      .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+      .collect();
+    Self { text, line_starts }
+  }
+
+  pub(crate) fn position(&self, offset: usize) -> Position {
+    let offset = offset.min(self.text.len());
+    let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+    let start = self.line_starts[line];
+    let character = self
+      .text
+      .get(start..offset)
+      .map_or(0, |prefix| prefix.encode_utf16().count());
+    Position::new(line as u32, character as u32)
+  }
+
+  pub(crate) fn range(&self, start: usize, end: usize) -> Range {
+    Range::new(self.position(start), self.position(end))
+  }
+
+  pub(crate) fn offset(&self, position: Position) -> usize {
+    let Some(&start) = self.line_starts.get(position.line as usize) else {
+      return self.text.len();
+    };
+    let mut units = 0;
+    for (index, c) in self.text[start..].char_indices() {
+      if units >= position.character as usize || c == '\n' {
+        return start + index;
+      }
+      units += c.len_utf16();
+    }
+    self.text.len()
+  }
+}
+
+fn severity(severity: Severity) -> DiagnosticSeverity {
+  match severity {
+    Severity::Error => DiagnosticSeverity::ERROR,
+    Severity::Warning => DiagnosticSeverity::WARNING,
+    Severity::Advice => DiagnosticSeverity::HINT,
+  }
+}
+
+fn diagnostic(
+  index: &LineIndex,
+  message: String,
+  code: Option<String>,
+  help: Option<String>,
+  level: Severity,
+  mut labels: impl Iterator<Item = LabeledSpan>,
+) -> Diagnostic {
+  let range = labels
+    .next()
+    .map(|label| index.range(label.offset(), label.offset() + label.len()))
+    .unwrap_or_default();
+  let message = match help {
+    Some(help) => format!("{message}\n{help}"),
+    None => message,
+  };
+
+  Diagnostic {
+    range,
+    severity: Some(severity(level)),
+    code_description: code
+      .as_deref()
+      .and_then(codes::lookup)
+      .and_then(|code| code.url().parse().ok())
+      .map(|href| CodeDescription { href }),
+    code: code.map(NumberOrString::String),
+    source: Some(SOURCE.to_string()),
+    message,
+    ..Default::default()
+  }
+}
+
+/// Parses `text` with [`PackageJsonParser::parse_str`] and lints it, the
+/// same way the command line does.
+pub fn diagnostics(text: &str) -> Vec<Diagnostic> {
+  let index = LineIndex::new(text);
+
+  match PackageJsonParser::parse_str(text) {
+    Ok(package) => package
+      .lint()
+      .diagnostics()
+      .iter()
+      .map(|finding| {
+        diagnostic(
+          &index,
+          finding.message.clone(),
+          Some(finding.code.clone()),
+          finding.help.clone(),
+          finding.severity,
+          finding.labels.iter().cloned(),
+        )
+      })
+      .collect(),
+    Err(report) => vec![diagnostic(
+      &index,
+      report.to_string(),
+      report.code().map(|code| code.to_string()),
+      report.help().map(|help| help.to_string()),
+      Severity::Error,
+      report.labels().into_iter().flatten(),
+    )],
+  }
+}
+
+/// The `schema.json` description of the top-level field under the cursor.
+pub fn hover(text: &str, position: Position) -> Option<Hover> {
+  let index = LineIndex::new(text);
+  let offset = index.offset(position);
+
+  let options = JsoncParseOptions::default();
+  let ast = parse_to_ast(text, &CollectOptions::default(), &options).ok()?;
+  let root = ast.value.as_ref()?.as_object()?;
+  let prop = root.properties.iter().find(|prop| {
+    let range = prop.name.range();
+    range.start <= offset && offset <= range.end
+  })?;
+
+  let name = prop.name.as_str();
+  let description = schema_property(name).and_then(schema_description)?;
+  let range = prop.name.range();
+
+  Some(Hover {
+    contents: HoverContents::Markup(MarkupContent {
+      kind: MarkupKind::Markdown,
+      value: format!("**{name}**\n\n{description}"),
+    }),
+    range: Some(index.range(range.start, range.end)),
+  })
+}
+
+/// Where the cursor sits in the root object.
+#[derive(Debug, PartialEq, Eq)]
+enum CompletionContext {
+  Key { quoted: bool },
+  Value { key: String, quoted: bool },
+}
+
+/// Scans `text` tolerantly, since it is usually mid-edit and not valid
+/// JSON. Returns the context at `offset` and every top-level key.
+fn scan(text: &str, offset: usize) -> (Option<CompletionContext>, Vec<String>) {
+  let mut depth = 0usize;
+  let mut in_string = false;
+  let mut escaped = false;
+  let mut string_start = 0;
+  let mut after_colon = false;
+  let mut key = String::new();
+  let mut keys = Vec::new();
+  let mut context = None;
+
+  let snapshot = |depth: usize, in_string: bool, after_colon: bool, key: &str| {
+    if depth != 1 {
+      return None;
+    }
+    Some(if after_colon {
+      CompletionContext::Value {
+        key: key.to_string(),
+        quoted: in_string,
+      }
+    } else {
+      CompletionContext::Key { quoted: in_string }
+    })
+  };
+
+  for (index, c) in text.char_indices() {
+    if index == offset {
+      context = snapshot(depth, in_string, after_colon, &key);
+    }
+
+    if in_string {
+      match c {
+        _ if escaped => escaped = false,
+        '\\' => escaped = true,
+        '"' => {
+          in_string = false;
+          if depth == 1 && !after_colon {
+            key = text[string_start + 1..index].to_string();
+            keys.push(key.clone());
+          }
+        }
+        _ => {}
+      }
+      continue;
+    }
+
+    match c {
+      '"' => {
+        in_string = true;
+        string_start = index;
+      }
+      '{' | '[' => depth += 1,
+      '}' | ']' => depth = depth.saturating_sub(1),
+      ':' if depth == 1 => after_colon = true,
+      ',' if depth == 1 => after_colon = false,
+      _ => {}
+    }
+  }
+  if offset >= text.len() {
+    context = snapshot(depth, in_string, after_colon, &key);
+  }
+
+  (context, keys)
+}
+
+fn quote(value: &str, quoted: bool) -> String {
+  if quoted {
+    value.to_string()
+  } else {
+    format!("\"{value}\"")
+  }
+}
+
+/// Field names at the top level, and known values for `type`,
+/// `packageManager` and other fields with a fixed set of values.
+pub fn completion(text: &str, position: Position) -> Vec<CompletionItem> {
+  let index = LineIndex::new(text);
+  let offset = index.offset(position);
+  let (context, keys) = scan(text, offset);
+
+  match context {
+    Some(CompletionContext::Key { quoted }) => {
+      let Some(properties) = SCHEMA.get("properties").and_then(|value| value.as_object()) else {
+        return Vec::new();
+      };
+      properties
+        .iter()
+        .filter(|(name, _)| !keys.contains(name))
+        .map(|(name, property)| CompletionItem {
+          label: name.clone(),
+          kind: Some(CompletionItemKind::PROPERTY),
+          detail: property
+            .get("type")
+            .and_then(|kind| kind.as_str())
+            .map(str::to_string),
+          documentation: schema_description(property)
+            .map(|description| Documentation::String(description.to_string())),
+          insert_text: Some(if quoted {
+            name.clone()
+          } else {
+            format!("\"{name}\": ")
+          }),
+          ..Default::default()
+        })
+        .collect()
+    }
+    Some(CompletionContext::Value { key, quoted }) => {
+      let values = if key == "packageManager" {
+        PACKAGE_MANAGERS
+          .iter()
+          .map(|manager| format!("{manager}@"))
+          .collect()
+      } else {
+        schema_property(&key)
+          .and_then(|property| property.get("enum"))
+          .and_then(|values| values.as_array())
+          .map(|values| {
+            values
+              .iter()
+              .filter_map(|value| value.as_str())
+              .map(str::to_string)
+              .collect::<Vec<_>>()
+          })
+          .unwrap_or_default()
+      };
+
+      values
+        .into_iter()
+        .map(|value| CompletionItem {
+          insert_text: Some(quote(&value, quoted)),
+          label: value,
+          kind: Some(CompletionItemKind::VALUE),
+          ..Default::default()
+        })
+        .collect()
+    }
+    None => Vec::new(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{CompletionContext, LineIndex, completion, diagnostics, hover, scan};
+  use lsp_types::{DiagnosticSeverity, HoverContents, Position};
+
+  #[test]
+  fn should_convert_offsets_to_utf16_positions() {
+    let text = "{\n  \"description\": \"😀 x\"\n}";
+    let index = LineIndex::new(text);
+    let offset = text.find('x').unwrap();
+
+    assert_eq!(index.position(offset), Position::new(1, 21));
+    assert_eq!(index.offset(Position::new(1, 21)), offset);
+  }
+
+  #[test]
+  fn should_publish_lint_diagnostics_at_labels() {
+    let text = "{\n  \"name\": \"Bad Name\"\n}";
+    let found = diagnostics(text);
+
+    let error = &found[0];
+    assert_eq!(error.severity, Some(DiagnosticSeverity::ERROR));
+    assert_eq!(error.range.start, Position::new(1, 10));
+    assert_eq!(error.range.end, Position::new(1, 20));
+    assert!(
+      error
+        .code_description
+        .as_ref()
+        .is_some_and(|description| description.href.as_str().ends_with("#pkgnameinvalid-chars"))
+    );
+    assert!(
+      found
+        .iter()
+        .any(|diagnostic| diagnostic.severity == Some(DiagnosticSeverity::WARNING))
+    );
+  }
+
+  #[test]
+  fn should_publish_syntax_errors() {
+    let found = diagnostics("{ \"name\": tru }");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].range.start, Position::new(0, 10));
+  }
+
+  #[test]
+  fn should_hover_known_fields() {
+    let text = "{ \"type\": \"module\" }";
+    let hover = hover(text, Position::new(0, 4)).unwrap();
+    let HoverContents::Markup(content) = hover.contents else {
+      panic!("expected markup");
+    };
+    assert!(content.value.starts_with("**type**"));
+    assert!(super::hover(text, Position::new(0, 12)).is_none());
+  }
+
+  #[test]
+  fn should_detect_completion_context() {
+    let text = r#"{ "name": "x", "ty": "mo", "nested": { "a": 1 } }"#;
+    let at = |needle: &str| text.find(needle).unwrap() + needle.len();
+
+    assert_eq!(
+      scan(text, at("\"ty")).0,
+      Some(CompletionContext::Key { quoted: true })
+    );
+    assert_eq!(
+      scan(text, at("\"mo")).0,
+      Some(CompletionContext::Value {
+        key: "ty".to_string(),
+        quoted: true
+      })
+    );
+    assert_eq!(scan(text, at("{ \"a")).0, None);
+    assert_eq!(scan(text, 0).1, ["name", "ty", "nested"]);
+  }
+
+  #[test]
+  fn should_complete_field_names_and_values() {
+    let text = "{\n  \"name\": \"x\",\n  \"\n}";
+    let items = completion(text, Position::new(2, 3));
+    assert!(items.iter().any(|item| item.label == "version"));
+    assert!(!items.iter().any(|item| item.label == "name"));
+
+    let text = "{ \"type\": \"";
+    let items = completion(text, Position::new(0, 11));
+    let labels = items
+      .iter()
+      .map(|item| item.label.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(labels, ["commonjs", "module"]);
+
+    let text = "{ \"packageManager\": ";
+    let items = completion(text, Position::new(0, 20));
+    assert_eq!(items[1].label, "pnpm@");
+    assert_eq!(items[1].insert_text.as_deref(), Some("\"pnpm@\""));
+  }
+}
@@ -0,0 +1,160 @@
+//! A language server for package.json, enabled by the `lsp` feature.
+//!
+//! It publishes the same diagnostics as [`PackageJsonParser::lint`](crate::PackageJsonParser::lint),
+//! shows `schema.json` descriptions on hover and completes field names and
+//! known values.
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+  DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+  Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, HoverRequest, Request as LspRequest};
+use lsp_types::{
+  CompletionOptions, CompletionParams, CompletionResponse, HoverParams, HoverProviderCapability,
+  PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+  Uri,
+};
+use rustc_hash::FxHashMap;
+
+mod analysis;
+
+pub use analysis::{completion, diagnostics, hover};
+
+pub type LspResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+fn capabilities() -> ServerCapabilities {
+  ServerCapabilities {
+    text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+    hover_provider: Some(HoverProviderCapability::Simple(true)),
+    completion_provider: Some(CompletionOptions {
+      trigger_characters: Some(vec!["\"".to_string()]),
+      ..Default::default()
+    }),
+    ..Default::default()
+  }
+}
+
+/// Runs the server over stdin and stdout until the client shuts it down.
+pub fn run_stdio() -> LspResult<()> {
+  let (connection, io_threads) = Connection::stdio();
+  connection.initialize(serde_json::to_value(capabilities())?)?;
+  Server::default().run(&connection)?;
+  io_threads.join()?;
+  Ok(())
+}
+
+#[derive(Default)]
+struct Server {
+  documents: FxHashMap<Uri, String>,
+}
+
+impl Server {
+  fn run(&mut self, connection: &Connection) -> LspResult<()> {
+    for message in &connection.receiver {
+      match message {
+        Message::Request(request) => {
+          if connection.handle_shutdown(&request)? {
+            return Ok(());
+          }
+          let response = self.handle_request(request);
+          connection.sender.send(Message::Response(response))?;
+        }
+        Message::Notification(notification) => {
+          if let Some(uri) = self.handle_notification(notification) {
+            let text = self.documents.get(&uri).map(String::as_str);
+            let params = PublishDiagnosticsParams {
+              diagnostics: text.map(diagnostics).unwrap_or_default(),
+              uri,
+              version: None,
+            };
+            connection
+              .sender
+              .send(Message::Notification(Notification::new(
+                PublishDiagnostics::METHOD.to_string(),
+                params,
+              )))?;
+          }
+        }
+        Message::Response(_) => {}
+      }
+    }
+    Ok(())
+  }
+
+  fn handle_request(&self, request: Request) -> Response {
+    let id = request.id.clone();
+    let result = match request.method.as_str() {
+      HoverRequest::METHOD => {
+        request
+          .extract::<HoverParams>(HoverRequest::METHOD)
+          .map(|(_, params)| {
+            let position = params.text_document_position_params;
+            let result = self
+              .documents
+              .get(&position.text_document.uri)
+              .and_then(|text| hover(text, position.position));
+            serde_json::to_value(result)
+          })
+      }
+      Completion::METHOD => {
+        request
+          .extract::<CompletionParams>(Completion::METHOD)
+          .map(|(_, params)| {
+            let position = params.text_document_position;
+            let items = self
+              .documents
+              .get(&position.text_document.uri)
+              .map(|text| completion(text, position.position))
+              .unwrap_or_default();
+            serde_json::to_value(CompletionResponse::Array(items))
+          })
+      }
+      method => {
+        return Response::new_err(
+          id,
+          ErrorCode::MethodNotFound as i32,
+          format!("Unsupported request `{method}`"),
+        );
+      }
+    };
+
+    match result {
+      Ok(Ok(value)) => Response::new_ok(id, value),
+      Ok(Err(error)) => Response::new_err(id, ErrorCode::InternalError as i32, error.to_string()),
+      Err(error) => Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string()),
+    }
+  }
+
+  /// Updates the open documents and returns the one whose diagnostics
+  /// changed.
+  fn handle_notification(&mut self, notification: Notification) -> Option<Uri> {
+    match notification.method.as_str() {
+      DidOpenTextDocument::METHOD => {
+        let params = extract::<DidOpenTextDocument>(notification)?;
+        let document = params.text_document;
+        self.documents.insert(document.uri.clone(), document.text);
+        Some(document.uri)
+      }
+      DidChangeTextDocument::METHOD => {
+        let mut params = extract::<DidChangeTextDocument>(notification)?;
+        // Full sync: the last change holds the whole document.
+        let text = params.content_changes.pop()?.text;
+        let uri = params.text_document.uri;
+        self.documents.insert(uri.clone(), text);
+        Some(uri)
+      }
+      DidCloseTextDocument::METHOD => {
+        let params = extract::<DidCloseTextDocument>(notification)?;
+        let uri = params.text_document.uri;
+        self.documents.remove(&uri);
+        Some(uri)
+      }
+      _ => None,
+    }
+  }
+}
+
+fn extract<N: LspNotification>(notification: Notification) -> Option<N::Params> {
+  notification.extract(N::METHOD).ok()
+}
@@ -0,0 +1,29 @@
+/// How [`crate::PackageJsonParser`] reads its input.
+///
+/// The default is plain JSON, exactly what npm accepts. Set `jsonc` to also
+/// accept comments and trailing commas, as tsconfig-style tooling does; with
+/// `strict` on as well, each of them is still accepted but reported through
+/// [`crate::PackageJsonParser::warnings`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+  pub jsonc: bool,
+  pub strict: bool,
+}
+
+impl ParseOptions {
+  /// Comments and trailing commas are accepted silently.
+  pub fn jsonc() -> Self {
+    Self {
+      jsonc: true,
+      strict: false,
+    }
+  }
+
+  /// Comments and trailing commas are accepted and reported as warnings.
+  pub fn jsonc_strict() -> Self {
+    Self {
+      jsonc: true,
+      strict: true,
+    }
+  }
+}
@@ -0,0 +1,432 @@
+//! Node-compatible `exports` / `imports` resolution.
+//!
+//! Follows `PACKAGE_EXPORTS_RESOLVE` and `PACKAGE_IMPORTS_RESOLVE` from the
+//! Node.js ESM resolver specification. Resolved targets are returned as the
+//! package-relative path written in `package.json` (e.g. `./dist/index.js`),
+//! or as a bare specifier when an `imports` entry maps to another package.
+
+use std::cmp::Ordering;
+
+use indexmap::IndexMap;
+
+use crate::def::{Exports, ExportsTarget, has_invalid_segment, is_url};
+use crate::err::ResolveError;
+
+/// Outcome of resolving a single target, mirroring the spec's
+/// distinction between `null` (explicitly excluded) and `undefined`
+/// (no condition matched).
+enum TargetResolution {
+  Resolved(String),
+  Excluded,
+  Unmatched,
+}
+
+impl Exports {
+  /// Resolves a package subpath (`.` or `./sub/path`) under the given conditions.
+  pub fn resolve(&self, subpath: &str, conditions: &[&str]) -> Result<String, ResolveError> {
+    let not_exported = || ResolveError::PackagePathNotExported {
+      subpath: subpath.to_string(),
+    };
+
+    let resolution = match self {
+      Exports::Subpaths(subpaths) => {
+        if subpaths.keys().any(|key| !key.starts_with('.')) {
+          return Err(ResolveError::InvalidPackageConfiguration {
+            reason: "\"exports\" cannot mix subpath keys and condition keys".to_string(),
+          });
+        }
+
+        if subpath == "." {
+          match subpaths.get(".") {
+            Some(target) => resolve_target(".", target, None, false, conditions)?,
+            None => TargetResolution::Unmatched,
+          }
+        } else {
+          resolve_imports_exports(subpath, subpaths, false, conditions)?
+        }
+      }
+      _ if subpath != "." => TargetResolution::Unmatched,
+      Exports::Null => TargetResolution::Excluded,
+      Exports::Path(path) => resolve_target(
+        ".",
+        &ExportsTarget::Path(path.clone()),
+        None,
+        false,
+        conditions,
+      )?,
+      Exports::Fallback(items) => resolve_target(
+        ".",
+        &ExportsTarget::Fallback(items.clone()),
+        None,
+        false,
+        conditions,
+      )?,
+      Exports::Conditions(map) => resolve_target(
+        ".",
+        &ExportsTarget::Conditions(map.clone()),
+        None,
+        false,
+        conditions,
+      )?,
+    };
+
+    match resolution {
+      TargetResolution::Resolved(resolved) => Ok(resolved),
+      TargetResolution::Excluded | TargetResolution::Unmatched => Err(not_exported()),
+    }
+  }
+}
+
+/// Resolves a `#`-prefixed specifier against an `imports` map.
+pub(crate) fn resolve_imports(
+  imports: Option<&IndexMap<String, ExportsTarget>>,
+  specifier: &str,
+  conditions: &[&str],
+) -> Result<String, ResolveError> {
+  if !specifier.starts_with('#') {
+    return Err(ResolveError::InvalidModuleSpecifier {
+      specifier: specifier.to_string(),
+      reason: "import specifiers must start with `#`".to_string(),
+    });
+  }
+
+  if specifier == "#" || specifier.starts_with("#/") {
+    return Err(ResolveError::InvalidModuleSpecifier {
+      specifier: specifier.to_string(),
+      reason: "`#` and `#/` are reserved".to_string(),
+    });
+  }
+
+  if specifier.ends_with('/') {
+    return Err(ResolveError::InvalidModuleSpecifier {
+      specifier: specifier.to_string(),
+      reason: "import specifiers must not end with `/`".to_string(),
+    });
+  }
+
+  if let Some(imports) = imports {
+    if let TargetResolution::Resolved(resolved) =
+      resolve_imports_exports(specifier, imports, true, conditions)?
+    {
+      return Ok(resolved);
+    }
+  }
+
+  Err(ResolveError::PackageImportNotDefined {
+    specifier: specifier.to_string(),
+  })
+}
+
+/// `PACKAGE_IMPORTS_EXPORTS_RESOLVE`: exact key match first, then `*` patterns
+/// ordered by specificity.
+fn resolve_imports_exports(
+  match_key: &str,
+  match_obj: &IndexMap<String, ExportsTarget>,
+  is_imports: bool,
+  conditions: &[&str],
+) -> Result<TargetResolution, ResolveError> {
+  if !match_key.contains('*') {
+    if let Some(target) = match_obj.get(match_key) {
+      return resolve_target(match_key, target, None, is_imports, conditions);
+    }
+  }
+
+  let mut expansion_keys = match_obj
+    .keys()
+    .filter(|key| key.matches('*').count() == 1)
+    .collect::<Vec<_>>();
+  expansion_keys.sort_by(|a, b| pattern_key_compare(a, b));
+
+  for expansion_key in expansion_keys {
+    let Some((pattern_base, pattern_trailer)) = expansion_key.split_once('*') else {
+      continue;
+    };
+
+    if !match_key.starts_with(pattern_base) || match_key == pattern_base {
+      continue;
+    }
+
+    if pattern_trailer.is_empty()
+      || (match_key.ends_with(pattern_trailer) && match_key.len() >= expansion_key.len())
+    {
+      let pattern_match = &match_key[pattern_base.len()..match_key.len() - pattern_trailer.len()];
+      return resolve_target(
+        expansion_key,
+        &match_obj[expansion_key.as_str()],
+        Some(pattern_match),
+        is_imports,
+        conditions,
+      );
+    }
+  }
+
+  Ok(TargetResolution::Unmatched)
+}
+
+/// `PATTERN_KEY_COMPARE`: longer base first, then patterns before exact keys,
+/// then longer keys first.
+fn pattern_key_compare(key_a: &str, key_b: &str) -> Ordering {
+  let base_length = |key: &str| key.find('*').map_or(key.len(), |index| index + 1);
+
+  base_length(key_b)
+    .cmp(&base_length(key_a))
+    .then_with(|| match (key_a.contains('*'), key_b.contains('*')) {
+      (false, _) => Ordering::Greater,
+      (_, false) => Ordering::Less,
+      _ => Ordering::Equal,
+    })
+    .then_with(|| key_b.len().cmp(&key_a.len()))
+}
+
+fn substitute_pattern(target: &str, pattern_match: &str) -> String {
+  target.split('*').collect::<Vec<_>>().join(pattern_match)
+}
+
+/// `PACKAGE_TARGET_RESOLVE`.
+fn resolve_target(
+  key: &str,
+  target: &ExportsTarget,
+  pattern_match: Option<&str>,
+  is_imports: bool,
+  conditions: &[&str],
+) -> Result<TargetResolution, ResolveError> {
+  let invalid_target = |target: &str| ResolveError::InvalidPackageTarget {
+    key: key.to_string(),
+    target: target.to_string(),
+  };
+
+  match target {
+    ExportsTarget::Path(path) => {
+      let Some(rest) = path.strip_prefix("./") else {
+        if !is_imports || path.starts_with("../") || path.starts_with('/') || is_url(path) {
+          return Err(invalid_target(path));
+        }
+
+        let specifier = match pattern_match {
+          Some(pattern_match) => substitute_pattern(path, pattern_match),
+          None => path.clone(),
+        };
+        return Ok(TargetResolution::Resolved(specifier));
+      };
+
+      if has_invalid_segment(rest) {
+        return Err(invalid_target(path));
+      }
+
+      let Some(pattern_match) = pattern_match else {
+        return Ok(TargetResolution::Resolved(path.clone()));
+      };
+
+      if has_invalid_segment(pattern_match) {
+        return Err(ResolveError::InvalidModuleSpecifier {
+          specifier: substitute_pattern(key, pattern_match),
+          reason: format!("pattern match `{pattern_match}` contains an invalid segment"),
+        });
+      }
+
+      Ok(TargetResolution::Resolved(substitute_pattern(
+        path,
+        pattern_match,
+      )))
+    }
+    ExportsTarget::Conditions(map) => {
+      if map.keys().any(|condition| condition.parse::<u32>().is_ok()) {
+        return Err(ResolveError::InvalidPackageConfiguration {
+          reason: format!("conditions for `{key}` must not contain numeric keys"),
+        });
+      }
+
+      for (condition, value) in map {
+        if condition != "default" && !conditions.contains(&condition.as_str()) {
+          continue;
+        }
+
+        match resolve_target(key, value, pattern_match, is_imports, conditions)? {
+          TargetResolution::Unmatched => continue,
+          resolution => return Ok(resolution),
+        }
+      }
+
+      Ok(TargetResolution::Unmatched)
+    }
+    ExportsTarget::Fallback(items) => {
+      if items.is_empty() {
+        return Ok(TargetResolution::Excluded);
+      }
+
+      let mut last = Ok(TargetResolution::Excluded);
+      for item in items {
+        match resolve_target(key, item, pattern_match, is_imports, conditions) {
+          Err(error @ ResolveError::InvalidPackageTarget { .. }) => last = Err(error),
+          Ok(TargetResolution::Unmatched) => last = Ok(TargetResolution::Unmatched),
+          resolution => return resolution,
+        }
+      }
+
+      last
+    }
+    ExportsTarget::Null => Ok(TargetResolution::Excluded),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{PackageJsonParser, ResolveError};
+
+  const CONDITIONS: &[&str] = &["import", "node", "default"];
+
+  #[test]
+  fn should_resolve_main_export() {
+    let jsones = [
+      r#"{"name":"pkg","exports":"./index.js"}"#,
+      r#"{"name":"pkg","exports":{"require":"./index.cjs","import":"./index.js"}}"#,
+      r#"{"name":"pkg","exports":{".":["invalid", "./index.js"]}}"#,
+      r#"{"name":"pkg","exports":{".":{"types":"./index.d.ts","node":{"import":"./index.js"}}}}"#,
+    ];
+
+    for json in jsones {
+      let parser = PackageJsonParser::parse_str(json).unwrap();
+      assert_eq!(
+        parser.resolve_export("pkg", CONDITIONS).unwrap(),
+        "./index.js"
+      );
+      assert_eq!(
+        parser.resolve_export(".", CONDITIONS).unwrap(),
+        "./index.js"
+      );
+    }
+  }
+
+  #[test]
+  fn should_resolve_subpath_patterns() {
+    let parser = PackageJsonParser::parse_str(
+      r#"{
+        "name": "@scope/pkg",
+        "exports": {
+          "./features/*.js": "./src/features/*.js",
+          "./features/internal/*": null,
+          "./utils/*": {"import": "./esm/utils/*.mjs", "require": "./cjs/utils/*.cjs"},
+          "./package.json": "./package.json"
+        }
+      }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      parser
+        .resolve_export("@scope/pkg/features/a/b.js", CONDITIONS)
+        .unwrap(),
+      "./src/features/a/b.js"
+    );
+    assert_eq!(
+      parser
+        .resolve_export("@scope/pkg/utils/path", CONDITIONS)
+        .unwrap(),
+      "./esm/utils/path.mjs"
+    );
+    assert_eq!(
+      parser.resolve_export("./package.json", CONDITIONS).unwrap(),
+      "./package.json"
+    );
+    assert_eq!(
+      parser.resolve_export("@scope/pkg/features/internal/x", CONDITIONS),
+      Err(ResolveError::PackagePathNotExported {
+        subpath: "./features/internal/x".to_string()
+      })
+    );
+    assert!(matches!(
+      parser.resolve_export("@scope/pkg/features/../secret.js", CONDITIONS),
+      Err(ResolveError::InvalidModuleSpecifier { .. })
+    ));
+  }
+
+  #[test]
+  fn should_fail_when_subpath_is_not_exported() {
+    let parser =
+      PackageJsonParser::parse_str(r#"{"name":"pkg","exports":{"require":"./index.cjs"}}"#)
+        .unwrap();
+
+    assert!(matches!(
+      parser.resolve_export("pkg", CONDITIONS),
+      Err(ResolveError::PackagePathNotExported { .. })
+    ));
+    assert!(matches!(
+      parser.resolve_export("pkg/deep", CONDITIONS),
+      Err(ResolveError::PackagePathNotExported { .. })
+    ));
+    assert!(matches!(
+      parser.resolve_export("other", CONDITIONS),
+      Err(ResolveError::InvalidModuleSpecifier { .. })
+    ));
+  }
+
+  #[test]
+  fn should_fail_when_exports_is_missing_or_mixed() {
+    let parser = PackageJsonParser::parse_str(r#"{"name":"pkg"}"#).unwrap();
+    assert_eq!(
+      parser.resolve_export("pkg", CONDITIONS),
+      Err(ResolveError::ExportsNotDefined)
+    );
+
+    let parser =
+      PackageJsonParser::parse_str(r#"{"exports":{".":"./a.js","import":"./b.js"}}"#).unwrap();
+    assert!(matches!(
+      parser.resolve_export(".", CONDITIONS),
+      Err(ResolveError::InvalidPackageConfiguration { .. })
+    ));
+  }
+
+  #[test]
+  fn should_resolve_imports() {
+    let parser = PackageJsonParser::parse_str(
+      r##"{
+        "imports": {
+          "#dep": {"node": "dep-node-native", "default": "./dep-polyfill.js"},
+          "#internal/*.js": "./src/internal/*.js",
+          "#dir/*": "./src/dir/*",
+          "#bad": "../outside.js"
+        }
+      }"##,
+    )
+    .unwrap();
+
+    assert_eq!(
+      parser.resolve_import("#dep", CONDITIONS).unwrap(),
+      "dep-node-native"
+    );
+    assert_eq!(
+      parser.resolve_import("#dep", &["default"]).unwrap(),
+      "./dep-polyfill.js"
+    );
+    assert_eq!(
+      parser.resolve_import("#internal/a.js", CONDITIONS).unwrap(),
+      "./src/internal/a.js"
+    );
+    assert!(matches!(
+      parser.resolve_import("#bad", CONDITIONS),
+      Err(ResolveError::InvalidPackageTarget { .. })
+    ));
+    assert!(matches!(
+      parser.resolve_import("#missing", CONDITIONS),
+      Err(ResolveError::PackageImportNotDefined { .. })
+    ));
+    assert!(matches!(
+      parser.resolve_import("#/x", CONDITIONS),
+      Err(ResolveError::InvalidModuleSpecifier { .. })
+    ));
+    assert!(matches!(
+      parser.resolve_import("#dir/", CONDITIONS),
+      Err(ResolveError::InvalidModuleSpecifier { .. })
+    ));
+  }
+
+  #[test]
+  fn should_fail_when_imports_is_not_an_object() {
+    let parser = PackageJsonParser::parse_str(r#"{ "imports": ["./a.js"] }"#).unwrap();
+
+    assert!(matches!(
+      parser.resolve_import("#a", CONDITIONS),
+      Err(ResolveError::InvalidPackageConfiguration { .. })
+    ));
+  }
+}
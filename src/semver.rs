@@ -0,0 +1,604 @@
+//! node-semver compatible version and range parsing.
+//!
+//! Ranges are parsed with npm's loose grammar (`v` prefixes, `=` prefixes and
+//! whitespace after operators are accepted) and desugared into plain
+//! comparator sets, so `^1.2.0 || ~2.1` becomes
+//! `>=1.2.0 <2.0.0-0 || >=2.1.0 <2.2.0-0`.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::Serialize;
+
+/// A prerelease identifier, compared numerically when it is all digits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum Prerelease {
+  Numeric(u64),
+  Alpha(String),
+}
+
+impl Ord for Prerelease {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Prerelease::Numeric(a), Prerelease::Numeric(b)) => a.cmp(b),
+      (Prerelease::Numeric(_), Prerelease::Alpha(_)) => Ordering::Less,
+      (Prerelease::Alpha(_), Prerelease::Numeric(_)) => Ordering::Greater,
+      (Prerelease::Alpha(a), Prerelease::Alpha(b)) => a.cmp(b),
+    }
+  }
+}
+
+impl PartialOrd for Prerelease {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl fmt::Display for Prerelease {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Prerelease::Numeric(value) => write!(f, "{value}"),
+      Prerelease::Alpha(value) => f.write_str(value),
+    }
+  }
+}
+
+/// A full `major.minor.patch[-pre][+build]` version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SemVersion {
+  pub major: u64,
+  pub minor: u64,
+  pub patch: u64,
+  pub pre: Vec<Prerelease>,
+  pub build: Vec<String>,
+}
+
+impl SemVersion {
+  pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+    Self {
+      major,
+      minor,
+      patch,
+      pre: Vec::new(),
+      build: Vec::new(),
+    }
+  }
+
+  /// Parses a version, accepting a leading `v` or `=` like node-semver's loose mode.
+  pub fn parse(input: &str) -> Result<Self, String> {
+    let partial = PartialVersion::parse(input.trim())?;
+    match (partial.major, partial.minor, partial.patch) {
+      (Some(major), Some(minor), Some(patch)) => Ok(Self {
+        major,
+        minor,
+        patch,
+        pre: partial.pre,
+        build: partial.build,
+      }),
+      _ => Err(format!("`{input}` is not a full version")),
+    }
+  }
+
+  fn with_zero_pre(mut self) -> Self {
+    self.pre = vec![Prerelease::Numeric(0)];
+    self
+  }
+}
+
+impl Ord for SemVersion {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self
+      .major
+      .cmp(&other.major)
+      .then(self.minor.cmp(&other.minor))
+      .then(self.patch.cmp(&other.patch))
+      .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => self.pre.cmp(&other.pre),
+      })
+  }
+}
+
+impl PartialOrd for SemVersion {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl fmt::Display for SemVersion {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+    if !self.pre.is_empty() {
+      let pre = self.pre.iter().map(ToString::to_string).collect::<Vec<_>>();
+      write!(f, "-{}", pre.join("."))?;
+    }
+    if !self.build.is_empty() {
+      write!(f, "+{}", self.build.join("."))?;
+    }
+    Ok(())
+  }
+}
+
+/// A version with optional `x`/`*` wildcard components, e.g. `1.2.x` or `1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PartialVersion {
+  major: Option<u64>,
+  minor: Option<u64>,
+  patch: Option<u64>,
+  pre: Vec<Prerelease>,
+  build: Vec<String>,
+}
+
+impl PartialVersion {
+  fn parse(input: &str) -> Result<Self, String> {
+    let input = input.trim_start_matches('=');
+    let input = input.strip_prefix(['v', 'V']).unwrap_or(input);
+
+    if input.is_empty() {
+      return Err("missing version".to_string());
+    }
+
+    let (input, build) = match input.split_once('+') {
+      Some((rest, build)) => (rest, parse_identifiers(build, "build")?),
+      None => (input, Vec::new()),
+    };
+
+    let (core, pre) = match input.split_once('-') {
+      Some((core, pre)) => (core, parse_identifiers(pre, "prerelease")?),
+      None => (input, Vec::new()),
+    };
+
+    let mut parts = core.split('.');
+    let major = parse_component(parts.next())?;
+    let minor = parse_component(parts.next())?;
+    let patch = parse_component(parts.next())?;
+
+    if parts.next().is_some() {
+      return Err(format!("`{core}` has more than three version components"));
+    }
+
+    let components = [major, minor, patch];
+    if components
+      .iter()
+      .any(|part| matches!(part, Component::Missing))
+      && (!pre.is_empty() || !build.is_empty())
+    {
+      return Err("prerelease and build metadata require a full version".to_string());
+    }
+
+    let mut wildcard_seen = false;
+    let mut values = [None; 3];
+    for (index, component) in components.into_iter().enumerate() {
+      match component {
+        Component::Number(value) if !wildcard_seen => values[index] = Some(value),
+        Component::Number(_) => {
+          return Err("version numbers cannot follow a wildcard".to_string());
+        }
+        Component::Wildcard | Component::Missing => wildcard_seen = true,
+      }
+    }
+
+    Ok(Self {
+      major: values[0],
+      minor: values[1],
+      patch: values[2],
+      pre: if wildcard_seen { Vec::new() } else { pre },
+      build,
+    })
+  }
+
+  fn is_any(&self) -> bool {
+    self.major.is_none()
+  }
+
+  /// Lowest version matched by this partial, filling wildcards with zero.
+  fn floor(&self) -> SemVersion {
+    SemVersion {
+      major: self.major.unwrap_or(0),
+      minor: self.minor.unwrap_or(0),
+      patch: self.patch.unwrap_or(0),
+      pre: self.pre.clone(),
+      build: Vec::new(),
+    }
+  }
+
+  /// First version above everything matched by this partial, as `X.Y.Z-0`.
+  fn ceiling(&self) -> Option<SemVersion> {
+    match (self.major, self.minor, self.patch) {
+      (None, _, _) => None,
+      (Some(major), None, _) => Some(SemVersion::new(major + 1, 0, 0).with_zero_pre()),
+      (Some(major), Some(minor), None) => {
+        Some(SemVersion::new(major, minor + 1, 0).with_zero_pre())
+      }
+      (Some(_), Some(_), Some(_)) => None,
+    }
+  }
+}
+
+/// node-semver rejects version numbers above JavaScript's
+/// `Number.MAX_SAFE_INTEGER`, which also keeps ceilings from overflowing.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+enum Component {
+  Number(u64),
+  Wildcard,
+  Missing,
+}
+
+fn parse_component(part: Option<&str>) -> Result<Component, String> {
+  let Some(part) = part else {
+    return Ok(Component::Missing);
+  };
+
+  if matches!(part, "x" | "X" | "*") {
+    return Ok(Component::Wildcard);
+  }
+
+  if part.is_empty() || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+    return Err(format!("`{part}` is not a valid version number"));
+  }
+
+  if part.len() > 1 && part.starts_with('0') {
+    return Err(format!("`{part}` must not have leading zeros"));
+  }
+
+  match part.parse::<u64>() {
+    Ok(number) if number <= MAX_SAFE_INTEGER => Ok(Component::Number(number)),
+    _ => Err(format!("`{part}` is larger than {MAX_SAFE_INTEGER}")),
+  }
+}
+
+fn parse_identifiers<T: From<String>>(input: &str, kind: &str) -> Result<Vec<T>, String> {
+  input
+    .split('.')
+    .map(|identifier| {
+      let valid = !identifier.is_empty()
+        && identifier
+          .bytes()
+          .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-');
+      if valid {
+        Ok(T::from(identifier.to_string()))
+      } else {
+        Err(format!("`{input}` is not a valid {kind} identifier list"))
+      }
+    })
+    .collect()
+}
+
+impl From<String> for Prerelease {
+  fn from(value: String) -> Self {
+    let numeric = value.bytes().all(|byte| byte.is_ascii_digit());
+    match value.parse::<u64>() {
+      Ok(number) if numeric => Prerelease::Numeric(number),
+      _ => Prerelease::Alpha(value),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Operator {
+  Eq,
+  Lt,
+  Lte,
+  Gt,
+  Gte,
+}
+
+/// A primitive comparator such as `>=1.2.0`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Comparator {
+  pub operator: Operator,
+  pub version: SemVersion,
+}
+
+impl Comparator {
+  fn new(operator: Operator, version: SemVersion) -> Self {
+    Self { operator, version }
+  }
+
+  fn matches(&self, version: &SemVersion) -> bool {
+    let ordering = version.cmp(&self.version);
+    match self.operator {
+      Operator::Eq => ordering == Ordering::Equal,
+      Operator::Lt => ordering == Ordering::Less,
+      Operator::Lte => ordering != Ordering::Greater,
+      Operator::Gt => ordering == Ordering::Greater,
+      Operator::Gte => ordering != Ordering::Less,
+    }
+  }
+}
+
+/// A node-semver range: a union (`||`) of comparator intersections.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionRange {
+  raw: String,
+  set: Vec<Vec<Comparator>>,
+}
+
+impl VersionRange {
+  pub fn parse(input: &str) -> Result<Self, String> {
+    let set = input
+      .split("||")
+      .map(|range| parse_range(range.trim()))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Self {
+      raw: input.to_string(),
+      set,
+    })
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.raw
+  }
+
+  pub fn comparator_sets(&self) -> &[Vec<Comparator>] {
+    &self.set
+  }
+
+  /// Whether `version` falls inside the range. Prereleases only match
+  /// comparator sets that mention a prerelease of the same `major.minor.patch`.
+  pub fn satisfies(&self, version: &SemVersion) -> bool {
+    self.set.iter().any(|comparators| {
+      if !comparators
+        .iter()
+        .all(|comparator| comparator.matches(version))
+      {
+        return false;
+      }
+
+      if version.pre.is_empty() {
+        return true;
+      }
+
+      comparators.iter().any(|comparator| {
+        !comparator.version.pre.is_empty()
+          && comparator.version.major == version.major
+          && comparator.version.minor == version.minor
+          && comparator.version.patch == version.patch
+      })
+    })
+  }
+}
+
+impl fmt::Display for VersionRange {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.raw)
+  }
+}
+
+const OPERATORS: &[&str] = &[">=", "<=", "~>", ">", "<", "=", "~", "^"];
+
+fn parse_range(range: &str) -> Result<Vec<Comparator>, String> {
+  if range.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  if let Some((from, to)) = range.split_once(" - ") {
+    return parse_hyphen(from.trim(), to.trim());
+  }
+
+  let mut tokens = Vec::new();
+  let mut pending_operator: Option<&str> = None;
+  for token in range.split_whitespace() {
+    if let Some(operator) = pending_operator.take() {
+      tokens.push(format!("{operator}{token}"));
+    } else if OPERATORS.contains(&token) {
+      pending_operator = Some(token);
+    } else {
+      tokens.push(token.to_string());
+    }
+  }
+
+  if let Some(operator) = pending_operator {
+    return Err(format!("operator `{operator}` is missing a version"));
+  }
+
+  let mut comparators = Vec::new();
+  for token in tokens {
+    comparators.extend(parse_simple(&token)?);
+  }
+  Ok(comparators)
+}
+
+fn parse_hyphen(from: &str, to: &str) -> Result<Vec<Comparator>, String> {
+  let from = PartialVersion::parse(from)?;
+  let to = PartialVersion::parse(to)?;
+  let mut comparators = Vec::new();
+
+  if !from.is_any() {
+    comparators.push(Comparator::new(Operator::Gte, from.floor()));
+  }
+
+  if !to.is_any() {
+    match to.ceiling() {
+      Some(ceiling) => comparators.push(Comparator::new(Operator::Lt, ceiling)),
+      None => comparators.push(Comparator::new(Operator::Lte, to.floor())),
+    }
+  }
+
+  Ok(comparators)
+}
+
+fn parse_simple(token: &str) -> Result<Vec<Comparator>, String> {
+  let operator = OPERATORS
+    .iter()
+    .find(|operator| token.starts_with(**operator))
+    .copied()
+    .unwrap_or("");
+  let partial = PartialVersion::parse(&token[operator.len()..])?;
+
+  let comparators = match operator {
+    "~" | "~>" => tilde(&partial),
+    "^" => caret(&partial),
+    "" | "=" => x_range(&partial),
+    ">" => match partial.ceiling() {
+      _ if partial.is_any() => vec![Comparator::new(
+        Operator::Lt,
+        SemVersion::new(0, 0, 0).with_zero_pre(),
+      )],
+      Some(ceiling) => vec![Comparator::new(Operator::Gte, ceiling_without_pre(ceiling))],
+      None => vec![Comparator::new(Operator::Gt, partial.floor())],
+    },
+    ">=" => match partial.is_any() {
+      true => Vec::new(),
+      false => vec![Comparator::new(Operator::Gte, partial.floor())],
+    },
+    "<" => match partial.is_any() {
+      true => vec![Comparator::new(
+        Operator::Lt,
+        SemVersion::new(0, 0, 0).with_zero_pre(),
+      )],
+      false if partial.ceiling().is_some() => vec![Comparator::new(
+        Operator::Lt,
+        partial.floor().with_zero_pre(),
+      )],
+      false => vec![Comparator::new(Operator::Lt, partial.floor())],
+    },
+    "<=" => match partial.ceiling() {
+      _ if partial.is_any() => Vec::new(),
+      Some(ceiling) => vec![Comparator::new(Operator::Lt, ceiling)],
+      None => vec![Comparator::new(Operator::Lte, partial.floor())],
+    },
+    _ => return Err(format!("unknown operator in `{token}`")),
+  };
+
+  Ok(comparators)
+}
+
+fn ceiling_without_pre(mut version: SemVersion) -> SemVersion {
+  version.pre.clear();
+  version
+}
+
+fn x_range(partial: &PartialVersion) -> Vec<Comparator> {
+  if partial.is_any() {
+    return Vec::new();
+  }
+
+  match partial.ceiling() {
+    Some(ceiling) => vec![
+      Comparator::new(Operator::Gte, partial.floor()),
+      Comparator::new(Operator::Lt, ceiling),
+    ],
+    None => vec![Comparator::new(Operator::Eq, partial.floor())],
+  }
+}
+
+fn tilde(partial: &PartialVersion) -> Vec<Comparator> {
+  let floor = partial.floor();
+  let ceiling = match (partial.major, partial.minor) {
+    (None, _) => return Vec::new(),
+    (Some(major), None) => SemVersion::new(major + 1, 0, 0),
+    (Some(major), Some(minor)) => SemVersion::new(major, minor + 1, 0),
+  };
+
+  vec![
+    Comparator::new(Operator::Gte, floor),
+    Comparator::new(Operator::Lt, ceiling.with_zero_pre()),
+  ]
+}
+
+fn caret(partial: &PartialVersion) -> Vec<Comparator> {
+  let floor = partial.floor();
+  let ceiling = match (partial.major, partial.minor, partial.patch) {
+    (None, _, _) => return Vec::new(),
+    (Some(major), None, _) => SemVersion::new(major + 1, 0, 0),
+    (Some(0), Some(minor), None) => SemVersion::new(0, minor + 1, 0),
+    (Some(major), Some(_), None) => SemVersion::new(major + 1, 0, 0),
+    (Some(0), Some(0), Some(patch)) => SemVersion::new(0, 0, patch + 1),
+    (Some(0), Some(minor), Some(_)) => SemVersion::new(0, minor + 1, 0),
+    (Some(major), Some(_), Some(_)) => SemVersion::new(major + 1, 0, 0),
+  };
+
+  vec![
+    Comparator::new(Operator::Gte, floor),
+    Comparator::new(Operator::Lt, ceiling.with_zero_pre()),
+  ]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{SemVersion, VersionRange};
+
+  fn satisfies(range: &str, version: &str) -> bool {
+    VersionRange::parse(range)
+      .unwrap()
+      .satisfies(&SemVersion::parse(version).unwrap())
+  }
+
+  #[test]
+  fn should_parse_valid_ranges() {
+    let ranges = [
+      "",
+      "*",
+      "1",
+      "1.x",
+      "1.2.X",
+      "^1.2.3",
+      "~1.2",
+      "~> 1.2",
+      ">= 1.2.3 < 2",
+      "1.2.3 - 2.3",
+      "^1.0.0 || ^2.0.0-beta.1",
+      "v1.2.3",
+      "=1.2.3+build.5",
+    ];
+
+    for range in ranges {
+      assert!(VersionRange::parse(range).is_ok(), "{range}");
+    }
+  }
+
+  #[test]
+  fn should_reject_invalid_ranges() {
+    let ranges = [
+      "^1.0.0.", "1.2.3.4", "01.2.3", ">=", "1.x.3", "^1.0.0-", "abc",
+    ];
+
+    for range in ranges {
+      assert!(VersionRange::parse(range).is_err(), "{range}");
+    }
+  }
+
+  #[test]
+  fn should_reject_version_numbers_above_max_safe_integer() {
+    let ranges = [
+      "^18446744073709551615.0.0",
+      "^0.0.18446744073709551615",
+      "~1.18446744073709551615",
+      "18446744073709551615.x",
+      "1.9007199254740992.x",
+      "9007199254740992.0.0",
+    ];
+
+    for range in ranges {
+      assert!(VersionRange::parse(range).is_err(), "{range}");
+    }
+    assert!(VersionRange::parse("^9007199254740991.0.0").is_ok());
+    assert!(SemVersion::parse("1.0.18446744073709551615").is_err());
+  }
+
+  #[test]
+  fn should_match_versions_like_node_semver() {
+    assert!(satisfies("^1.2.3", "1.9.0"));
+    assert!(!satisfies("^1.2.3", "2.0.0"));
+    assert!(satisfies("^0.2.3", "0.2.9"));
+    assert!(!satisfies("^0.2.3", "0.3.0"));
+    assert!(!satisfies("^0.0.3", "0.0.4"));
+    assert!(satisfies("~1.2", "1.2.9"));
+    assert!(!satisfies("~1.2", "1.3.0"));
+    assert!(satisfies("1.2.3 - 2.3", "2.3.9"));
+    assert!(!satisfies("1.2.3 - 2.3", "2.4.0"));
+    assert!(satisfies(">1.2", "1.3.0"));
+    assert!(!satisfies(">1.2", "1.2.9"));
+    assert!(satisfies("<=1.2", "1.2.9"));
+    assert!(satisfies("*", "3.0.0"));
+    assert!(!satisfies("*", "3.0.0-beta.1"));
+    assert!(satisfies("^3.0.0-beta.1", "3.0.0-beta.2"));
+    assert!(!satisfies("^3.0.0-beta.1", "3.1.0-beta.2"));
+    assert!(satisfies("1.x || >=2.5.0", "2.6.0"));
+  }
+}
@@ -0,0 +1,1814 @@
+//! SPDX license list 3.27.0, generated from
+//! <https://github.com/spdx/license-list-data>. Do not edit by hand.
+
+/// Version of the SPDX license list the identifiers are taken from.
+pub const LICENSE_LIST_VERSION: &str = "3.27.0";
+
+/// `(identifier, full name, deprecated)`.
+pub(crate) const LICENSES: &[(&str, &str, bool)] = &[
+  ("0BSD", "BSD Zero Clause License", false),
+  ("3D-Slicer-1.0", "3D Slicer License v1.0", false),
+  ("AAL", "Attribution Assurance License", false),
+  ("ADSL", "Amazon Digital Services License", false),
+  ("AFL-1.1", "Academic Free License v1.1", false),
+  ("AFL-1.2", "Academic Free License v1.2", false),
+  ("AFL-2.0", "Academic Free License v2.0", false),
+  ("AFL-2.1", "Academic Free License v2.1", false),
+  ("AFL-3.0", "Academic Free License v3.0", false),
+  ("AGPL-1.0", "Affero General Public License v1.0", true),
+  (
+    "AGPL-1.0-only",
+    "Affero General Public License v1.0 only",
+    false,
+  ),
+  (
+    "AGPL-1.0-or-later",
+    "Affero General Public License v1.0 or later",
+    false,
+  ),
+  ("AGPL-3.0", "GNU Affero General Public License v3.0", true),
+  (
+    "AGPL-3.0-only",
+    "GNU Affero General Public License v3.0 only",
+    false,
+  ),
+  (
+    "AGPL-3.0-or-later",
+    "GNU Affero General Public License v3.0 or later",
+    false,
+  ),
+  ("AMD-newlib", "AMD newlib License", false),
+  ("AMDPLPA", "AMD's plpa_map.c License", false),
+  ("AML", "Apple MIT License", false),
+  ("AML-glslang", "AML glslang variant License", false),
+  (
+    "AMPAS",
+    "Academy of Motion Picture Arts and Sciences BSD",
+    false,
+  ),
+  ("ANTLR-PD", "ANTLR Software Rights Notice", false),
+  (
+    "ANTLR-PD-fallback",
+    "ANTLR Software Rights Notice with license fallback",
+    false,
+  ),
+  ("APAFML", "Adobe Postscript AFM License", false),
+  ("APL-1.0", "Adaptive Public License 1.0", false),
+  ("APSL-1.0", "Apple Public Source License 1.0", false),
+  ("APSL-1.1", "Apple Public Source License 1.1", false),
+  ("APSL-1.2", "Apple Public Source License 1.2", false),
+  ("APSL-2.0", "Apple Public Source License 2.0", false),
+  (
+    "ASWF-Digital-Assets-1.0",
+    "ASWF Digital Assets License version 1.0",
+    false,
+  ),
+  (
+    "ASWF-Digital-Assets-1.1",
+    "ASWF Digital Assets License 1.1",
+    false,
+  ),
+  ("Abstyles", "Abstyles License", false),
+  ("AdaCore-doc", "AdaCore Doc License", false),
+  (
+    "Adobe-2006",
+    "Adobe Systems Incorporated Source Code License Agreement",
+    false,
+  ),
+  (
+    "Adobe-Display-PostScript",
+    "Adobe Display PostScript License",
+    false,
+  ),
+  ("Adobe-Glyph", "Adobe Glyph List License", false),
+  ("Adobe-Utopia", "Adobe Utopia Font License", false),
+  ("Afmparse", "Afmparse License", false),
+  ("Aladdin", "Aladdin Free Public License", false),
+  ("Apache-1.0", "Apache License 1.0", false),
+  ("Apache-1.1", "Apache License 1.1", false),
+  ("Apache-2.0", "Apache License 2.0", false),
+  ("App-s2p", "App::s2p License", false),
+  ("Arphic-1999", "Arphic Public License", false),
+  ("Artistic-1.0", "Artistic License 1.0", false),
+  ("Artistic-1.0-Perl", "Artistic License 1.0 (Perl)", false),
+  ("Artistic-1.0-cl8", "Artistic License 1.0 w/clause 8", false),
+  ("Artistic-2.0", "Artistic License 2.0", false),
+  ("Artistic-dist", "Artistic License 1.0 (dist)", false),
+  ("Aspell-RU", "Aspell Russian License", false),
+  ("BSD-1-Clause", "BSD 1-Clause License", false),
+  ("BSD-2-Clause", "BSD 2-Clause \"Simplified\" License", false),
+  (
+    "BSD-2-Clause-Darwin",
+    "BSD 2-Clause - Ian Darwin variant",
+    false,
+  ),
+  ("BSD-2-Clause-FreeBSD", "BSD 2-Clause FreeBSD License", true),
+  ("BSD-2-Clause-NetBSD", "BSD 2-Clause NetBSD License", true),
+  (
+    "BSD-2-Clause-Patent",
+    "BSD-2-Clause Plus Patent License",
+    false,
+  ),
+  (
+    "BSD-2-Clause-Views",
+    "BSD 2-Clause with views sentence",
+    false,
+  ),
+  (
+    "BSD-2-Clause-first-lines",
+    "BSD 2-Clause - first lines requirement",
+    false,
+  ),
+  (
+    "BSD-2-Clause-pkgconf-disclaimer",
+    "BSD 2-Clause pkgconf disclaimer variant",
+    false,
+  ),
+  (
+    "BSD-3-Clause",
+    "BSD 3-Clause \"New\" or \"Revised\" License",
+    false,
+  ),
+  ("BSD-3-Clause-Attribution", "BSD with attribution", false),
+  ("BSD-3-Clause-Clear", "BSD 3-Clause Clear License", false),
+  (
+    "BSD-3-Clause-HP",
+    "Hewlett-Packard BSD variant license",
+    false,
+  ),
+  (
+    "BSD-3-Clause-LBNL",
+    "Lawrence Berkeley National Labs BSD variant license",
+    false,
+  ),
+  (
+    "BSD-3-Clause-Modification",
+    "BSD 3-Clause Modification",
+    false,
+  ),
+  (
+    "BSD-3-Clause-No-Military-License",
+    "BSD 3-Clause No Military License",
+    false,
+  ),
+  (
+    "BSD-3-Clause-No-Nuclear-License",
+    "BSD 3-Clause No Nuclear License",
+    false,
+  ),
+  (
+    "BSD-3-Clause-No-Nuclear-License-2014",
+    "BSD 3-Clause No Nuclear License 2014",
+    false,
+  ),
+  (
+    "BSD-3-Clause-No-Nuclear-Warranty",
+    "BSD 3-Clause No Nuclear Warranty",
+    false,
+  ),
+  (
+    "BSD-3-Clause-Open-MPI",
+    "BSD 3-Clause Open MPI variant",
+    false,
+  ),
+  ("BSD-3-Clause-Sun", "BSD 3-Clause Sun Microsystems", false),
+  ("BSD-3-Clause-acpica", "BSD 3-Clause acpica variant", false),
+  ("BSD-3-Clause-flex", "BSD 3-Clause Flex variant", false),
+  (
+    "BSD-4-Clause",
+    "BSD 4-Clause \"Original\" or \"Old\" License",
+    false,
+  ),
+  ("BSD-4-Clause-Shortened", "BSD 4 Clause Shortened", false),
+  (
+    "BSD-4-Clause-UC",
+    "BSD-4-Clause (University of California-Specific)",
+    false,
+  ),
+  ("BSD-4.3RENO", "BSD 4.3 RENO License", false),
+  ("BSD-4.3TAHOE", "BSD 4.3 TAHOE License", false),
+  (
+    "BSD-Advertising-Acknowledgement",
+    "BSD Advertising Acknowledgement License",
+    false,
+  ),
+  (
+    "BSD-Attribution-HPND-disclaimer",
+    "BSD with Attribution and HPND disclaimer",
+    false,
+  ),
+  ("BSD-Inferno-Nettverk", "BSD-Inferno-Nettverk", false),
+  ("BSD-Protection", "BSD Protection License", false),
+  ("BSD-Source-Code", "BSD Source Code Attribution", false),
+  (
+    "BSD-Source-beginning-file",
+    "BSD Source Code Attribution - beginning of file variant",
+    false,
+  ),
+  ("BSD-Systemics", "Systemics BSD variant license", false),
+  (
+    "BSD-Systemics-W3Works",
+    "Systemics W3Works BSD variant license",
+    false,
+  ),
+  ("BSL-1.0", "Boost Software License 1.0", false),
+  ("BUSL-1.1", "Business Source License 1.1", false),
+  ("Baekmuk", "Baekmuk License", false),
+  ("Bahyph", "Bahyph License", false),
+  ("Barr", "Barr License", false),
+  ("Beerware", "Beerware License", false),
+  (
+    "BitTorrent-1.0",
+    "BitTorrent Open Source License v1.0",
+    false,
+  ),
+  (
+    "BitTorrent-1.1",
+    "BitTorrent Open Source License v1.1",
+    false,
+  ),
+  ("Bitstream-Charter", "Bitstream Charter Font License", false),
+  ("Bitstream-Vera", "Bitstream Vera Font License", false),
+  ("BlueOak-1.0.0", "Blue Oak Model License 1.0.0", false),
+  ("Boehm-GC", "Boehm-Demers-Weiser GC License", false),
+  (
+    "Boehm-GC-without-fee",
+    "Boehm-Demers-Weiser GC License (without fee)",
+    false,
+  ),
+  ("Borceux", "Borceux license", false),
+  (
+    "Brian-Gladman-2-Clause",
+    "Brian Gladman 2-Clause License",
+    false,
+  ),
+  (
+    "Brian-Gladman-3-Clause",
+    "Brian Gladman 3-Clause License",
+    false,
+  ),
+  (
+    "C-UDA-1.0",
+    "Computational Use of Data Agreement v1.0",
+    false,
+  ),
+  ("CAL-1.0", "Cryptographic Autonomy License 1.0", false),
+  (
+    "CAL-1.0-Combined-Work-Exception",
+    "Cryptographic Autonomy License 1.0 (Combined Work Exception)",
+    false,
+  ),
+  (
+    "CATOSL-1.1",
+    "Computer Associates Trusted Open Source License 1.1",
+    false,
+  ),
+  (
+    "CC-BY-1.0",
+    "Creative Commons Attribution 1.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-2.0",
+    "Creative Commons Attribution 2.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-2.5",
+    "Creative Commons Attribution 2.5 Generic",
+    false,
+  ),
+  (
+    "CC-BY-2.5-AU",
+    "Creative Commons Attribution 2.5 Australia",
+    false,
+  ),
+  (
+    "CC-BY-3.0",
+    "Creative Commons Attribution 3.0 Unported",
+    false,
+  ),
+  (
+    "CC-BY-3.0-AT",
+    "Creative Commons Attribution 3.0 Austria",
+    false,
+  ),
+  (
+    "CC-BY-3.0-AU",
+    "Creative Commons Attribution 3.0 Australia",
+    false,
+  ),
+  (
+    "CC-BY-3.0-DE",
+    "Creative Commons Attribution 3.0 Germany",
+    false,
+  ),
+  (
+    "CC-BY-3.0-IGO",
+    "Creative Commons Attribution 3.0 IGO",
+    false,
+  ),
+  (
+    "CC-BY-3.0-NL",
+    "Creative Commons Attribution 3.0 Netherlands",
+    false,
+  ),
+  (
+    "CC-BY-3.0-US",
+    "Creative Commons Attribution 3.0 United States",
+    false,
+  ),
+  (
+    "CC-BY-4.0",
+    "Creative Commons Attribution 4.0 International",
+    false,
+  ),
+  (
+    "CC-BY-NC-1.0",
+    "Creative Commons Attribution Non Commercial 1.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-2.0",
+    "Creative Commons Attribution Non Commercial 2.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-2.5",
+    "Creative Commons Attribution Non Commercial 2.5 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-3.0",
+    "Creative Commons Attribution Non Commercial 3.0 Unported",
+    false,
+  ),
+  (
+    "CC-BY-NC-3.0-DE",
+    "Creative Commons Attribution Non Commercial 3.0 Germany",
+    false,
+  ),
+  (
+    "CC-BY-NC-4.0",
+    "Creative Commons Attribution Non Commercial 4.0 International",
+    false,
+  ),
+  (
+    "CC-BY-NC-ND-1.0",
+    "Creative Commons Attribution Non Commercial No Derivatives 1.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-ND-2.0",
+    "Creative Commons Attribution Non Commercial No Derivatives 2.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-ND-2.5",
+    "Creative Commons Attribution Non Commercial No Derivatives 2.5 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-ND-3.0",
+    "Creative Commons Attribution Non Commercial No Derivatives 3.0 Unported",
+    false,
+  ),
+  (
+    "CC-BY-NC-ND-3.0-DE",
+    "Creative Commons Attribution Non Commercial No Derivatives 3.0 Germany",
+    false,
+  ),
+  (
+    "CC-BY-NC-ND-3.0-IGO",
+    "Creative Commons Attribution Non Commercial No Derivatives 3.0 IGO",
+    false,
+  ),
+  (
+    "CC-BY-NC-ND-4.0",
+    "Creative Commons Attribution Non Commercial No Derivatives 4.0 International",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-1.0",
+    "Creative Commons Attribution Non Commercial Share Alike 1.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-2.0",
+    "Creative Commons Attribution Non Commercial Share Alike 2.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-2.0-DE",
+    "Creative Commons Attribution Non Commercial Share Alike 2.0 Germany",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-2.0-FR",
+    "Creative Commons Attribution-NonCommercial-ShareAlike 2.0 France",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-2.0-UK",
+    "Creative Commons Attribution Non Commercial Share Alike 2.0 England and Wales",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-2.5",
+    "Creative Commons Attribution Non Commercial Share Alike 2.5 Generic",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-3.0",
+    "Creative Commons Attribution Non Commercial Share Alike 3.0 Unported",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-3.0-DE",
+    "Creative Commons Attribution Non Commercial Share Alike 3.0 Germany",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-3.0-IGO",
+    "Creative Commons Attribution Non Commercial Share Alike 3.0 IGO",
+    false,
+  ),
+  (
+    "CC-BY-NC-SA-4.0",
+    "Creative Commons Attribution Non Commercial Share Alike 4.0 International",
+    false,
+  ),
+  (
+    "CC-BY-ND-1.0",
+    "Creative Commons Attribution No Derivatives 1.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-ND-2.0",
+    "Creative Commons Attribution No Derivatives 2.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-ND-2.5",
+    "Creative Commons Attribution No Derivatives 2.5 Generic",
+    false,
+  ),
+  (
+    "CC-BY-ND-3.0",
+    "Creative Commons Attribution No Derivatives 3.0 Unported",
+    false,
+  ),
+  (
+    "CC-BY-ND-3.0-DE",
+    "Creative Commons Attribution No Derivatives 3.0 Germany",
+    false,
+  ),
+  (
+    "CC-BY-ND-4.0",
+    "Creative Commons Attribution No Derivatives 4.0 International",
+    false,
+  ),
+  (
+    "CC-BY-SA-1.0",
+    "Creative Commons Attribution Share Alike 1.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-SA-2.0",
+    "Creative Commons Attribution Share Alike 2.0 Generic",
+    false,
+  ),
+  (
+    "CC-BY-SA-2.0-UK",
+    "Creative Commons Attribution Share Alike 2.0 England and Wales",
+    false,
+  ),
+  (
+    "CC-BY-SA-2.1-JP",
+    "Creative Commons Attribution Share Alike 2.1 Japan",
+    false,
+  ),
+  (
+    "CC-BY-SA-2.5",
+    "Creative Commons Attribution Share Alike 2.5 Generic",
+    false,
+  ),
+  (
+    "CC-BY-SA-3.0",
+    "Creative Commons Attribution Share Alike 3.0 Unported",
+    false,
+  ),
+  (
+    "CC-BY-SA-3.0-AT",
+    "Creative Commons Attribution Share Alike 3.0 Austria",
+    false,
+  ),
+  (
+    "CC-BY-SA-3.0-DE",
+    "Creative Commons Attribution Share Alike 3.0 Germany",
+    false,
+  ),
+  (
+    "CC-BY-SA-3.0-IGO",
+    "Creative Commons Attribution-ShareAlike 3.0 IGO",
+    false,
+  ),
+  (
+    "CC-BY-SA-4.0",
+    "Creative Commons Attribution Share Alike 4.0 International",
+    false,
+  ),
+  (
+    "CC-PDDC",
+    "Creative Commons Public Domain Dedication and Certification",
+    false,
+  ),
+  (
+    "CC-PDM-1.0",
+    "Creative    Commons Public Domain Mark 1.0 Universal",
+    false,
+  ),
+  (
+    "CC-SA-1.0",
+    "Creative Commons Share Alike 1.0 Generic",
+    false,
+  ),
+  ("CC0-1.0", "Creative Commons Zero v1.0 Universal", false),
+  (
+    "CDDL-1.0",
+    "Common Development and Distribution License 1.0",
+    false,
+  ),
+  (
+    "CDDL-1.1",
+    "Common Development and Distribution License 1.1",
+    false,
+  ),
+  ("CDL-1.0", "Common Documentation License 1.0", false),
+  (
+    "CDLA-Permissive-1.0",
+    "Community Data License Agreement Permissive 1.0",
+    false,
+  ),
+  (
+    "CDLA-Permissive-2.0",
+    "Community Data License Agreement Permissive 2.0",
+    false,
+  ),
+  (
+    "CDLA-Sharing-1.0",
+    "Community Data License Agreement Sharing 1.0",
+    false,
+  ),
+  (
+    "CECILL-1.0",
+    "CeCILL Free Software License Agreement v1.0",
+    false,
+  ),
+  (
+    "CECILL-1.1",
+    "CeCILL Free Software License Agreement v1.1",
+    false,
+  ),
+  (
+    "CECILL-2.0",
+    "CeCILL Free Software License Agreement v2.0",
+    false,
+  ),
+  (
+    "CECILL-2.1",
+    "CeCILL Free Software License Agreement v2.1",
+    false,
+  ),
+  (
+    "CECILL-B",
+    "CeCILL-B Free Software License Agreement",
+    false,
+  ),
+  (
+    "CECILL-C",
+    "CeCILL-C Free Software License Agreement",
+    false,
+  ),
+  ("CERN-OHL-1.1", "CERN Open Hardware Licence v1.1", false),
+  ("CERN-OHL-1.2", "CERN Open Hardware Licence v1.2", false),
+  (
+    "CERN-OHL-P-2.0",
+    "CERN Open Hardware Licence Version 2 - Permissive",
+    false,
+  ),
+  (
+    "CERN-OHL-S-2.0",
+    "CERN Open Hardware Licence Version 2 - Strongly Reciprocal",
+    false,
+  ),
+  (
+    "CERN-OHL-W-2.0",
+    "CERN Open Hardware Licence Version 2 - Weakly Reciprocal",
+    false,
+  ),
+  ("CFITSIO", "CFITSIO License", false),
+  ("CMU-Mach", "CMU Mach License", false),
+  (
+    "CMU-Mach-nodoc",
+    "CMU    Mach - no notices-in-documentation variant",
+    false,
+  ),
+  ("CNRI-Jython", "CNRI Jython License", false),
+  ("CNRI-Python", "CNRI Python License", false),
+  (
+    "CNRI-Python-GPL-Compatible",
+    "CNRI Python Open Source GPL Compatible License Agreement",
+    false,
+  ),
+  ("COIL-1.0", "Copyfree Open Innovation License", false),
+  ("CPAL-1.0", "Common Public Attribution License 1.0", false),
+  ("CPL-1.0", "Common Public License 1.0", false),
+  ("CPOL-1.02", "Code Project Open License 1.02", false),
+  ("CUA-OPL-1.0", "CUA Office Public License v1.0", false),
+  ("Caldera", "Caldera License", false),
+  (
+    "Caldera-no-preamble",
+    "Caldera License (without preamble)",
+    false,
+  ),
+  ("Catharon", "Catharon License", false),
+  ("ClArtistic", "Clarified Artistic License", false),
+  ("Clips", "Clips License", false),
+  (
+    "Community-Spec-1.0",
+    "Community Specification License 1.0",
+    false,
+  ),
+  ("Condor-1.1", "Condor Public License v1.1", false),
+  (
+    "Cornell-Lossless-JPEG",
+    "Cornell Lossless JPEG License",
+    false,
+  ),
+  ("Cronyx", "Cronyx License", false),
+  ("Crossword", "Crossword License", false),
+  ("CryptoSwift", "CryptoSwift License", false),
+  ("CrystalStacker", "CrystalStacker License", false),
+  ("Cube", "Cube License", false),
+  ("D-FSL-1.0", "Deutsche Freie Software Lizenz", false),
+  ("DEC-3-Clause", "DEC 3-Clause License", false),
+  (
+    "DL-DE-BY-2.0",
+    "Data licence Germany – attribution – version 2.0",
+    false,
+  ),
+  (
+    "DL-DE-ZERO-2.0",
+    "Data licence Germany – zero – version 2.0",
+    false,
+  ),
+  ("DOC", "DOC License", false),
+  ("DRL-1.0", "Detection Rule License 1.0", false),
+  ("DRL-1.1", "Detection Rule License 1.1", false),
+  ("DSDP", "DSDP License", false),
+  ("DocBook-DTD", "DocBook DTD License", false),
+  ("DocBook-Schema", "DocBook Schema License", false),
+  ("DocBook-Stylesheet", "DocBook Stylesheet License", false),
+  ("DocBook-XML", "DocBook XML License", false),
+  ("Dotseqn", "Dotseqn License", false),
+  ("ECL-1.0", "Educational Community License v1.0", false),
+  ("ECL-2.0", "Educational Community License v2.0", false),
+  ("EFL-1.0", "Eiffel Forum License v1.0", false),
+  ("EFL-2.0", "Eiffel Forum License v2.0", false),
+  ("EPICS", "EPICS Open License", false),
+  ("EPL-1.0", "Eclipse Public License 1.0", false),
+  ("EPL-2.0", "Eclipse Public License 2.0", false),
+  ("EUDatagrid", "EU DataGrid Software License", false),
+  ("EUPL-1.0", "European Union Public License 1.0", false),
+  ("EUPL-1.1", "European Union Public License 1.1", false),
+  ("EUPL-1.2", "European Union Public License 1.2", false),
+  ("Elastic-2.0", "Elastic License 2.0", false),
+  ("Entessa", "Entessa Public License v1.0", false),
+  ("ErlPL-1.1", "Erlang Public License v1.1", false),
+  ("Eurosym", "Eurosym License", false),
+  ("FBM", "Fuzzy Bitmap License", false),
+  ("FDK-AAC", "Fraunhofer FDK AAC Codec Library", false),
+  ("FSFAP", "FSF All Permissive License", false),
+  (
+    "FSFAP-no-warranty-disclaimer",
+    "FSF All Permissive License (without Warranty)",
+    false,
+  ),
+  ("FSFUL", "FSF Unlimited License", false),
+  (
+    "FSFULLR",
+    "FSF Unlimited License (with License Retention)",
+    false,
+  ),
+  (
+    "FSFULLRSD",
+    "FSF Unlimited License (with License Retention and Short Disclaimer)",
+    false,
+  ),
+  (
+    "FSFULLRWD",
+    "FSF Unlimited License (With License Retention and Warranty Disclaimer)",
+    false,
+  ),
+  (
+    "FSL-1.1-ALv2",
+    "Functional Source License, Version 1.1, ALv2 Future License",
+    false,
+  ),
+  (
+    "FSL-1.1-MIT",
+    "Functional Source License, Version 1.1, MIT Future License",
+    false,
+  ),
+  ("FTL", "Freetype Project License", false),
+  ("Fair", "Fair License", false),
+  ("Ferguson-Twofish", "Ferguson Twofish License", false),
+  ("Frameworx-1.0", "Frameworx Open License 1.0", false),
+  ("FreeBSD-DOC", "FreeBSD Documentation License", false),
+  ("FreeImage", "FreeImage Public License v1.0", false),
+  ("Furuseth", "Furuseth License", false),
+  ("GCR-docs", "Gnome GCR Documentation License", false),
+  ("GD", "GD License", false),
+  ("GFDL-1.1", "GNU Free Documentation License v1.1", true),
+  (
+    "GFDL-1.1-invariants",
+    "GNU Free Documentation License v1.1 only - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.1-invariants-only",
+    "GNU Free Documentation License v1.1 only - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.1-invariants-or-later",
+    "GNU Free Documentation License v1.1 or later - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.1-no-invariants",
+    "GNU Free Documentation License v1.1 only - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.1-no-invariants-only",
+    "GNU Free Documentation License v1.1 only - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.1-no-invariants-or-later",
+    "GNU Free Documentation License v1.1 or later - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.1-only",
+    "GNU Free Documentation License v1.1 only",
+    false,
+  ),
+  (
+    "GFDL-1.1-or-later",
+    "GNU Free Documentation License v1.1 or later",
+    false,
+  ),
+  ("GFDL-1.2", "GNU Free Documentation License v1.2", true),
+  (
+    "GFDL-1.2-invariants",
+    "GNU Free Documentation License v1.2 only - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.2-invariants-only",
+    "GNU Free Documentation License v1.2 only - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.2-invariants-or-later",
+    "GNU Free Documentation License v1.2 or later - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.2-no-invariants",
+    "GNU Free Documentation License v1.2 only - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.2-no-invariants-only",
+    "GNU Free Documentation License v1.2 only - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.2-no-invariants-or-later",
+    "GNU Free Documentation License v1.2 or later - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.2-only",
+    "GNU Free Documentation License v1.2 only",
+    false,
+  ),
+  (
+    "GFDL-1.2-or-later",
+    "GNU Free Documentation License v1.2 or later",
+    false,
+  ),
+  ("GFDL-1.3", "GNU Free Documentation License v1.3", true),
+  (
+    "GFDL-1.3-invariants",
+    "GNU Free Documentation License v1.3 only - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.3-invariants-only",
+    "GNU Free Documentation License v1.3 only - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.3-invariants-or-later",
+    "GNU Free Documentation License v1.3 or later - invariants",
+    false,
+  ),
+  (
+    "GFDL-1.3-no-invariants",
+    "GNU Free Documentation License v1.3 only - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.3-no-invariants-only",
+    "GNU Free Documentation License v1.3 only - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.3-no-invariants-or-later",
+    "GNU Free Documentation License v1.3 or later - no invariants",
+    false,
+  ),
+  (
+    "GFDL-1.3-only",
+    "GNU Free Documentation License v1.3 only",
+    false,
+  ),
+  (
+    "GFDL-1.3-or-later",
+    "GNU Free Documentation License v1.3 or later",
+    false,
+  ),
+  ("GL2PS", "GL2PS License", false),
+  ("GLWTPL", "Good Luck With That Public License", false),
+  ("GPL-1.0", "GNU General Public License v1.0 only", true),
+  ("GPL-1.0+", "GNU General Public License v1.0 or later", true),
+  (
+    "GPL-1.0-only",
+    "GNU General Public License v1.0 only",
+    false,
+  ),
+  (
+    "GPL-1.0-or-later",
+    "GNU General Public License v1.0 or later",
+    false,
+  ),
+  ("GPL-2.0", "GNU General Public License v2.0 only", true),
+  ("GPL-2.0+", "GNU General Public License v2.0 or later", true),
+  (
+    "GPL-2.0-only",
+    "GNU General Public License v2.0 only",
+    false,
+  ),
+  (
+    "GPL-2.0-or-later",
+    "GNU General Public License v2.0 or later",
+    false,
+  ),
+  (
+    "GPL-2.0-with-GCC-exception",
+    "GNU General Public License v2.0 w/GCC Runtime Library exception",
+    true,
+  ),
+  (
+    "GPL-2.0-with-autoconf-exception",
+    "GNU General Public License v2.0 w/Autoconf exception",
+    true,
+  ),
+  (
+    "GPL-2.0-with-bison-exception",
+    "GNU General Public License v2.0 w/Bison exception",
+    true,
+  ),
+  (
+    "GPL-2.0-with-classpath-exception",
+    "GNU General Public License v2.0 w/Classpath exception",
+    true,
+  ),
+  (
+    "GPL-2.0-with-font-exception",
+    "GNU General Public License v2.0 w/Font exception",
+    true,
+  ),
+  ("GPL-3.0", "GNU General Public License v3.0 only", true),
+  ("GPL-3.0+", "GNU General Public License v3.0 or later", true),
+  (
+    "GPL-3.0-only",
+    "GNU General Public License v3.0 only",
+    false,
+  ),
+  (
+    "GPL-3.0-or-later",
+    "GNU General Public License v3.0 or later",
+    false,
+  ),
+  (
+    "GPL-3.0-with-GCC-exception",
+    "GNU General Public License v3.0 w/GCC Runtime Library exception",
+    true,
+  ),
+  (
+    "GPL-3.0-with-autoconf-exception",
+    "GNU General Public License v3.0 w/Autoconf exception",
+    true,
+  ),
+  (
+    "Game-Programming-Gems",
+    "Game Programming Gems License",
+    false,
+  ),
+  ("Giftware", "Giftware License", false),
+  ("Glide", "3dfx Glide License", false),
+  ("Glulxe", "Glulxe License", false),
+  ("Graphics-Gems", "Graphics Gems License", false),
+  ("Gutmann", "Gutmann License", false),
+  ("HDF5", "HDF5 License", false),
+  ("HIDAPI", "HIDAPI License", false),
+  ("HP-1986", "Hewlett-Packard 1986 License", false),
+  ("HP-1989", "Hewlett-Packard 1989 License", false),
+  ("HPND", "Historical Permission Notice and Disclaimer", false),
+  (
+    "HPND-DEC",
+    "Historical Permission Notice and Disclaimer - DEC variant",
+    false,
+  ),
+  (
+    "HPND-Fenneberg-Livingston",
+    "Historical Permission Notice and Disclaimer - Fenneberg-Livingston variant",
+    false,
+  ),
+  (
+    "HPND-INRIA-IMAG",
+    "Historical Permission Notice and Disclaimer    - INRIA-IMAG variant",
+    false,
+  ),
+  (
+    "HPND-Intel",
+    "Historical Permission Notice and Disclaimer - Intel variant",
+    false,
+  ),
+  (
+    "HPND-Kevlin-Henney",
+    "Historical Permission Notice and Disclaimer - Kevlin Henney variant",
+    false,
+  ),
+  (
+    "HPND-MIT-disclaimer",
+    "Historical Permission Notice and Disclaimer with MIT disclaimer",
+    false,
+  ),
+  (
+    "HPND-Markus-Kuhn",
+    "Historical Permission Notice and Disclaimer - Markus Kuhn variant",
+    false,
+  ),
+  (
+    "HPND-Netrek",
+    "Historical Permission Notice and Disclaimer - Netrek variant",
+    false,
+  ),
+  (
+    "HPND-Pbmplus",
+    "Historical Permission Notice and Disclaimer - Pbmplus variant",
+    false,
+  ),
+  (
+    "HPND-UC",
+    "Historical Permission Notice and Disclaimer - University of California variant",
+    false,
+  ),
+  (
+    "HPND-UC-export-US",
+    "Historical Permission Notice and Disclaimer - University of California, US export warning",
+    false,
+  ),
+  (
+    "HPND-doc",
+    "Historical Permission Notice and Disclaimer - documentation variant",
+    false,
+  ),
+  (
+    "HPND-doc-sell",
+    "Historical Permission Notice and Disclaimer - documentation sell variant",
+    false,
+  ),
+  (
+    "HPND-export-US",
+    "HPND with US Government export control warning",
+    false,
+  ),
+  (
+    "HPND-export-US-acknowledgement",
+    "HPND with US Government export control warning and acknowledgment",
+    false,
+  ),
+  (
+    "HPND-export-US-modify",
+    "HPND with US Government export control warning and modification rqmt",
+    false,
+  ),
+  (
+    "HPND-export2-US",
+    "HPND with US Government export control and 2 disclaimers",
+    false,
+  ),
+  (
+    "HPND-merchantability-variant",
+    "Historical Permission Notice and Disclaimer - merchantability variant",
+    false,
+  ),
+  (
+    "HPND-sell-MIT-disclaimer-xserver",
+    "Historical Permission Notice and Disclaimer - sell xserver variant with MIT disclaimer",
+    false,
+  ),
+  (
+    "HPND-sell-regexpr",
+    "Historical Permission Notice and Disclaimer - sell regexpr variant",
+    false,
+  ),
+  (
+    "HPND-sell-variant",
+    "Historical Permission Notice and Disclaimer - sell variant",
+    false,
+  ),
+  (
+    "HPND-sell-variant-MIT-disclaimer",
+    "HPND sell variant with MIT disclaimer",
+    false,
+  ),
+  (
+    "HPND-sell-variant-MIT-disclaimer-rev",
+    "HPND sell variant with MIT disclaimer - reverse",
+    false,
+  ),
+  ("HTMLTIDY", "HTML Tidy License", false),
+  ("HaskellReport", "Haskell Language Report License", false),
+  ("Hippocratic-2.1", "Hippocratic License 2.1", false),
+  (
+    "IBM-pibs",
+    "IBM PowerPC Initialization and Boot Software",
+    false,
+  ),
+  ("ICU", "ICU License", false),
+  (
+    "IEC-Code-Components-EULA",
+    "IEC    Code Components End-user licence agreement",
+    false,
+  ),
+  ("IJG", "Independent JPEG Group License", false),
+  ("IJG-short", "Independent JPEG Group License - short", false),
+  ("IPA", "IPA Font License", false),
+  ("IPL-1.0", "IBM Public License v1.0", false),
+  ("ISC", "ISC License", false),
+  ("ISC-Veillard", "ISC Veillard variant", false),
+  ("ImageMagick", "ImageMagick License", false),
+  ("Imlib2", "Imlib2 License", false),
+  ("Info-ZIP", "Info-ZIP License", false),
+  ("Inner-Net-2.0", "Inner Net License v2.0", false),
+  ("InnoSetup", "Inno Setup License", false),
+  ("Intel", "Intel Open Source License", false),
+  ("Intel-ACPI", "Intel ACPI Software License Agreement", false),
+  ("Interbase-1.0", "Interbase Public License v1.0", false),
+  ("JPL-image", "JPL Image Use Policy", false),
+  ("JPNIC", "Japan Network Information Center License", false),
+  ("JSON", "JSON License", false),
+  ("Jam", "Jam License", false),
+  ("JasPer-2.0", "JasPer License", false),
+  ("Kastrup", "Kastrup License", false),
+  ("Kazlib", "Kazlib License", false),
+  ("Knuth-CTAN", "Knuth CTAN License", false),
+  ("LAL-1.2", "Licence Art Libre 1.2", false),
+  ("LAL-1.3", "Licence Art Libre 1.3", false),
+  (
+    "LGPL-2.0",
+    "GNU Library General Public License v2 only",
+    true,
+  ),
+  (
+    "LGPL-2.0+",
+    "GNU Library General Public License v2 or later",
+    true,
+  ),
+  (
+    "LGPL-2.0-only",
+    "GNU Library General Public License v2 only",
+    false,
+  ),
+  (
+    "LGPL-2.0-or-later",
+    "GNU Library General Public License v2 or later",
+    false,
+  ),
+  (
+    "LGPL-2.1",
+    "GNU Lesser General Public License v2.1 only",
+    true,
+  ),
+  (
+    "LGPL-2.1+",
+    "GNU Lesser General Public License v2.1 or later",
+    true,
+  ),
+  (
+    "LGPL-2.1-only",
+    "GNU Lesser General Public License v2.1 only",
+    false,
+  ),
+  (
+    "LGPL-2.1-or-later",
+    "GNU Lesser General Public License v2.1 or later",
+    false,
+  ),
+  (
+    "LGPL-3.0",
+    "GNU Lesser General Public License v3.0 only",
+    true,
+  ),
+  (
+    "LGPL-3.0+",
+    "GNU Lesser General Public License v3.0 or later",
+    true,
+  ),
+  (
+    "LGPL-3.0-only",
+    "GNU Lesser General Public License v3.0 only",
+    false,
+  ),
+  (
+    "LGPL-3.0-or-later",
+    "GNU Lesser General Public License v3.0 or later",
+    false,
+  ),
+  (
+    "LGPLLR",
+    "Lesser General Public License For Linguistic Resources",
+    false,
+  ),
+  ("LOOP", "Common Lisp LOOP License", false),
+  ("LPD-document", "LPD Documentation License", false),
+  ("LPL-1.0", "Lucent Public License Version 1.0", false),
+  ("LPL-1.02", "Lucent Public License v1.02", false),
+  ("LPPL-1.0", "LaTeX Project Public License v1.0", false),
+  ("LPPL-1.1", "LaTeX Project Public License v1.1", false),
+  ("LPPL-1.2", "LaTeX Project Public License v1.2", false),
+  ("LPPL-1.3a", "LaTeX Project Public License v1.3a", false),
+  ("LPPL-1.3c", "LaTeX Project Public License v1.3c", false),
+  (
+    "LZMA-SDK-9.11-to-9.20",
+    "LZMA SDK License (versions 9.11 to 9.20)",
+    false,
+  ),
+  (
+    "LZMA-SDK-9.22",
+    "LZMA SDK License (versions 9.22 and beyond)",
+    false,
+  ),
+  ("Latex2e", "Latex2e License", false),
+  (
+    "Latex2e-translated-notice",
+    "Latex2e with translated notice permission",
+    false,
+  ),
+  ("Leptonica", "Leptonica License", false),
+  (
+    "LiLiQ-P-1.1",
+    "Licence Libre du Québec – Permissive version 1.1",
+    false,
+  ),
+  (
+    "LiLiQ-R-1.1",
+    "Licence Libre du Québec – Réciprocité version 1.1",
+    false,
+  ),
+  (
+    "LiLiQ-Rplus-1.1",
+    "Licence Libre du Québec – Réciprocité forte version 1.1",
+    false,
+  ),
+  ("Libpng", "libpng License", false),
+  (
+    "Linux-OpenIB",
+    "Linux Kernel Variant of OpenIB.org license",
+    false,
+  ),
+  (
+    "Linux-man-pages-1-para",
+    "Linux man-pages - 1 paragraph",
+    false,
+  ),
+  (
+    "Linux-man-pages-copyleft",
+    "Linux man-pages Copyleft",
+    false,
+  ),
+  (
+    "Linux-man-pages-copyleft-2-para",
+    "Linux man-pages Copyleft - 2 paragraphs",
+    false,
+  ),
+  (
+    "Linux-man-pages-copyleft-var",
+    "Linux man-pages Copyleft Variant",
+    false,
+  ),
+  ("Lucida-Bitmap-Fonts", "Lucida Bitmap Fonts License", false),
+  ("MIPS", "MIPS License", false),
+  ("MIT", "MIT License", false),
+  ("MIT-0", "MIT No Attribution", false),
+  ("MIT-CMU", "CMU License", false),
+  ("MIT-Click", "MIT Click License", false),
+  ("MIT-Festival", "MIT Festival Variant", false),
+  ("MIT-Khronos-old", "MIT Khronos - old variant", false),
+  ("MIT-Modern-Variant", "MIT License Modern Variant", false),
+  ("MIT-Wu", "MIT Tom Wu Variant", false),
+  ("MIT-advertising", "Enlightenment License (e16)", false),
+  ("MIT-enna", "enna License", false),
+  ("MIT-feh", "feh License", false),
+  ("MIT-open-group", "MIT Open Group variant", false),
+  ("MIT-testregex", "MIT testregex Variant", false),
+  ("MITNFA", "MIT +no-false-attribs license", false),
+  ("MMIXware", "MMIXware License", false),
+  ("MPEG-SSG", "MPEG Software Simulation", false),
+  ("MPL-1.0", "Mozilla Public License 1.0", false),
+  ("MPL-1.1", "Mozilla Public License 1.1", false),
+  ("MPL-2.0", "Mozilla Public License 2.0", false),
+  (
+    "MPL-2.0-no-copyleft-exception",
+    "Mozilla Public License 2.0 (no copyleft exception)",
+    false,
+  ),
+  ("MS-LPL", "Microsoft Limited Public License", false),
+  ("MS-PL", "Microsoft Public License", false),
+  ("MS-RL", "Microsoft Reciprocal License", false),
+  ("MTLL", "Matrix Template Library License", false),
+  ("Mackerras-3-Clause", "Mackerras 3-Clause License", false),
+  (
+    "Mackerras-3-Clause-acknowledgment",
+    "Mackerras 3-Clause - acknowledgment variant",
+    false,
+  ),
+  ("MakeIndex", "MakeIndex License", false),
+  ("Martin-Birgmeier", "Martin Birgmeier License", false),
+  ("McPhee-slideshow", "McPhee Slideshow License", false),
+  ("Minpack", "Minpack License", false),
+  ("MirOS", "The MirOS Licence", false),
+  ("Motosoto", "Motosoto License", false),
+  (
+    "MulanPSL-1.0",
+    "Mulan Permissive Software License, Version 1",
+    false,
+  ),
+  (
+    "MulanPSL-2.0",
+    "Mulan Permissive Software License, Version 2",
+    false,
+  ),
+  ("Multics", "Multics License", false),
+  ("Mup", "Mup License", false),
+  (
+    "NAIST-2003",
+    "Nara Institute of Science and Technology License (2003)",
+    false,
+  ),
+  ("NASA-1.3", "NASA Open Source Agreement 1.3", false),
+  ("NBPL-1.0", "Net Boolean Public License v1", false),
+  ("NCBI-PD", "NCBI Public Domain Notice", false),
+  ("NCGL-UK-2.0", "Non-Commercial Government Licence", false),
+  ("NCL", "NCL Source Code License", false),
+  (
+    "NCSA",
+    "University of Illinois/NCSA Open Source License",
+    false,
+  ),
+  ("NGPL", "Nethack General Public License", false),
+  (
+    "NICTA-1.0",
+    "NICTA Public Software License, Version 1.0",
+    false,
+  ),
+  ("NIST-PD", "NIST Public Domain Notice", false),
+  (
+    "NIST-PD-fallback",
+    "NIST Public Domain Notice with license fallback",
+    false,
+  ),
+  ("NIST-Software", "NIST Software License", false),
+  (
+    "NLOD-1.0",
+    "Norwegian Licence for Open Government Data (NLOD) 1.0",
+    false,
+  ),
+  (
+    "NLOD-2.0",
+    "Norwegian Licence for Open Government Data (NLOD) 2.0",
+    false,
+  ),
+  ("NLPL", "No Limit Public License", false),
+  ("NOASSERTION", "NOASSERTION", false),
+  ("NOSL", "Netizen Open Source License", false),
+  ("NPL-1.0", "Netscape Public License v1.0", false),
+  ("NPL-1.1", "Netscape Public License v1.1", false),
+  ("NPOSL-3.0", "Non-Profit Open Software License 3.0", false),
+  ("NRL", "NRL License", false),
+  ("NTIA-PD", "NTIA Public Domain Notice", false),
+  ("NTP", "NTP License", false),
+  ("NTP-0", "NTP No Attribution", false),
+  ("Naumen", "Naumen Public License", false),
+  ("Net-SNMP", "Net-SNMP License", true),
+  ("NetCDF", "NetCDF license", false),
+  ("Newsletr", "Newsletr License", false),
+  ("Nokia", "Nokia Open Source License", false),
+  ("Noweb", "Noweb License", false),
+  ("Nunit", "Nunit License", true),
+  ("O-UDA-1.0", "Open Use of Data Agreement v1.0", false),
+  ("OAR", "OAR License", false),
+  ("OCCT-PL", "Open CASCADE Technology Public License", false),
+  ("OCLC-2.0", "OCLC Research Public License 2.0", false),
+  (
+    "ODC-By-1.0",
+    "Open Data Commons Attribution License v1.0",
+    false,
+  ),
+  (
+    "ODbL-1.0",
+    "Open Data Commons Open Database License v1.0",
+    false,
+  ),
+  ("OFFIS", "OFFIS License", false),
+  ("OFL-1.0", "SIL Open Font License 1.0", false),
+  (
+    "OFL-1.0-RFN",
+    "SIL Open Font License 1.0 with Reserved Font Name",
+    false,
+  ),
+  (
+    "OFL-1.0-no-RFN",
+    "SIL Open Font License 1.0 with no Reserved Font Name",
+    false,
+  ),
+  ("OFL-1.1", "SIL Open Font License 1.1", false),
+  (
+    "OFL-1.1-RFN",
+    "SIL Open Font License 1.1 with Reserved Font Name",
+    false,
+  ),
+  (
+    "OFL-1.1-no-RFN",
+    "SIL Open Font License 1.1 with no Reserved Font Name",
+    false,
+  ),
+  ("OGC-1.0", "OGC Software License, Version 1.0", false),
+  (
+    "OGDL-Taiwan-1.0",
+    "Taiwan Open Government Data License, version 1.0",
+    false,
+  ),
+  ("OGL-Canada-2.0", "Open Government Licence - Canada", false),
+  ("OGL-UK-1.0", "Open Government Licence v1.0", false),
+  ("OGL-UK-2.0", "Open Government Licence v2.0", false),
+  ("OGL-UK-3.0", "Open Government Licence v3.0", false),
+  ("OGTSL", "Open Group Test Suite License", false),
+  ("OLDAP-1.1", "Open LDAP Public License v1.1", false),
+  ("OLDAP-1.2", "Open LDAP Public License v1.2", false),
+  ("OLDAP-1.3", "Open LDAP Public License v1.3", false),
+  ("OLDAP-1.4", "Open LDAP Public License v1.4", false),
+  (
+    "OLDAP-2.0",
+    "Open LDAP Public License v2.0 (or possibly 2.0A and 2.0B)",
+    false,
+  ),
+  ("OLDAP-2.0.1", "Open LDAP Public License v2.0.1", false),
+  ("OLDAP-2.1", "Open LDAP Public License v2.1", false),
+  ("OLDAP-2.2", "Open LDAP Public License v2.2", false),
+  ("OLDAP-2.2.1", "Open LDAP Public License v2.2.1", false),
+  ("OLDAP-2.2.2", "Open LDAP Public License 2.2.2", false),
+  ("OLDAP-2.3", "Open LDAP Public License v2.3", false),
+  ("OLDAP-2.4", "Open LDAP Public License v2.4", false),
+  ("OLDAP-2.5", "Open LDAP Public License v2.5", false),
+  ("OLDAP-2.6", "Open LDAP Public License v2.6", false),
+  ("OLDAP-2.7", "Open LDAP Public License v2.7", false),
+  ("OLDAP-2.8", "Open LDAP Public License v2.8", false),
+  (
+    "OLFL-1.3",
+    "Open Logistics Foundation License Version 1.3",
+    false,
+  ),
+  ("OML", "Open Market License", false),
+  ("OPL-1.0", "Open Public License v1.0", false),
+  (
+    "OPL-UK-3.0",
+    "United    Kingdom Open Parliament Licence v3.0",
+    false,
+  ),
+  ("OPUBL-1.0", "Open Publication License v1.0", false),
+  ("OSET-PL-2.1", "OSET Public License version 2.1", false),
+  ("OSL-1.0", "Open Software License 1.0", false),
+  ("OSL-1.1", "Open Software License 1.1", false),
+  ("OSL-2.0", "Open Software License 2.0", false),
+  ("OSL-2.1", "Open Software License 2.1", false),
+  ("OSL-3.0", "Open Software License 3.0", false),
+  ("OpenPBS-2.3", "OpenPBS v2.3 Software License", false),
+  ("OpenSSL", "OpenSSL License", false),
+  ("OpenSSL-standalone", "OpenSSL License - standalone", false),
+  ("OpenVision", "OpenVision License", false),
+  ("PADL", "PADL License", false),
+  (
+    "PDDL-1.0",
+    "Open Data Commons Public Domain Dedication & License 1.0",
+    false,
+  ),
+  ("PHP-3.0", "PHP License v3.0", false),
+  ("PHP-3.01", "PHP License v3.01", false),
+  ("PPL", "Peer Production License", false),
+  ("PSF-2.0", "Python Software Foundation License 2.0", false),
+  ("Parity-6.0.0", "The Parity Public License 6.0.0", false),
+  ("Parity-7.0.0", "The Parity Public License 7.0.0", false),
+  ("Pixar", "Pixar License", false),
+  ("Plexus", "Plexus Classworlds License", false),
+  (
+    "PolyForm-Noncommercial-1.0.0",
+    "PolyForm Noncommercial License 1.0.0",
+    false,
+  ),
+  (
+    "PolyForm-Small-Business-1.0.0",
+    "PolyForm Small Business License 1.0.0",
+    false,
+  ),
+  ("PostgreSQL", "PostgreSQL License", false),
+  ("Python-2.0", "Python License 2.0", false),
+  ("Python-2.0.1", "Python License 2.0.1", false),
+  ("QPL-1.0", "Q Public License 1.0", false),
+  (
+    "QPL-1.0-INRIA-2004",
+    "Q Public License 1.0 - INRIA 2004 variant",
+    false,
+  ),
+  ("Qhull", "Qhull License", false),
+  ("RHeCos-1.1", "Red Hat eCos Public License v1.1", false),
+  ("RPL-1.1", "Reciprocal Public License 1.1", false),
+  ("RPL-1.5", "Reciprocal Public License 1.5", false),
+  ("RPSL-1.0", "RealNetworks Public Source License v1.0", false),
+  ("RSA-MD", "RSA Message-Digest License", false),
+  ("RSCPL", "Ricoh Source Code Public License", false),
+  ("Rdisc", "Rdisc License", false),
+  ("Ruby", "Ruby License", false),
+  ("Ruby-pty", "Ruby pty extension license", false),
+  ("SAX-PD", "Sax Public Domain Notice", false),
+  ("SAX-PD-2.0", "Sax Public Domain Notice 2.0", false),
+  ("SCEA", "SCEA Shared Source License", false),
+  ("SGI-B-1.0", "SGI Free Software License B v1.0", false),
+  ("SGI-B-1.1", "SGI Free Software License B v1.1", false),
+  ("SGI-B-2.0", "SGI Free Software License B v2.0", false),
+  ("SGI-OpenGL", "SGI OpenGL License", false),
+  ("SGP4", "SGP4 Permission Notice", false),
+  ("SHL-0.5", "Solderpad Hardware License v0.5", false),
+  (
+    "SHL-0.51",
+    "Solderpad Hardware License, Version 0.51",
+    false,
+  ),
+  ("SISSL", "Sun Industry Standards Source License v1.1", false),
+  (
+    "SISSL-1.2",
+    "Sun Industry Standards Source License v1.2",
+    false,
+  ),
+  ("SL", "SL License", false),
+  ("SMAIL-GPL", "SMAIL General Public License", false),
+  ("SMLNJ", "Standard ML of New Jersey License", false),
+  ("SMPPL", "Secure Messaging Protocol Public License", false),
+  ("SNIA", "SNIA Public License 1.1", false),
+  ("SOFA", "SOFA Software License", false),
+  ("SPL-1.0", "Sun Public License v1.0", false),
+  ("SSH-OpenSSH", "SSH OpenSSH license", false),
+  ("SSH-short", "SSH short notice", false),
+  ("SSLeay-standalone", "SSLeay License - standalone", false),
+  ("SSPL-1.0", "Server Side Public License, v 1", false),
+  ("SUL-1.0", "Sustainable Use License v1.0", false),
+  (
+    "SWL",
+    "Scheme Widget Library (SWL) Software License Agreement",
+    false,
+  ),
+  ("Saxpath", "Saxpath License", false),
+  ("SchemeReport", "Scheme Language Report License", false),
+  ("Sendmail", "Sendmail License", false),
+  ("Sendmail-8.23", "Sendmail License 8.23", false),
+  (
+    "Sendmail-Open-Source-1.1",
+    "Sendmail Open Source License v1.1",
+    false,
+  ),
+  ("SimPL-2.0", "Simple Public License 2.0", false),
+  ("Sleepycat", "Sleepycat License", false),
+  ("Soundex", "Soundex License", false),
+  ("Spencer-86", "Spencer License 86", false),
+  ("Spencer-94", "Spencer License 94", false),
+  ("Spencer-99", "Spencer License 99", false),
+  ("StandardML-NJ", "Standard ML of New Jersey License", true),
+  ("SugarCRM-1.1.3", "SugarCRM Public License v1.1.3", false),
+  ("Sun-PPP", "Sun PPP License", false),
+  ("Sun-PPP-2000", "Sun PPP License (2000)", false),
+  ("SunPro", "SunPro License", false),
+  ("Symlinks", "Symlinks License", false),
+  ("TAPR-OHL-1.0", "TAPR Open Hardware License v1.0", false),
+  ("TCL", "TCL/TK License", false),
+  ("TCP-wrappers", "TCP Wrappers License", false),
+  (
+    "TGPPL-1.0",
+    "Transitive Grace Period Public Licence 1.0",
+    false,
+  ),
+  ("TMate", "TMate Open Source License", false),
+  ("TORQUE-1.1", "TORQUE v2.5+ Software License v1.1", false),
+  ("TOSL", "Trusster Open Source License", false),
+  ("TPDL", "Time::ParseDate License", false),
+  ("TPL-1.0", "THOR Public License 1.0", false),
+  ("TTWL", "Text-Tabs+Wrap License", false),
+  ("TTYP0", "TTYP0 License", false),
+  (
+    "TU-Berlin-1.0",
+    "Technische Universitaet Berlin License 1.0",
+    false,
+  ),
+  (
+    "TU-Berlin-2.0",
+    "Technische Universitaet Berlin License 2.0",
+    false,
+  ),
+  ("TermReadKey", "TermReadKey License", false),
+  ("ThirdEye", "ThirdEye License", false),
+  ("TrustedQSL", "TrustedQSL License", false),
+  ("UCAR", "UCAR License", false),
+  ("UCL-1.0", "Upstream Compatibility License v1.0", false),
+  ("UMich-Merit", "Michigan/Merit Networks License", false),
+  ("UPL-1.0", "Universal Permissive License v1.0", false),
+  (
+    "URT-RLE",
+    "Utah Raster Toolkit Run Length Encoded License",
+    false,
+  ),
+  ("Ubuntu-font-1.0", "Ubuntu Font Licence v1.0", false),
+  ("Unicode-3.0", "Unicode License v3", false),
+  (
+    "Unicode-DFS-2015",
+    "Unicode License Agreement - Data Files and Software (2015)",
+    false,
+  ),
+  (
+    "Unicode-DFS-2016",
+    "Unicode License Agreement - Data Files and Software (2016)",
+    false,
+  ),
+  ("Unicode-TOU", "Unicode Terms of Use", false),
+  ("UnixCrypt", "UnixCrypt License", false),
+  ("Unlicense", "The Unlicense", false),
+  (
+    "Unlicense-libtelnet",
+    "Unlicense - libtelnet variant",
+    false,
+  ),
+  (
+    "Unlicense-libwhirlpool",
+    "Unlicense - libwhirlpool variant",
+    false,
+  ),
+  ("VOSTROM", "VOSTROM Public License for Open Source", false),
+  ("VSL-1.0", "Vovida Software License v1.0", false),
+  ("Vim", "Vim License", false),
+  ("W3C", "W3C Software Notice and License (2002-12-31)", false),
+  (
+    "W3C-19980720",
+    "W3C Software Notice and License (1998-07-20)",
+    false,
+  ),
+  (
+    "W3C-20150513",
+    "W3C Software Notice and Document License (2015-05-13)",
+    false,
+  ),
+  (
+    "WTFPL",
+    "Do What The F*ck You Want To Public License",
+    false,
+  ),
+  ("Watcom-1.0", "Sybase Open Watcom Public License 1.0", false),
+  ("Widget-Workshop", "Widget Workshop License", false),
+  ("Wsuipa", "Wsuipa License", false),
+  ("X11", "X11 License", false),
+  (
+    "X11-distribute-modifications-variant",
+    "X11 License Distribution Modification Variant",
+    false,
+  ),
+  ("X11-swapped", "X11 swapped final paragraphs", false),
+  ("XFree86-1.1", "XFree86 License 1.1", false),
+  ("XSkat", "XSkat License", false),
+  ("Xdebug-1.03", "Xdebug License v 1.03", false),
+  ("Xerox", "Xerox License", false),
+  ("Xfig", "Xfig License", false),
+  ("Xnet", "X.Net License", false),
+  ("YPL-1.0", "Yahoo! Public License v1.0", false),
+  ("YPL-1.1", "Yahoo! Public License v1.1", false),
+  ("ZPL-1.1", "Zope Public License 1.1", false),
+  ("ZPL-2.0", "Zope Public License 2.0", false),
+  ("ZPL-2.1", "Zope Public License 2.1", false),
+  ("Zed", "Zed License", false),
+  ("Zeeff", "Zeeff License", false),
+  ("Zend-2.0", "Zend License v2.0", false),
+  ("Zimbra-1.3", "Zimbra Public License v1.3", false),
+  ("Zimbra-1.4", "Zimbra Public License v1.4", false),
+  ("Zlib", "zlib License", false),
+  ("any-OSI", "Any OSI License", false),
+  (
+    "any-OSI-perl-modules",
+    "Any OSI License - Perl Modules",
+    false,
+  ),
+  (
+    "bcrypt-Solar-Designer",
+    "bcrypt Solar Designer License",
+    false,
+  ),
+  ("blessing", "SQLite Blessing", false),
+  ("bzip2-1.0.5", "bzip2 and libbzip2 License v1.0.5", true),
+  ("bzip2-1.0.6", "bzip2 and libbzip2 License v1.0.6", false),
+  ("check-cvs", "check-cvs License", false),
+  ("checkmk", "Checkmk License", false),
+  ("copyleft-next-0.3.0", "copyleft-next 0.3.0", false),
+  ("copyleft-next-0.3.1", "copyleft-next 0.3.1", false),
+  ("curl", "curl License", false),
+  (
+    "cve-tou",
+    "Common Vulnerability Enumeration ToU License",
+    false,
+  ),
+  ("diffmark", "diffmark license", false),
+  ("dtoa", "David M. Gay dtoa License", false),
+  ("dvipdfm", "dvipdfm License", false),
+  ("eCos-2.0", "eCos license version 2.0", true),
+  ("eGenix", "eGenix.com Public License 1.1.0", false),
+  ("etalab-2.0", "Etalab Open License 2.0", false),
+  ("fwlw", "fwlw License", false),
+  ("gSOAP-1.3b", "gSOAP Public License v1.3b", false),
+  ("generic-xts", "Generic XTS License", false),
+  ("gnuplot", "gnuplot License", false),
+  ("gtkbook", "gtkbook License", false),
+  ("hdparm", "hdparm License", false),
+  (
+    "iMatix",
+    "iMatix Standard Function Library Agreement",
+    false,
+  ),
+  ("jove", "Jove License", false),
+  (
+    "libpng-1.6.35",
+    "PNG Reference Library License v1 (for libpng 0.5 through 1.6.35)",
+    false,
+  ),
+  ("libpng-2.0", "PNG Reference Library version 2", false),
+  ("libselinux-1.0", "libselinux public domain notice", false),
+  ("libtiff", "libtiff License", false),
+  (
+    "libutil-David-Nugent",
+    "libutil David Nugent License",
+    false,
+  ),
+  ("lsof", "lsof License", false),
+  ("magaz", "magaz License", false),
+  ("mailprio", "mailprio License", false),
+  ("man2html", "man2html License", false),
+  ("metamail", "metamail License", false),
+  ("mpi-permissive", "mpi Permissive License", false),
+  ("mpich2", "mpich2 License", false),
+  ("mplus", "mplus Font License", false),
+  ("ngrep", "ngrep License", false),
+  ("pkgconf", "pkgconf License", false),
+  ("pnmstitch", "pnmstitch License", false),
+  ("psfrag", "psfrag License", false),
+  ("psutils", "psutils License", false),
+  ("python-ldap", "Python ldap License", false),
+  ("radvd", "radvd License", false),
+  ("snprintf", "snprintf License", false),
+  ("softSurfer", "softSurfer License", false),
+  ("ssh-keyscan", "ssh-keyscan License", false),
+  ("swrule", "swrule License", false),
+  ("threeparttable", "threeparttable License", false),
+  ("ulem", "ulem License", false),
+  ("w3m", "w3m License", false),
+  ("wwl", "WWL License", false),
+  ("wxWindows", "wxWindows Library License", true),
+  ("xinetd", "xinetd License", false),
+  (
+    "xkeyboard-config-Zinoviev",
+    "xkeyboard-config Zinoviev License",
+    false,
+  ),
+  ("xlock", "xlock License", false),
+  ("xpp", "XPP License", false),
+  ("xzoom", "xzoom License", false),
+  (
+    "zlib-acknowledgement",
+    "zlib/libpng License with Acknowledgement",
+    false,
+  ),
+];
+
+/// `(identifier, deprecated)`.
+pub(crate) const EXCEPTIONS: &[(&str, bool)] = &[
+  ("389-exception", false),
+  ("Asterisk-exception", false),
+  ("Asterisk-linking-protocols-exception", false),
+  ("Autoconf-exception-2.0", false),
+  ("Autoconf-exception-3.0", false),
+  ("Autoconf-exception-generic", false),
+  ("Autoconf-exception-generic-3.0", false),
+  ("Autoconf-exception-macro", false),
+  ("Bison-exception-1.24", false),
+  ("Bison-exception-2.2", false),
+  ("Bootloader-exception", false),
+  ("CGAL-linking-exception", false),
+  ("CLISP-exception-2.0", false),
+  ("Classpath-exception-2.0", false),
+  ("DigiRule-FOSS-exception", false),
+  ("Digia-Qt-LGPL-exception-1.1", false),
+  ("FLTK-exception", false),
+  ("Fawkes-Runtime-exception", false),
+  ("Font-exception-2.0", false),
+  ("GCC-exception-2.0", false),
+  ("GCC-exception-2.0-note", false),
+  ("GCC-exception-3.1", false),
+  ("GNAT-exception", false),
+  ("GNOME-examples-exception", false),
+  ("GNU-compiler-exception", false),
+  ("GPL-3.0-389-ds-base-exception", false),
+  ("GPL-3.0-interface-exception", false),
+  ("GPL-3.0-linking-exception", false),
+  ("GPL-3.0-linking-source-exception", false),
+  ("GPL-CC-1.0", false),
+  ("GStreamer-exception-2005", false),
+  ("GStreamer-exception-2008", false),
+  ("Gmsh-exception", false),
+  ("Independent-modules-exception", false),
+  ("KiCad-libraries-exception", false),
+  ("LGPL-3.0-linking-exception", false),
+  ("LLGPL", false),
+  ("LLVM-exception", false),
+  ("LZMA-exception", false),
+  ("Libtool-exception", false),
+  ("Linux-syscall-note", false),
+  ("Nokia-Qt-exception-1.1", true),
+  ("OCCT-exception-1.0", false),
+  ("OCaml-LGPL-linking-exception", false),
+  ("OpenJDK-assembly-exception-1.0", false),
+  ("PCRE2-exception", false),
+  ("PS-or-PDF-font-exception-20170817", false),
+  ("QPL-1.0-INRIA-2004-exception", false),
+  ("Qt-GPL-exception-1.0", false),
+  ("Qt-LGPL-exception-1.1", false),
+  ("Qwt-exception-1.0", false),
+  ("RRDtool-FLOSS-exception-2.0", false),
+  ("SANE-exception", false),
+  ("SHL-2.0", false),
+  ("SHL-2.1", false),
+  ("SWI-exception", false),
+  ("Swift-exception", false),
+  ("Texinfo-exception", false),
+  ("UBDL-exception", false),
+  ("Universal-FOSS-exception-1.0", false),
+  ("WxWindows-exception-3.1", false),
+  ("cryptsetup-OpenSSL-exception", false),
+  ("eCos-exception-2.0", false),
+  ("erlang-otp-linking-exception", false),
+  ("fmt-exception", false),
+  ("freertos-exception-2.0", false),
+  ("gnu-javamail-exception", false),
+  ("harbour-exception", false),
+  ("i2p-gpl-java-exception", false),
+  ("libpri-OpenH323-exception", false),
+  ("mif-exception", false),
+  ("mxml-exception", false),
+  ("openvpn-openssl-exception", false),
+  ("polyparse-exception", false),
+  ("romic-exception", false),
+  ("stunnel-exception", false),
+  ("u-boot-exception-2.0", false),
+  ("vsftpd-openssl-exception", false),
+  ("x11vnc-openssl-exception", false),
+];
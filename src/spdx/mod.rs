@@ -0,0 +1,422 @@
+//! SPDX license expression parsing.
+//!
+//! Implements the expression grammar from SPDX spec Annex D: `AND` binds
+//! tighter than `OR`, `WITH` attaches an exception to a single license, and
+//! `+` marks "or later". Identifiers are checked against the embedded SPDX
+//! license list.
+
+mod ids;
+
+use std::fmt;
+
+use serde::Serialize;
+
+pub use ids::LICENSE_LIST_VERSION;
+
+/// A single license requirement, e.g. `GPL-2.0-or-later WITH Classpath-exception-2.0`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpdxLicense {
+  pub id: String,
+  pub or_later: bool,
+  pub exception: Option<String>,
+}
+
+impl SpdxLicense {
+  /// Whether the identifier is a `LicenseRef-` or `DocumentRef-` reference.
+  pub fn is_license_ref(&self) -> bool {
+    self.id.starts_with("LicenseRef-") || self.id.starts_with("DocumentRef-")
+  }
+
+  /// Whether the SPDX list marks this identifier as deprecated.
+  pub fn is_deprecated(&self) -> bool {
+    find_license(&self.id).is_some_and(|(_, _, deprecated)| deprecated)
+  }
+
+  /// Full license name from the SPDX list.
+  pub fn name(&self) -> Option<&'static str> {
+    find_license(&self.id).map(|(_, name, _)| name)
+  }
+}
+
+impl fmt::Display for SpdxLicense {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.id)?;
+    if self.or_later {
+      f.write_str("+")?;
+    }
+    if let Some(exception) = self.exception.as_ref() {
+      write!(f, " WITH {exception}")?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum SpdxExpression {
+  License(SpdxLicense),
+  And(Box<SpdxExpression>, Box<SpdxExpression>),
+  Or(Box<SpdxExpression>, Box<SpdxExpression>),
+}
+
+impl SpdxExpression {
+  pub fn parse(input: &str) -> Result<Self, SpdxError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+      input,
+      tokens,
+      position: 0,
+    };
+
+    let expression = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+      let operator = ["AND", "OR", "WITH"]
+        .into_iter()
+        .find(|operator| operator.eq_ignore_ascii_case(token.text));
+      let message = match operator {
+        Some(operator) => format!("operator `{}` must be written as `{operator}`", token.text),
+        None => format!("unexpected `{}`", token.text),
+      };
+      return Err(SpdxError::at(message, token));
+    }
+
+    Ok(expression)
+  }
+
+  /// Every license requirement in the expression, left to right.
+  pub fn licenses(&self) -> Vec<&SpdxLicense> {
+    match self {
+      SpdxExpression::License(license) => vec![license],
+      SpdxExpression::And(left, right) | SpdxExpression::Or(left, right) => {
+        let mut licenses = left.licenses();
+        licenses.extend(right.licenses());
+        licenses
+      }
+    }
+  }
+}
+
+impl fmt::Display for SpdxExpression {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn write_operand(
+      f: &mut fmt::Formatter<'_>,
+      operand: &SpdxExpression,
+      parenthesize_or: bool,
+    ) -> fmt::Result {
+      match operand {
+        SpdxExpression::Or(_, _) if parenthesize_or => write!(f, "({operand})"),
+        _ => write!(f, "{operand}"),
+      }
+    }
+
+    match self {
+      SpdxExpression::License(license) => write!(f, "{license}"),
+      SpdxExpression::And(left, right) => {
+        write_operand(f, left, true)?;
+        f.write_str(" AND ")?;
+        write_operand(f, right, true)
+      }
+      SpdxExpression::Or(left, right) => {
+        write_operand(f, left, false)?;
+        f.write_str(" OR ")?;
+        write_operand(f, right, false)
+      }
+    }
+  }
+}
+
+/// A parse failure, with the byte span of the offending token in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpdxError {
+  pub message: String,
+  pub offset: usize,
+  pub len: usize,
+}
+
+impl SpdxError {
+  fn at(message: String, token: &Token) -> Self {
+    Self {
+      message,
+      offset: token.offset,
+      len: token.text.len(),
+    }
+  }
+}
+
+impl fmt::Display for SpdxError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.message)
+  }
+}
+
+fn find_license(id: &str) -> Option<(&'static str, &'static str, bool)> {
+  ids::LICENSES
+    .iter()
+    .find(|(license, _, _)| *license == id)
+    .copied()
+}
+
+fn find_license_ignore_case(id: &str) -> Option<&'static str> {
+  ids::LICENSES
+    .iter()
+    .find(|(license, _, _)| license.eq_ignore_ascii_case(id))
+    .map(|(license, _, _)| *license)
+}
+
+fn find_exception(id: &str) -> Option<&'static str> {
+  ids::EXCEPTIONS
+    .iter()
+    .find(|(exception, _)| *exception == id)
+    .map(|(exception, _)| *exception)
+}
+
+fn find_exception_ignore_case(id: &str) -> Option<&'static str> {
+  ids::EXCEPTIONS
+    .iter()
+    .find(|(exception, _)| exception.eq_ignore_ascii_case(id))
+    .map(|(exception, _)| *exception)
+}
+
+#[derive(Debug, Clone)]
+struct Token<'a> {
+  text: &'a str,
+  offset: usize,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token<'_>>, SpdxError> {
+  let mut tokens = Vec::new();
+  let mut chars = input.char_indices().peekable();
+
+  while let Some((offset, char)) = chars.next() {
+    match char {
+      ' ' | '\t' | '\n' | '\r' => {}
+      '(' | ')' | '+' => tokens.push(Token {
+        text: &input[offset..offset + 1],
+        offset,
+      }),
+      char if char.is_ascii_alphanumeric() || matches!(char, '.' | '-' | ':') => {
+        let mut end = offset + char.len_utf8();
+        while let Some(&(next_offset, next)) = chars.peek() {
+          if !(next.is_ascii_alphanumeric() || matches!(next, '.' | '-' | ':')) {
+            break;
+          }
+          end = next_offset + next.len_utf8();
+          chars.next();
+        }
+        tokens.push(Token {
+          text: &input[offset..end],
+          offset,
+        });
+      }
+      char => {
+        return Err(SpdxError {
+          message: format!("unexpected character `{char}`"),
+          offset,
+          len: char.len_utf8(),
+        });
+      }
+    }
+  }
+
+  Ok(tokens)
+}
+
+struct Parser<'a> {
+  input: &'a str,
+  tokens: Vec<Token<'a>>,
+  position: usize,
+}
+
+impl<'a> Parser<'a> {
+  fn peek(&self) -> Option<&Token<'a>> {
+    self.tokens.get(self.position)
+  }
+
+  fn next(&mut self) -> Option<Token<'a>> {
+    let token = self.tokens.get(self.position).cloned();
+    self.position += 1;
+    token
+  }
+
+  fn eat(&mut self, text: &str) -> bool {
+    if self.peek().is_some_and(|token| token.text == text) {
+      self.position += 1;
+      return true;
+    }
+    false
+  }
+
+  fn end_error(&self, message: &str) -> SpdxError {
+    SpdxError {
+      message: message.to_string(),
+      offset: self.input.len(),
+      len: 0,
+    }
+  }
+
+  fn parse_or(&mut self) -> Result<SpdxExpression, SpdxError> {
+    let mut left = self.parse_and()?;
+    while self.eat("OR") {
+      let right = self.parse_and()?;
+      left = SpdxExpression::Or(Box::new(left), Box::new(right));
+    }
+    Ok(left)
+  }
+
+  fn parse_and(&mut self) -> Result<SpdxExpression, SpdxError> {
+    let mut left = self.parse_primary()?;
+    while self.eat("AND") {
+      let right = self.parse_primary()?;
+      left = SpdxExpression::And(Box::new(left), Box::new(right));
+    }
+    Ok(left)
+  }
+
+  fn parse_primary(&mut self) -> Result<SpdxExpression, SpdxError> {
+    if self.eat("(") {
+      let expression = self.parse_or()?;
+      if !self.eat(")") {
+        return Err(match self.peek() {
+          Some(token) => SpdxError::at(format!("expected `)`, found `{}`", token.text), token),
+          None => self.end_error("expected `)`"),
+        });
+      }
+      return Ok(expression);
+    }
+
+    let Some(token) = self.next() else {
+      return Err(self.end_error("expected a license identifier"));
+    };
+
+    let id = parse_license_id(&token)?;
+    let or_later = self.eat("+");
+
+    let exception = if self.eat("WITH") {
+      let Some(token) = self.next() else {
+        return Err(self.end_error("expected a license exception after `WITH`"));
+      };
+      Some(parse_exception_id(&token)?)
+    } else {
+      None
+    };
+
+    Ok(SpdxExpression::License(SpdxLicense {
+      id,
+      or_later,
+      exception,
+    }))
+  }
+}
+
+fn check_operator_case(token: &Token) -> Result<(), SpdxError> {
+  let operator = ["AND", "OR", "WITH"]
+    .into_iter()
+    .find(|operator| operator.eq_ignore_ascii_case(token.text));
+
+  match operator {
+    Some(operator) if operator == token.text => Err(SpdxError::at(
+      format!("expected a license identifier, found `{operator}`"),
+      token,
+    )),
+    Some(operator) => Err(SpdxError::at(
+      format!("operator `{}` must be written as `{operator}`", token.text),
+      token,
+    )),
+    None if matches!(token.text, "(" | ")" | "+") => Err(SpdxError::at(
+      format!("expected a license identifier, found `{}`", token.text),
+      token,
+    )),
+    None => Ok(()),
+  }
+}
+
+fn parse_license_id(token: &Token) -> Result<String, SpdxError> {
+  check_operator_case(token)?;
+
+  let id = token.text;
+  let is_ref = lazy_regex::regex_is_match!(
+    r"^(?:DocumentRef-[A-Za-z0-9.\-]+:)?LicenseRef-[A-Za-z0-9.\-]+$",
+    id
+  );
+
+  if is_ref || find_license(id).is_some() {
+    return Ok(id.to_string());
+  }
+
+  let message = match find_license_ignore_case(id) {
+    Some(suggestion) => {
+      format!("unknown SPDX license identifier `{id}`, did you mean `{suggestion}`?")
+    }
+    None => format!("unknown SPDX license identifier `{id}`"),
+  };
+  Err(SpdxError::at(message, token))
+}
+
+fn parse_exception_id(token: &Token) -> Result<String, SpdxError> {
+  check_operator_case(token)?;
+
+  let id = token.text;
+  if find_exception(id).is_some() {
+    return Ok(id.to_string());
+  }
+
+  let message = match find_exception_ignore_case(id) {
+    Some(suggestion) => {
+      format!("unknown SPDX license exception `{id}`, did you mean `{suggestion}`?")
+    }
+    None => format!("unknown SPDX license exception `{id}`"),
+  };
+  Err(SpdxError::at(message, token))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::SpdxExpression;
+
+  #[test]
+  fn should_parse_spdx_expressions() {
+    let cases = [
+      ("MIT", "MIT"),
+      ("MIT OR Apache-2.0", "MIT OR Apache-2.0"),
+      ("(MIT AND BSD-3-Clause)", "MIT AND BSD-3-Clause"),
+      (
+        "Apache-2.0 WITH LLVM-exception",
+        "Apache-2.0 WITH LLVM-exception",
+      ),
+      ("GPL-2.0+", "GPL-2.0+"),
+      ("LicenseRef-Custom", "LicenseRef-Custom"),
+      ("MIT AND (ISC OR Apache-2.0)", "MIT AND (ISC OR Apache-2.0)"),
+      ("MIT OR ISC AND Apache-2.0", "MIT OR ISC AND Apache-2.0"),
+    ];
+
+    for (input, expected) in cases {
+      let expression = SpdxExpression::parse(input).unwrap();
+      assert_eq!(expression.to_string(), expected);
+    }
+  }
+
+  #[test]
+  fn should_bind_and_tighter_than_or() {
+    let expression = SpdxExpression::parse("MIT OR ISC AND Apache-2.0").unwrap();
+    assert!(matches!(expression, SpdxExpression::Or(_, _)));
+    assert_eq!(expression.licenses().len(), 3);
+  }
+
+  #[test]
+  fn should_reject_invalid_spdx_expressions() {
+    let cases = [
+      ("MIT1", 0),
+      ("mit", 0),
+      ("MIT or ISC", 4),
+      ("MIT OR", 6),
+      ("(MIT", 4),
+      ("MIT WITH Nope", 9),
+      ("MIT AND AND ISC", 8),
+      ("MIT, ISC", 3),
+    ];
+
+    for (input, offset) in cases {
+      let error = SpdxExpression::parse(input).unwrap_err();
+      assert_eq!(error.offset, offset, "{input}");
+    }
+  }
+}
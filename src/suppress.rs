@@ -0,0 +1,249 @@
+//! Inline suppression of diagnostics.
+//!
+//! JSONC and JSON5 manifests suppress diagnostics with a comment on the
+//! line before the field:
+//!
+//! ```jsonc
+//! // package-json-ignore-next-line pkg::name::invalid-chars
+//! "name": "My Package",
+//! ```
+//!
+//! Plain JSON has no comments, so the top-level `"//"` key, which npm keeps
+//! for comments, takes `package-json-ignore <field> [<code>...]` entries
+//! instead. `*` as the field suppresses the codes in the whole manifest.
+//! Without codes, every diagnostic of the field is suppressed.
+
+use std::ops::Range;
+
+use jsonc_parser::ast::{Object, Value};
+use jsonc_parser::common::Ranged;
+use jsonc_parser::tokens::{Token, TokenAndRange};
+use miette::LabeledSpan;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+const NEXT_LINE_DIRECTIVE: &str = "package-json-ignore-next-line";
+const KEY_DIRECTIVE: &str = "package-json-ignore";
+const COMMENT_KEY: &str = "//";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Suppression {
+  /// Suppressed codes; empty means all of them.
+  codes: Vec<String>,
+  /// Diagnostics labeled inside this range are suppressed; `None` covers
+  /// the whole manifest.
+  range: Option<Range<usize>>,
+}
+
+impl Suppression {
+  pub(crate) fn matches(&self, code: Option<&str>, labels: &[LabeledSpan]) -> bool {
+    let code_matches =
+      self.codes.is_empty() || code.is_some_and(|code| self.codes.iter().any(|c| c == code));
+    let span_matches = match &self.range {
+      None => true,
+      Some(range) => labels
+        .first()
+        .is_some_and(|label| range.contains(&label.offset())),
+    };
+    code_matches && span_matches
+  }
+
+  pub(crate) fn range(&self) -> Option<&Range<usize>> {
+    self.range.as_ref()
+  }
+
+  pub(crate) fn with_range(self, range: Option<Range<usize>>) -> Self {
+    Self { range, ..self }
+  }
+}
+
+/// Directive arguments: codes separated by whitespace or commas.
+fn parse_codes(arguments: &str) -> Vec<String> {
+  arguments
+    .split(|c: char| c.is_whitespace() || c == ',')
+    .filter(|code| !code.is_empty())
+    .map(str::to_string)
+    .collect()
+}
+
+/// Strips `directive` from the start of `text` when a word boundary follows.
+fn strip_directive<'a>(text: &'a str, directive: &str) -> Option<&'a str> {
+  let rest = text.trim().strip_prefix(directive)?;
+  (rest.is_empty() || rest.starts_with(char::is_whitespace)).then_some(rest)
+}
+
+/// Suppressions from `// package-json-ignore-next-line` comments. Each one
+/// covers the line after the comment, extended to the end of properties
+/// that start on it so multi-line values are covered too.
+pub(crate) fn comment_suppressions(
+  source: &str,
+  tokens: &[TokenAndRange],
+  root: Option<&Object>,
+) -> Vec<Suppression> {
+  let mut suppressions = Vec::new();
+  for token in tokens {
+    let (Token::CommentLine(text) | Token::CommentBlock(text)) = token.token else {
+      continue;
+    };
+    let Some(arguments) = strip_directive(text, NEXT_LINE_DIRECTIVE) else {
+      continue;
+    };
+    let Some(newline) = source[token.range.end..].find('\n') else {
+      continue;
+    };
+
+    let start = token.range.end + newline + 1;
+    let line_end = source[start..]
+      .find('\n')
+      .map_or(source.len(), |index| start + index);
+    let mut end = line_end;
+    if let Some(root) = root {
+      visit_props(root, &mut |prop_start, prop_end| {
+        if (start..line_end).contains(&prop_start) {
+          end = end.max(prop_end);
+        }
+      });
+    }
+
+    suppressions.push(Suppression {
+      codes: parse_codes(arguments),
+      range: Some(start..end),
+    });
+  }
+  suppressions
+}
+
+fn visit_props(object: &Object, visit: &mut impl FnMut(usize, usize)) {
+  for prop in &object.properties {
+    let range = prop.range();
+    visit(range.start, range.end);
+    visit_value(&prop.value, visit);
+  }
+}
+
+fn visit_value(value: &Value, visit: &mut impl FnMut(usize, usize)) {
+  match value {
+    Value::Object(object) => visit_props(object, visit),
+    Value::Array(array) => {
+      for element in &array.elements {
+        visit_value(element, visit);
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Suppressions from `package-json-ignore` entries of the top-level `"//"`
+/// key, which is a string or an array of strings.
+pub(crate) fn key_suppressions(
+  raw_fields: &JsonMap<String, JsonValue>,
+  root: Option<&Object>,
+) -> Vec<Suppression> {
+  let entries = match raw_fields.get(COMMENT_KEY) {
+    Some(JsonValue::String(entry)) => vec![entry.as_str()],
+    Some(JsonValue::Array(entries)) => entries.iter().filter_map(JsonValue::as_str).collect(),
+    _ => return Vec::new(),
+  };
+
+  entries
+    .into_iter()
+    .filter_map(|entry| {
+      let arguments = strip_directive(entry, KEY_DIRECTIVE)?.trim_start();
+      let (field, codes) = arguments
+        .split_once(char::is_whitespace)
+        .unwrap_or((arguments, ""));
+      let range = match field {
+        "*" => None,
+        field => {
+          let range = root?.get(field)?.range();
+          Some(range.start..range.end)
+        }
+      };
+
+      Some(Suppression {
+        codes: parse_codes(codes),
+        range,
+      })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{PackageJsonParser, ParseOptions};
+
+  fn codes(parser: &PackageJsonParser) -> Vec<String> {
+    parser
+      .lint()
+      .diagnostics()
+      .iter()
+      .map(|diagnostic| diagnostic.code.clone())
+      .collect()
+  }
+
+  #[test]
+  fn should_suppress_next_line_in_jsonc() {
+    let content = r#"{
+      "private": true,
+      // package-json-ignore-next-line pkg::name::invalid-chars
+      "name": "Bad Name",
+      "version": "x"
+    }"#;
+    let parser = PackageJsonParser::parse_str_with_options(content, ParseOptions::jsonc()).unwrap();
+
+    assert_eq!(codes(&parser), ["pkg::version::invalid-semver"]);
+    assert!(parser.name().is_err());
+  }
+
+  #[test]
+  fn should_only_suppress_listed_codes() {
+    let content = r#"{
+      "private": true,
+      /* package-json-ignore-next-line pkg::version::invalid-semver */
+      "name": "Bad Name"
+    }"#;
+    let parser = PackageJsonParser::parse_str_with_options(content, ParseOptions::jsonc()).unwrap();
+
+    assert_eq!(codes(&parser), ["pkg::name::invalid-chars"]);
+    assert!(parser.validate_all().is_err());
+  }
+
+  #[test]
+  fn should_suppress_every_code_inside_multiline_value() {
+    let content = r#"{
+      "private": true,
+      // package-json-ignore-next-line
+      "exports": {
+        ".": "index.js"
+      }
+    }"#;
+    let parser = PackageJsonParser::parse_json5_str(content).unwrap();
+
+    assert!(codes(&parser).is_empty());
+    assert!(parser.validate().is_ok());
+  }
+
+  #[test]
+  fn should_suppress_with_comment_key() {
+    let content = r#"{
+      "//": [
+        "Our legacy name predates npm's lowercase rule",
+        "package-json-ignore name pkg::name::invalid-chars",
+        "package-json-ignore * pkg::repository::missing, pkg::license::missing"
+      ],
+      "name": "Legacy",
+      "description": "A package"
+    }"#;
+    let parser = PackageJsonParser::parse_str(content).unwrap();
+
+    assert!(codes(&parser).is_empty());
+    assert!(parser.validate_all().is_ok());
+  }
+
+  #[test]
+  fn should_suppress_with_comment_key_in_yaml() {
+    let content = "//: package-json-ignore version\nprivate: true\nversion: x\n";
+    let parser = PackageJsonParser::parse_yaml_str(content).unwrap();
+
+    assert!(codes(&parser).is_empty());
+  }
+}
@@ -0,0 +1,343 @@
+use std::path::{Component, Path, PathBuf};
+use std::{fs, io};
+
+use glob::{MatchOptions, Pattern};
+use serde::{Deserialize, Serialize};
+
+use crate::err::{ErrorKind, WorkspaceError};
+use crate::yaml::parse_yaml;
+use crate::{FundingEntry, PackageJsonParser};
+
+const PACKAGE_JSON: &str = "package.json";
+const PNPM_WORKSPACE: &str = "pnpm-workspace.yaml";
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+  case_sensitive: true,
+  require_literal_separator: true,
+  require_literal_leading_dot: false,
+};
+
+#[derive(Debug, Default, Deserialize)]
+struct PnpmWorkspace {
+  #[serde(default)]
+  packages: Vec<String>,
+}
+
+/// One package matched by the workspace patterns.
+#[derive(Debug)]
+pub struct WorkspaceMember {
+  path: PathBuf,
+  manifest: miette::Result<PackageJsonParser>,
+}
+
+impl WorkspaceMember {
+  /// The directory that holds the member's package.json.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  pub fn manifest_path(&self) -> PathBuf {
+    self.path.join(PACKAGE_JSON)
+  }
+
+  /// The `name` field, when the manifest parsed and declares one.
+  pub fn name(&self) -> Option<&str> {
+    self
+      .manifest()
+      .and_then(|manifest| manifest.raw_str("name"))
+  }
+
+  pub fn manifest(&self) -> Option<&PackageJsonParser> {
+    self.manifest.as_ref().ok()
+  }
+
+  /// Why the member's package.json could not be parsed.
+  pub fn error(&self) -> Option<&miette::Report> {
+    self.manifest.as_ref().err()
+  }
+}
+
+/// The funding sources of one package, as listed by [`Workspace::funding`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PackageFunding {
+  pub name: Option<String>,
+  pub version: Option<String>,
+  /// The directory that holds the package.json.
+  pub path: PathBuf,
+  pub funding: Vec<FundingEntry>,
+}
+
+impl PackageFunding {
+  fn from_manifest(path: &Path, manifest: &PackageJsonParser) -> Option<Self> {
+    let funding = manifest.funding().ok()??;
+    Some(Self {
+      name: manifest.raw_str("name").map(str::to_string),
+      version: manifest.raw_str("version").map(str::to_string),
+      path: path.to_path_buf(),
+      funding: funding.entries(),
+    })
+  }
+}
+
+/// A monorepo root and the members its `workspaces` patterns select.
+#[derive(Debug)]
+pub struct Workspace {
+  root: PathBuf,
+  manifest: PackageJsonParser,
+  patterns: Vec<String>,
+  members: Vec<WorkspaceMember>,
+}
+
+impl Workspace {
+  /// Discovers the workspace rooted at `root`, which may be the root
+  /// package.json or the directory that contains it.
+  ///
+  /// Patterns come from the `workspaces` field (array or `{ packages }`
+  /// form) and from the `packages` list of a sibling `pnpm-workspace.yaml`.
+  /// Patterns starting with `!` exclude members. A member whose package.json
+  /// fails to parse, or whose directory cannot be read while expanding the
+  /// patterns, is still returned, with the error attached.
+  pub fn discover<P: AsRef<Path>>(root: P) -> miette::Result<Self> {
+    let root = root.as_ref();
+    let (root, manifest_path) = if root.is_dir() {
+      (root.to_path_buf(), root.join(PACKAGE_JSON))
+    } else {
+      let dir = root.parent().unwrap_or(Path::new(".")).to_path_buf();
+      (dir, root.to_path_buf())
+    };
+
+    let manifest = PackageJsonParser::parse(&manifest_path)?;
+
+    let mut patterns = manifest
+      .workspaces()?
+      .map(|workspaces| workspaces.packages().to_vec())
+      .unwrap_or_default();
+    patterns.extend(read_pnpm_workspace(&root)?.packages);
+
+    let members = expand_patterns(&root, &patterns)?
+      .into_iter()
+      .map(|(path, error)| WorkspaceMember {
+        manifest: match error {
+          Some(error) => Err(ErrorKind::IoError(error).into()),
+          None => PackageJsonParser::parse(path.join(PACKAGE_JSON)),
+        },
+        path,
+      })
+      .collect();
+
+    Ok(Self {
+      root,
+      manifest,
+      patterns,
+      members,
+    })
+  }
+
+  pub fn root(&self) -> &Path {
+    &self.root
+  }
+
+  /// The root package.json.
+  pub fn manifest(&self) -> &PackageJsonParser {
+    &self.manifest
+  }
+
+  /// Every pattern that was expanded, in declaration order.
+  pub fn patterns(&self) -> &[String] {
+    &self.patterns
+  }
+
+  /// Members sorted by path.
+  pub fn members(&self) -> &[WorkspaceMember] {
+    &self.members
+  }
+
+  pub fn member(&self, name: &str) -> Option<&WorkspaceMember> {
+    self
+      .members
+      .iter()
+      .find(|member| member.name() == Some(name))
+  }
+
+  /// Funding sources of the root and of every member, like `npm fund`
+  /// lists them. Packages without a valid `funding` field are skipped.
+  pub fn funding(&self) -> Vec<PackageFunding> {
+    let root = PackageFunding::from_manifest(&self.root, &self.manifest);
+    let members = self
+      .members
+      .iter()
+      .filter_map(|member| PackageFunding::from_manifest(member.path(), member.manifest()?));
+    root.into_iter().chain(members).collect()
+  }
+
+  /// Members whose package.json failed to parse.
+  pub fn errors(&self) -> impl Iterator<Item = &WorkspaceMember> {
+    self
+      .members
+      .iter()
+      .filter(|member| member.error().is_some())
+  }
+}
+
+fn read_pnpm_workspace(root: &Path) -> Result<PnpmWorkspace, WorkspaceError> {
+  let path = root.join(PNPM_WORKSPACE);
+  let invalid = |reason: String| WorkspaceError::InvalidPnpmWorkspace {
+    path: path.display().to_string(),
+    reason,
+  };
+
+  let content = match fs::read_to_string(&path) {
+    Ok(content) => content,
+    Err(error) if error.kind() == io::ErrorKind::NotFound => {
+      return Ok(PnpmWorkspace::default());
+    }
+    Err(error) => return Err(invalid(error.to_string())),
+  };
+
+  let document = parse_yaml(&content).map_err(|error| invalid(error.message))?;
+  if document.value.is_null() {
+    return Ok(PnpmWorkspace::default());
+  }
+  serde_json::from_value(document.value).map_err(|error| invalid(error.to_string()))
+}
+
+/// Expands member patterns into the directories that hold a package.json,
+/// each with the error met while reading it during the expansion, if any.
+fn expand_patterns(
+  root: &Path,
+  patterns: &[String],
+) -> Result<Vec<(PathBuf, Option<io::Error>)>, WorkspaceError> {
+  let invalid = |pattern: &str, error: glob::PatternError| WorkspaceError::InvalidPattern {
+    pattern: pattern.to_string(),
+    reason: error.msg.to_string(),
+  };
+
+  let mut excludes = Vec::new();
+  let mut members = Vec::new();
+
+  for pattern in patterns {
+    if let Some(negated) = pattern.strip_prefix('!') {
+      let normalized = normalize_pattern(negated);
+      excludes.push(Pattern::new(normalized).map_err(|error| invalid(pattern, error))?);
+      continue;
+    }
+
+    let full = format!(
+      "{}/{}/{PACKAGE_JSON}",
+      Pattern::escape(&root.to_string_lossy()),
+      normalize_pattern(pattern)
+    );
+    let paths = glob::glob_with(&full, MATCH_OPTIONS).map_err(|error| invalid(pattern, error))?;
+
+    for entry in paths {
+      let (dir, error) = match entry {
+        Ok(manifest) => match manifest.parent() {
+          Some(dir) => (dir.to_path_buf(), None),
+          None => continue,
+        },
+        Err(error) => (error.path().to_path_buf(), Some(error.into())),
+      };
+      let Ok(relative) = dir.strip_prefix(root) else {
+        continue;
+      };
+      if relative.as_os_str().is_empty() || is_in_node_modules(relative) {
+        continue;
+      }
+      members.push((dir, error));
+    }
+  }
+
+  members.retain(|(dir, _)| {
+    let relative = dir.strip_prefix(root).unwrap_or(dir);
+    !excludes
+      .iter()
+      .any(|exclude| exclude.matches_path_with(relative, MATCH_OPTIONS))
+  });
+  members.sort_by(|(a, _), (b, _)| a.cmp(b));
+  members.dedup_by(|(a, _), (b, _)| a == b);
+
+  Ok(members)
+}
+
+fn normalize_pattern(pattern: &str) -> &str {
+  let pattern = pattern.trim();
+  let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+  pattern.trim_end_matches('/')
+}
+
+fn is_in_node_modules(path: &Path) -> bool {
+  path
+    .components()
+    .any(|component| component == Component::Normal("node_modules".as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Workspace;
+  use std::path::Path;
+
+  fn fixture(name: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("fixtures")
+      .join(name)
+  }
+
+  #[test]
+  fn should_discover_npm_workspace_members() {
+    let workspace = Workspace::discover(fixture("workspace-npm")).unwrap();
+
+    let names = workspace
+      .members()
+      .iter()
+      .map(|member| member.name())
+      .collect::<Vec<_>>();
+    assert_eq!(names, [Some("web"), Some("@demo/a"), Some("@demo/b"), None]);
+
+    let broken = workspace.errors().collect::<Vec<_>>();
+    assert_eq!(broken.len(), 1);
+    assert!(broken[0].path().ends_with("packages/broken"));
+
+    let member = workspace.member("@demo/b").unwrap();
+    assert!(member.manifest_path().ends_with("packages/b/package.json"));
+  }
+
+  #[test]
+  fn should_collect_funding_of_root_and_members() {
+    let workspace = Workspace::discover(fixture("workspace-npm")).unwrap();
+    let funding = workspace.funding();
+
+    let names = funding
+      .iter()
+      .map(|package| package.name.as_deref())
+      .collect::<Vec<_>>();
+    assert_eq!(names, [Some("npm-root"), Some("@demo/a")]);
+
+    let a = &funding[1];
+    assert_eq!(a.version.as_deref(), Some("1.0.0"));
+    assert!(a.path.ends_with("packages/a"));
+    assert_eq!(a.funding.len(), 2);
+    assert_eq!(a.funding[1].r#type.as_deref(), Some("opencollective"));
+  }
+
+  #[test]
+  fn should_discover_pnpm_workspace_members() {
+    let root = fixture("workspace-pnpm").join("package.json");
+    let workspace = Workspace::discover(root).unwrap();
+
+    assert_eq!(workspace.patterns(), ["packages/*"]);
+    assert_eq!(workspace.members().len(), 1);
+    assert_eq!(workspace.members()[0].name(), Some("x"));
+    assert_eq!(workspace.manifest().raw_str("name"), Some("pnpm-root"));
+  }
+
+  #[test]
+  fn should_report_unreadable_pnpm_workspace() {
+    let error = Workspace::discover(fixture("workspace-pnpm-unreadable")).unwrap_err();
+    assert!(error.to_string().contains("pnpm-workspace.yaml"));
+  }
+
+  #[test]
+  fn should_fail_discover_without_root_manifest() {
+    assert!(Workspace::discover(fixture("missing")).is_err());
+  }
+}
@@ -0,0 +1,395 @@
+//! Reads YAML manifests (`package.yaml`, `pnpm-workspace.yaml`) into JSON
+//! values while remembering where every node sits in the original text.
+
+use std::ops::Range;
+
+use rustc_hash::FxHashMap;
+use serde_json::{Map as JsonMap, Number, Value as JsonValue};
+use yaml_rust2::Yaml;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+/// A YAML document converted to JSON, with the byte span of every value
+/// keyed by its path from the root (`["dependencies", "react"]`,
+/// `["files", "0"]`).
+#[derive(Debug)]
+pub(crate) struct YamlDocument {
+  pub value: JsonValue,
+  pub spans: FxHashMap<Vec<String>, Range<usize>>,
+}
+
+/// A YAML syntax error or an unsupported construct.
+#[derive(Debug)]
+pub(crate) struct YamlError {
+  pub message: String,
+  pub range: Range<usize>,
+}
+
+pub(crate) fn parse_yaml(content: &str) -> Result<YamlDocument, YamlError> {
+  let mut receiver = Receiver::new(content);
+  let result = Parser::new_from_str(content).load(&mut receiver, true);
+
+  if let Err(error) = result {
+    let start = receiver.byte_offset(error.marker());
+    return Err(YamlError {
+      message: error.info().to_string(),
+      range: start..start,
+    });
+  }
+  if let Some(error) = receiver.error {
+    return Err(error);
+  }
+
+  Ok(YamlDocument {
+    value: receiver.root.unwrap_or(JsonValue::Null),
+    spans: receiver.spans,
+  })
+}
+
+enum Frame {
+  Mapping {
+    map: JsonMap<String, JsonValue>,
+    key: Option<String>,
+    path: Vec<String>,
+    start: usize,
+    end: usize,
+    flow: bool,
+    anchor: usize,
+  },
+  Sequence {
+    items: Vec<JsonValue>,
+    path: Vec<String>,
+    start: usize,
+    end: usize,
+    flow: bool,
+    anchor: usize,
+  },
+}
+
+struct Receiver<'a> {
+  content: &'a str,
+  /// Byte offset of every char, since the scanner counts chars.
+  offsets: Vec<usize>,
+  stack: Vec<Frame>,
+  anchors: FxHashMap<usize, JsonValue>,
+  spans: FxHashMap<Vec<String>, Range<usize>>,
+  root: Option<JsonValue>,
+  documents: usize,
+  error: Option<YamlError>,
+}
+
+impl<'a> Receiver<'a> {
+  fn new(content: &'a str) -> Self {
+    let mut offsets = content
+      .char_indices()
+      .map(|(offset, _)| offset)
+      .collect::<Vec<_>>();
+    offsets.push(content.len());
+
+    Self {
+      content,
+      offsets,
+      stack: Vec::new(),
+      anchors: FxHashMap::default(),
+      spans: FxHashMap::default(),
+      root: None,
+      documents: 0,
+      error: None,
+    }
+  }
+
+  fn byte_offset(&self, marker: &Marker) -> usize {
+    let last = self.offsets.len() - 1;
+    self.offsets[marker.index().min(last)]
+  }
+
+  fn fail(&mut self, message: &str, start: usize) {
+    if self.error.is_none() {
+      self.error = Some(YamlError {
+        message: message.to_string(),
+        range: start..start,
+      });
+    }
+  }
+
+  /// Path of the value about to be added to the innermost collection.
+  fn child_path(&self) -> Vec<String> {
+    match self.stack.last() {
+      None => Vec::new(),
+      Some(Frame::Mapping { path, key, .. }) => {
+        let mut path = path.clone();
+        path.push(key.clone().unwrap_or_default());
+        path
+      }
+      Some(Frame::Sequence { path, items, .. }) => {
+        let mut path = path.clone();
+        path.push(items.len().to_string());
+        path
+      }
+    }
+  }
+
+  /// Whether the next scalar is a mapping key rather than a value.
+  fn expects_key(&self) -> bool {
+    matches!(self.stack.last(), Some(Frame::Mapping { key: None, .. }))
+  }
+
+  fn push_value(&mut self, value: JsonValue, span: Range<usize>, anchor: usize) {
+    if anchor > 0 {
+      self.anchors.insert(anchor, value.clone());
+    }
+    self.spans.insert(self.child_path(), span.clone());
+
+    match self.stack.last_mut() {
+      None => self.root = Some(value),
+      Some(Frame::Mapping { map, key, end, .. }) => {
+        map.insert(key.take().unwrap_or_default(), value);
+        *end = (*end).max(span.end);
+      }
+      Some(Frame::Sequence { items, end, .. }) => {
+        items.push(value);
+        *end = (*end).max(span.end);
+      }
+    }
+  }
+
+  fn on_scalar(
+    &mut self,
+    value: String,
+    style: TScalarStyle,
+    anchor: usize,
+    tag: bool,
+    start: usize,
+  ) {
+    let span = self.scalar_span(&value, style, start);
+
+    if self.expects_key() {
+      if let Some(Frame::Mapping {
+        key, start: first, ..
+      }) = self.stack.last_mut()
+      {
+        *first = (*first).min(span.start);
+        *key = Some(value);
+      }
+      return;
+    }
+
+    let value = if style == TScalarStyle::Plain && !tag {
+      resolve_plain(&value)
+    } else {
+      JsonValue::String(value)
+    };
+    self.push_value(value, span, anchor);
+  }
+
+  /// The scanner only reports where a scalar starts, so the end is recovered
+  /// from the source text.
+  fn scalar_span(&self, value: &str, style: TScalarStyle, start: usize) -> Range<usize> {
+    let rest = &self.content[start..];
+    let line_end = start + rest.find(['\r', '\n']).unwrap_or(rest.len());
+
+    let end = match style {
+      TScalarStyle::Plain if rest.starts_with(value) => start + value.len(),
+      TScalarStyle::SingleQuoted => closing_quote(rest, '\'').map_or(line_end, |end| start + end),
+      TScalarStyle::DoubleQuoted => closing_quote(rest, '"').map_or(line_end, |end| start + end),
+      _ => line_end,
+    };
+    start..end
+  }
+
+  fn end_collection(&mut self, marker_offset: usize) {
+    let Some(frame) = self.stack.pop() else {
+      return;
+    };
+
+    let (value, start, end, flow, anchor) = match frame {
+      Frame::Mapping {
+        map,
+        start,
+        end,
+        flow,
+        anchor,
+        ..
+      } => (JsonValue::Object(map), start, end, flow, anchor),
+      Frame::Sequence {
+        items,
+        start,
+        end,
+        flow,
+        anchor,
+        ..
+      } => (JsonValue::Array(items), start, end, flow, anchor),
+    };
+
+    // Flow collections end at their closing bracket; block collections end
+    // with their last entry.
+    let end = if flow {
+      marker_offset + 1
+    } else {
+      end.max(start)
+    };
+    self.push_value(value, start..end, anchor);
+  }
+}
+
+impl MarkedEventReceiver for Receiver<'_> {
+  fn on_event(&mut self, event: Event, marker: Marker) {
+    if self.error.is_some() {
+      return;
+    }
+    let offset = self.byte_offset(&marker);
+
+    match event {
+      Event::DocumentStart => {
+        self.documents += 1;
+        if self.documents > 1 {
+          self.fail("Expected a single YAML document", offset);
+        }
+      }
+      Event::Scalar(value, style, anchor, tag) => {
+        let tag = tag.is_some_and(|tag| tag.suffix == "str");
+        self.on_scalar(value, style, anchor, tag, offset);
+      }
+      Event::MappingStart(..) | Event::SequenceStart(..) if self.expects_key() => {
+        self.fail("Complex mapping keys are not supported", offset);
+      }
+      Event::MappingStart(anchor, _) => {
+        let flow = self.content[offset..].starts_with('{');
+        self.stack.push(Frame::Mapping {
+          map: JsonMap::new(),
+          key: None,
+          path: self.child_path(),
+          start: offset,
+          end: offset,
+          flow,
+          anchor,
+        });
+      }
+      Event::SequenceStart(anchor, _) => {
+        let flow = self.content[offset..].starts_with('[');
+        self.stack.push(Frame::Sequence {
+          items: Vec::new(),
+          path: self.child_path(),
+          start: offset,
+          end: offset,
+          flow,
+          anchor,
+        });
+      }
+      Event::MappingEnd | Event::SequenceEnd => self.end_collection(offset),
+      Event::Alias(id) => {
+        if self.expects_key() {
+          self.fail("Aliases are not supported as mapping keys", offset);
+          return;
+        }
+        let value = self.anchors.get(&id).cloned().unwrap_or(JsonValue::Null);
+        let end = offset
+          + self.content[offset..]
+            .find(|c: char| c.is_whitespace() || c == ',' || c == ']' || c == '}')
+            .unwrap_or(self.content.len() - offset);
+        self.push_value(value, offset..end, 0);
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Resolves an unquoted scalar with the YAML core schema.
+fn resolve_plain(value: &str) -> JsonValue {
+  match Yaml::from_str(value) {
+    Yaml::Null => JsonValue::Null,
+    Yaml::Boolean(value) => JsonValue::Bool(value),
+    Yaml::Integer(value) => JsonValue::Number(value.into()),
+    Yaml::Real(real) => real
+      .parse::<f64>()
+      .ok()
+      .and_then(Number::from_f64)
+      .map_or_else(|| JsonValue::String(real), JsonValue::Number),
+    _ => JsonValue::String(value.to_string()),
+  }
+}
+
+/// Byte offset just past the quote closing the scalar that opens `text`.
+fn closing_quote(text: &str, quote: char) -> Option<usize> {
+  let mut chars = text.char_indices().skip(1).peekable();
+  while let Some((index, c)) = chars.next() {
+    match c {
+      '\\' if quote == '"' => {
+        chars.next();
+      }
+      c if c == quote => {
+        // `''` is an escaped quote inside a single-quoted scalar.
+        if quote == '\'' && chars.peek().is_some_and(|(_, next)| *next == '\'') {
+          chars.next();
+          continue;
+        }
+        return Some(index + c.len_utf8());
+      }
+      _ => {}
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::parse_yaml;
+  use serde_json::json;
+
+  fn span<'a>(content: &'a str, path: &[&str]) -> &'a str {
+    let document = parse_yaml(content).unwrap();
+    let path = path.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    &content[document.spans[&path].clone()]
+  }
+
+  #[test]
+  fn should_convert_yaml_to_json() {
+    let content = "name: demo\nversion: '1.0.0'\nprivate: true\nfiles:\n  - dist\n  - 1\nmeta: {a: ~, b: 1.5}\n";
+    let document = parse_yaml(content).unwrap();
+    assert_eq!(
+      document.value,
+      json!({
+        "name": "demo",
+        "version": "1.0.0",
+        "private": true,
+        "files": ["dist", 1],
+        "meta": { "a": null, "b": 1.5 }
+      })
+    );
+  }
+
+  #[test]
+  fn should_record_span_of_every_node() {
+    let content = "name: \"é-x\"\nv: 'a''b'\nlist:\n  - 1\n  - é\nm: {a: 1}\nend: x  # c\n";
+    assert_eq!(span(content, &["name"]), "\"é-x\"");
+    assert_eq!(span(content, &["v"]), "'a''b'");
+    assert_eq!(span(content, &["list"]), "- 1\n  - é");
+    assert_eq!(span(content, &["list", "1"]), "é");
+    assert_eq!(span(content, &["m"]), "{a: 1}");
+    assert_eq!(span(content, &["m", "a"]), "1");
+    assert_eq!(span(content, &["end"]), "x");
+  }
+
+  #[test]
+  fn should_expand_aliases() {
+    let document = parse_yaml("a: &v 1\nb: *v\n").unwrap();
+    assert_eq!(document.value, json!({ "a": 1, "b": 1 }));
+  }
+
+  #[test]
+  fn should_report_yaml_syntax_errors() {
+    let error = parse_yaml("a: [1\n").unwrap_err();
+    assert!(error.message.contains("expected ',' or ']'"));
+    assert_eq!(error.range, 6..6);
+  }
+
+  #[test]
+  fn should_reject_multiple_documents() {
+    let content = "---\nname: a\n---\nname: b\n";
+    let error = parse_yaml(content).unwrap_err();
+    assert_eq!(error.message, "Expected a single YAML document");
+    assert_eq!(&content[error.range.start..], "---\nname: b\n");
+
+    assert!(parse_yaml("---\nname: a\n...\n").is_ok());
+  }
+}